@@ -20,10 +20,23 @@
 //!
 //! - `new`: Creates a new bonding curve instance
 //! - `get_buy_price`: Calculates the amount of tokens received for a given SOL amount
+//! - `get_buy_price_with_fees`: Same as `get_buy_price` for a SOL amount that includes fees
+//! - `get_buy_cost`: Calculates the SOL needed to buy an exact amount of tokens
 //! - `get_sell_price`: Calculates the amount of SOL received for selling tokens
 //! - `get_market_cap_sol`: Calculates the current market cap in SOL
+//! - `price_per_token`: Calculates the current price of one whole token in SOL
+//! - `price_per_token_with_decimals`: Same as `price_per_token` for a mint with other decimals
+//! - `market_cap_sol`: Calculates the current market cap in whole SOL
+//! - `market_cap_sol_with_decimals`: Same as `market_cap_sol` for a mint with other decimals
 //! - `get_final_market_cap_sol`: Calculates the final market cap in SOL after all tokens are sold
 //! - `get_buy_out_price`: Calculates the price to buy out all remaining tokens
+//! - `get_progress`: Calculates how far the curve is towards graduation
+//! - `remaining_tokens_before_graduation`: Returns the tokens that can still be bought
+//! - `sol_to_graduation`: Calculates the SOL still needed to complete the curve
+//! - `time_to_graduation`: Extrapolates when the curve completes at a given SOL inflow rate
+//! - `get_price_impact_bps`: Calculates the price impact of a buy in basis points
+//! - `get_sell_price_impact_bps`: Calculates the price impact of a sell in basis points
+//! - `get_break_even`: Calculates the curve state at which selling a buy recovers its cost
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_sdk::pubkey::Pubkey;
@@ -49,6 +62,37 @@ pub struct BondingCurveAccount {
     pub creator: Pubkey,
 }
 
+/// Curve state at which selling the tokens of a buy recovers the SOL spent, fees included
+///
+/// Returned by [`BondingCurveAccount::get_break_even`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BreakEven {
+    /// Amount of tokens received by the buy
+    pub tokens: u64,
+    /// Virtual SOL reserves the curve must reach for the sell to break even
+    pub virtual_sol_reserves: u64,
+    /// Virtual token reserves of the curve at that point
+    pub virtual_token_reserves: u64,
+}
+
+impl BreakEven {
+    /// Returns the break-even spot price in lamports per whole token
+    pub fn price_lamports_per_token(&self) -> f64 {
+        if self.virtual_token_reserves == 0 {
+            return 0.0;
+        }
+
+        let token_unit = 10f64.powi(crate::constants::decimals::TOKEN_DECIMALS as i32);
+        self.virtual_sol_reserves as f64 * token_unit / self.virtual_token_reserves as f64
+    }
+}
+
+/// Result of decoding a single bonding curve account in a batch fetch
+///
+/// `Ok(None)` means the account does not exist, while `Err` means the account exists
+/// but could not be decoded.
+pub type BondingCurveAccountResult = Result<Option<BondingCurveAccount>, crate::error::ClientError>;
+
 impl BondingCurveAccount {
     /// Creates a new bonding curve instance
     ///
@@ -121,6 +165,67 @@ impl BondingCurveAccount {
         })
     }
 
+    /// Calculates the amount of tokens received for a given SOL amount, fees included
+    ///
+    /// The program charges the buy fee on top of the SOL going into the curve, so of a
+    /// total spend of `sol_in` only `sol_in * 10000 / (10000 + fee_basis_points)` reaches
+    /// the curve. The constant product formula is applied to that net amount. One lamport
+    /// is kept back because the program rounds fees up. Unlike [`Self::get_buy_price`],
+    /// which treats all of `sol_in` as curve input, the result does not overestimate the
+    /// tokens received.
+    ///
+    /// # Arguments
+    /// * `sol_in` - Total SOL to spend including fees, in lamports
+    /// * `fee_basis_points` - Total fee charged per trade in basis points, i.e. the protocol
+    ///   fee plus the creator fee
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - Amount of tokens that would be received
+    /// * `Err(&str)` - Error message if curve is complete
+    pub fn get_buy_price_with_fees(
+        &self,
+        sol_in: u64,
+        fee_basis_points: u64,
+    ) -> Result<u64, &'static str> {
+        let net_sol: u128 =
+            (sol_in.saturating_sub(1) as u128) * 10000 / (10000 + fee_basis_points as u128);
+
+        self.get_buy_price(net_sol as u64)
+    }
+
+    /// Calculates the SOL needed to buy an exact amount of tokens
+    ///
+    /// Inverse of [`Self::get_buy_price`]: the SOL going into the curve, fees excluded, for
+    /// the constant product to release `amount` tokens. Like the program, the cost is
+    /// rounded up by one lamport, so spending it with `get_buy_price` yields at least
+    /// `amount` tokens.
+    ///
+    /// # Arguments
+    /// * `amount` - Amount of tokens to buy
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - Lamports going into the curve, fees excluded
+    /// * `Err(&str)` - Error message if curve is complete or holds fewer than `amount` tokens
+    pub fn get_buy_cost(&self, amount: u64) -> Result<u64, &'static str> {
+        if self.complete {
+            return Err("Curve is complete");
+        }
+
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        if amount > self.real_token_reserves || amount >= self.virtual_token_reserves {
+            return Err("Not enough tokens left in the curve");
+        }
+
+        let cost: u128 = (amount as u128) * (self.virtual_sol_reserves as u128)
+            / ((self.virtual_token_reserves - amount) as u128)
+            + 1;
+
+        u64::try_from(cost).map_err(|_| "Buy cost overflows u64")
+    }
+
     /// Calculates the amount of SOL received for selling tokens
     ///
     /// # Arguments
@@ -160,6 +265,48 @@ impl BondingCurveAccount {
             / (self.virtual_token_reserves as u128)) as u64
     }
 
+    /// Calculates the current price of one whole token in SOL
+    ///
+    /// This is the marginal price at the current virtual reserves, adjusted for the token
+    /// and SOL decimals, i.e. "1 TOKEN = X SOL". Assumes the default
+    /// [`TOKEN_DECIMALS`](crate::constants::decimals::TOKEN_DECIMALS).
+    pub fn price_per_token(&self) -> f64 {
+        self.price_per_token_with_decimals(crate::constants::decimals::TOKEN_DECIMALS)
+    }
+
+    /// Calculates the current price of one whole token in SOL for a mint with `token_decimals`
+    ///
+    /// # Arguments
+    /// * `token_decimals` - Decimals of the mint, see `PumpFun::get_mint_decimals`
+    pub fn price_per_token_with_decimals(&self, token_decimals: u8) -> f64 {
+        if self.virtual_token_reserves == 0 {
+            return 0.0;
+        }
+
+        let sol = crate::utils::base_amount_to_ui(
+            self.virtual_sol_reserves,
+            crate::constants::decimals::SOL_DECIMALS,
+        );
+        let tokens = crate::utils::base_amount_to_ui(self.virtual_token_reserves, token_decimals);
+        sol / tokens
+    }
+
+    /// Calculates the current market cap in whole SOL
+    ///
+    /// Values the whole `token_total_supply` at [`BondingCurveAccount::price_per_token`].
+    pub fn market_cap_sol(&self) -> f64 {
+        self.market_cap_sol_with_decimals(crate::constants::decimals::TOKEN_DECIMALS)
+    }
+
+    /// Calculates the current market cap in whole SOL for a mint with `token_decimals`
+    ///
+    /// # Arguments
+    /// * `token_decimals` - Decimals of the mint, see `PumpFun::get_mint_decimals`
+    pub fn market_cap_sol_with_decimals(&self, token_decimals: u8) -> f64 {
+        let supply = crate::utils::base_amount_to_ui(self.token_total_supply, token_decimals);
+        supply * self.price_per_token_with_decimals(token_decimals)
+    }
+
     /// Calculates the final market cap in SOL after all tokens are sold
     ///
     /// # Arguments
@@ -202,6 +349,223 @@ impl BondingCurveAccount {
         // Return total including fee, converting back to u64
         (total_sell_value + fee) as u64
     }
+
+    /// Calculates the curve state at which selling a buy's tokens recovers its full cost
+    ///
+    /// Fees apply on both sides: the buy fee is charged on top of the SOL going into the
+    /// curve, and the sell fee is deducted from the SOL coming out. Selling also moves the
+    /// curve, so breaking even needs a higher price than the spot price right after the buy.
+    /// The curve is assumed to move along its constant product (other traders buying), and
+    /// the result is the smallest virtual SOL reserve at which `get_sell_price` for the
+    /// bought tokens returns at least `sol_spent`.
+    ///
+    /// # Arguments
+    /// * `sol_spent` - Total SOL spent on the buy including the buy fee, in lamports
+    /// * `fee_basis_points` - Total fee charged per trade in basis points, i.e. the protocol
+    ///   fee plus the creator fee
+    ///
+    /// # Returns
+    /// * `Ok(BreakEven)` - Tokens bought and the break-even curve state
+    /// * `Err(&str)` - Error message if curve is complete, the buy receives no tokens, or
+    ///   the cost cannot be recovered within the range of `u64`
+    pub fn get_break_even(
+        &self,
+        sol_spent: u64,
+        fee_basis_points: u64,
+    ) -> Result<BreakEven, &'static str> {
+        // SOL that actually enters the curve once the buy fee is taken
+        let sol_in = ((sol_spent as u128) * 10000 / (10000 + fee_basis_points as u128)) as u64;
+        let tokens = self.get_buy_price(sol_in)?;
+        if tokens == 0 {
+            return Err("Buy receives no tokens");
+        }
+
+        let sol_after: u128 = (self.virtual_sol_reserves as u128) + (sol_in as u128);
+        let tokens_after: u128 = (self.virtual_token_reserves as u128) - (tokens as u128);
+        let k: u128 = sol_after * tokens_after;
+
+        // Curve state with the given virtual SOL reserves on the same constant product
+        let state_at = |virtual_sol: u128| -> Option<Self> {
+            let virtual_token = k.div_ceil(virtual_sol);
+            Some(Self {
+                virtual_sol_reserves: u64::try_from(virtual_sol).ok()?,
+                virtual_token_reserves: u64::try_from(virtual_token).ok()?,
+                ..self.clone()
+            })
+        };
+        let breaks_even = |virtual_sol: u128| -> Result<bool, &'static str> {
+            match state_at(virtual_sol) {
+                Some(state) => Ok(state.get_sell_price(tokens, fee_basis_points)? >= sol_spent),
+                None => Err("Break-even price out of range"),
+            }
+        };
+
+        // Find an upper bound, then the smallest virtual SOL reserves that break even
+        let mut low: u128 = sol_after.max(1);
+        let mut high: u128 = low;
+        while !breaks_even(high)? {
+            low = high + 1;
+            high *= 2;
+        }
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if breaks_even(mid)? {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+
+        let state = state_at(high).ok_or("Break-even price out of range")?;
+        Ok(BreakEven {
+            tokens,
+            virtual_sol_reserves: state.virtual_sol_reserves,
+            virtual_token_reserves: state.virtual_token_reserves,
+        })
+    }
+
+    /// Calculates the price impact of buying with a given SOL amount
+    ///
+    /// The impact is the relative increase of the marginal price (virtual SOL reserves per
+    /// virtual token) caused by the trade, expressed in basis points.
+    ///
+    /// # Arguments
+    /// * `sol_in` - Amount of SOL to spend
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - Price impact in basis points, saturating at `u64::MAX` when the trade
+    ///   would drain the curve
+    /// * `Err(&str)` - Error message if curve is complete
+    pub fn get_price_impact_bps(&self, sol_in: u64) -> Result<u64, &'static str> {
+        let tokens_out = self.get_buy_price(sol_in)?;
+        if tokens_out == 0 || self.virtual_sol_reserves == 0 {
+            return Ok(0);
+        }
+
+        let sol_before: u128 = self.virtual_sol_reserves as u128;
+        let sol_after: u128 = sol_before + (sol_in as u128);
+        let tokens_before: u128 = self.virtual_token_reserves as u128;
+        let tokens_after: u128 = tokens_before - (tokens_out as u128);
+        if tokens_after == 0 {
+            return Ok(u64::MAX);
+        }
+
+        // price_after / price_before = (sol_after / tokens_after) / (sol_before / tokens_before)
+        let ratio_bps: u128 = match (sol_after * 10000 / sol_before).checked_mul(tokens_before) {
+            Some(value) => value / tokens_after,
+            None => return Ok(u64::MAX),
+        };
+
+        Ok(u64::try_from(ratio_bps.saturating_sub(10000)).unwrap_or(u64::MAX))
+    }
+
+    /// Calculates the price impact of selling a given amount of tokens
+    ///
+    /// The impact is the relative decrease of the marginal price caused by the trade,
+    /// expressed in basis points, i.e. how much less the remaining holders get per token
+    /// after the sell. Fees do not move the curve and are ignored.
+    ///
+    /// # Arguments
+    /// * `tokens_in` - Amount of tokens to sell
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - Price impact in basis points, at most `10000`
+    /// * `Err(&str)` - Error message if curve is complete
+    pub fn get_sell_price_impact_bps(&self, tokens_in: u64) -> Result<u64, &'static str> {
+        let sol_out = self.get_sell_price(tokens_in, 0)?;
+        if sol_out == 0 || self.virtual_sol_reserves == 0 {
+            return Ok(0);
+        }
+
+        let sol_before: u128 = self.virtual_sol_reserves as u128;
+        let sol_after: u128 = sol_before - (sol_out as u128);
+        let tokens_before: u128 = self.virtual_token_reserves as u128;
+        let tokens_after: u128 = tokens_before + (tokens_in as u128);
+
+        // price_after / price_before = (sol_after / tokens_after) / (sol_before / tokens_before)
+        // The SOL ratio is at most 1, so the product cannot overflow
+        let ratio_bps: u128 = sol_after * 10000 / sol_before * tokens_before / tokens_after;
+
+        Ok(10000 - ratio_bps.min(10000) as u64)
+    }
+
+    /// Calculates how far the curve is towards graduation
+    ///
+    /// Progress is the share of the initial real token reserves that has been sold,
+    /// from `0.0` for a fresh curve to `1.0` for a complete one.
+    ///
+    /// # Arguments
+    /// * `initial_real_token_reserves` - Real token reserves the curve started with,
+    ///   as found in the global account
+    pub fn get_progress(&self, initial_real_token_reserves: u64) -> f64 {
+        if self.complete {
+            return 1.0;
+        }
+
+        if initial_real_token_reserves == 0 {
+            return 0.0;
+        }
+
+        let sold = initial_real_token_reserves.saturating_sub(self.real_token_reserves);
+        (sold as f64 / initial_real_token_reserves as f64).clamp(0.0, 1.0)
+    }
+
+    /// Returns the amount of tokens that can still be bought before the curve completes
+    ///
+    /// The tokens reserved for the migration to the AMM pool are held by the curve's token
+    /// account but are not part of `real_token_reserves`, so they are already excluded: buying
+    /// exactly this amount completes the curve, and larger buys are capped to it.
+    ///
+    /// # Returns
+    /// Amount of tokens in base units, `0` once the curve is complete
+    pub fn remaining_tokens_before_graduation(&self) -> u64 {
+        if self.complete {
+            return 0;
+        }
+
+        self.real_token_reserves
+    }
+
+    /// Calculates the SOL that must still go into the curve for it to complete
+    ///
+    /// # Returns
+    /// Lamports, fees excluded, needed to buy the remaining tokens before graduation, `0`
+    /// once the curve is complete
+    pub fn sol_to_graduation(&self) -> u64 {
+        if self.complete {
+            return 0;
+        }
+
+        self.get_buy_cost(self.remaining_tokens_before_graduation())
+            .unwrap_or(u64::MAX)
+    }
+
+    /// Extrapolates how long the curve takes to complete at a constant SOL inflow rate
+    ///
+    /// # Arguments
+    /// * `net_sol_inflow` - Lamports bought minus lamports sold over `window`, fees excluded
+    /// * `window` - Period over which `net_sol_inflow` was measured
+    ///
+    /// # Returns
+    /// Time left until graduation, `Some(Duration::ZERO)` if the curve is already complete,
+    /// or `None` if the net inflow is not positive and the curve would never complete
+    pub fn time_to_graduation(
+        &self,
+        net_sol_inflow: i128,
+        window: std::time::Duration,
+    ) -> Option<std::time::Duration> {
+        if self.complete {
+            return Some(std::time::Duration::ZERO);
+        }
+
+        if net_sol_inflow <= 0 || window.is_zero() {
+            return None;
+        }
+
+        let lamports_per_sec = net_sol_inflow as f64 / window.as_secs_f64();
+        std::time::Duration::try_from_secs_f64(self.sol_to_graduation() as f64 / lamports_per_sec)
+            .ok()
+    }
 }
 
 #[cfg(test)]
@@ -252,6 +616,109 @@ mod tests {
         assert!(sell_price > 0);
     }
 
+    #[test]
+    fn test_get_buy_cost() {
+        let bonding_curve = BondingCurveAccount::new(
+            1,                     // discriminator
+            1_073_000_000_000_000, // virtual_token_reserves
+            30_000_000_000,        // virtual_sol_reserves
+            793_100_000_000_000,   // real_token_reserves
+            0,                     // real_sol_reserves
+            1_000_000_000_000_000, // token_total_supply
+            false,                 // complete
+            Pubkey::new_unique(),  // creator
+        );
+
+        assert_eq!(bonding_curve.get_buy_cost(0).unwrap(), 0);
+        for amount in [1, 1_000_000, 35_000_000_000_000, 793_100_000_000_000] {
+            let cost = bonding_curve.get_buy_cost(amount).unwrap();
+            assert!(bonding_curve.get_buy_price(cost).unwrap() >= amount);
+            assert!(bonding_curve.get_buy_price(cost.saturating_sub(2)).unwrap() < amount);
+        }
+
+        assert!(bonding_curve.get_buy_cost(793_100_000_000_001).is_err());
+    }
+
+    #[test]
+    fn test_time_to_graduation() {
+        let mut bonding_curve = BondingCurveAccount::new(
+            1,                     // discriminator
+            1_073_000_000_000_000, // virtual_token_reserves
+            30_000_000_000,        // virtual_sol_reserves
+            793_100_000_000_000,   // real_token_reserves
+            0,                     // real_sol_reserves
+            1_000_000_000_000_000, // token_total_supply
+            false,                 // complete
+            Pubkey::new_unique(),  // creator
+        );
+
+        // A fresh curve needs about 85 SOL to complete
+        let remaining = bonding_curve.sol_to_graduation();
+        assert_eq!(remaining / 1_000_000_000, 85);
+        assert_eq!(
+            bonding_curve.get_buy_price(remaining).unwrap(),
+            793_100_000_000_000
+        );
+
+        // Inflow of the remaining SOL every 10 minutes graduates in 10 minutes
+        let window = std::time::Duration::from_secs(600);
+        let eta = bonding_curve
+            .time_to_graduation(remaining as i128, window)
+            .unwrap();
+        assert!(eta.abs_diff(window) < std::time::Duration::from_millis(1));
+
+        // Twice the inflow halves the time
+        let eta = bonding_curve
+            .time_to_graduation(2 * remaining as i128, window)
+            .unwrap();
+        assert!(eta.abs_diff(window / 2) < std::time::Duration::from_millis(1));
+
+        // No or negative inflow never graduates
+        assert_eq!(bonding_curve.time_to_graduation(0, window), None);
+        assert_eq!(bonding_curve.time_to_graduation(-1_000, window), None);
+        assert_eq!(
+            bonding_curve.time_to_graduation(1_000, std::time::Duration::ZERO),
+            None
+        );
+
+        bonding_curve.complete = true;
+        assert_eq!(bonding_curve.sol_to_graduation(), 0);
+        assert_eq!(
+            bonding_curve.time_to_graduation(0, window),
+            Some(std::time::Duration::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_remaining_tokens_before_graduation() {
+        // Mainnet curve with a single whole token left before graduation
+        let mut bonding_curve = BondingCurveAccount::new(
+            1,                               // discriminator
+            279_900_000_000_000 + 1_000_000, // virtual_token_reserves
+            114_999_999_000,                 // virtual_sol_reserves
+            1_000_000,                       // real_token_reserves
+            84_999_999_000,                  // real_sol_reserves
+            1_000_000_000_000_000,           // token_total_supply
+            false,                           // complete
+            Pubkey::new_unique(),            // creator
+        );
+        let remaining = bonding_curve.remaining_tokens_before_graduation();
+        assert_eq!(remaining, 1_000_000);
+
+        // The reserved migration tokens cannot be bought: large buys stop at the remainder
+        assert_eq!(
+            bonding_curve.get_buy_price(1_000_000_000_000).unwrap(),
+            remaining
+        );
+
+        bonding_curve.real_token_reserves = 0;
+        assert_eq!(bonding_curve.remaining_tokens_before_graduation(), 0);
+
+        bonding_curve.real_token_reserves = 1_000_000;
+        bonding_curve.complete = true;
+        assert_eq!(bonding_curve.remaining_tokens_before_graduation(), 0);
+    }
+
     #[test]
     fn test_bonding_curve_complete() {
         let mut bonding_curve: BondingCurveAccount = get_bonding_curve();
@@ -280,6 +747,42 @@ mod tests {
         assert!(final_market_cap > 0);
     }
 
+    #[test]
+    fn test_price_per_token() {
+        // Reserves of a freshly launched curve
+        let bonding_curve = BondingCurveAccount::new(
+            1,
+            1_073_000_000_000_000,
+            30_000_000_000,
+            793_100_000_000_000,
+            0,
+            1_000_000_000_000_000,
+            false,
+            Pubkey::new_unique(),
+        );
+
+        // 30 SOL for 1.073 billion tokens
+        let price = bonding_curve.price_per_token();
+        assert!((price - 30.0 / 1_073_000_000.0).abs() < 1e-15);
+
+        // 1 billion tokens at that price, consistent with the lamport-based market cap
+        let market_cap = bonding_curve.market_cap_sol();
+        assert!((market_cap - 27.958993476).abs() < 1e-6);
+        assert_eq!(
+            (market_cap * 1e9) as u64 / 1000,
+            bonding_curve.get_market_cap_sol() / 1000
+        );
+
+        // The market cap does not depend on the decimals, the price per whole token does
+        assert!((bonding_curve.market_cap_sol_with_decimals(9) - market_cap).abs() < 1e-6);
+        assert!((bonding_curve.price_per_token_with_decimals(9) - price * 1000.0).abs() < 1e-12);
+
+        let mut empty = bonding_curve;
+        empty.virtual_token_reserves = 0;
+        assert_eq!(empty.price_per_token(), 0.0);
+        assert_eq!(empty.market_cap_sol(), 0.0);
+    }
+
     #[test]
     fn test_buy_out_price() {
         let bonding_curve: BondingCurveAccount = get_bonding_curve();
@@ -292,6 +795,87 @@ mod tests {
         assert!(small_buy_out > 0);
     }
 
+    #[test]
+    fn test_price_impact() {
+        let mut bonding_curve: BondingCurveAccount = get_bonding_curve();
+
+        assert_eq!(bonding_curve.get_price_impact_bps(0).unwrap(), 0);
+
+        // 100 SOL in: sol 1000 -> 1100, tokens 1000 -> 910, price ratio ~1.2087
+        let impact = bonding_curve.get_price_impact_bps(100).unwrap();
+        assert_eq!(impact, 2087);
+
+        // Larger trades move the price more
+        assert!(bonding_curve.get_price_impact_bps(200).unwrap() > impact);
+
+        bonding_curve.complete = true;
+        assert!(bonding_curve.get_price_impact_bps(100).is_err());
+    }
+
+    #[test]
+    fn test_sell_price_impact() {
+        let mut bonding_curve = BondingCurveAccount::new(
+            1,                     // discriminator
+            1_073_000_000_000_000, // virtual_token_reserves
+            30_000_000_000,        // virtual_sol_reserves
+            793_100_000_000_000,   // real_token_reserves
+            0,                     // real_sol_reserves
+            1_000_000_000_000_000, // token_total_supply
+            false,                 // complete
+            Pubkey::new_unique(),  // creator
+        );
+
+        // Buy 10 SOL worth of tokens first, so there is something to sell
+        let sol_in = 10_000_000_000;
+        let tokens = bonding_curve.get_buy_price(sol_in).unwrap();
+        let buy_impact = bonding_curve.get_price_impact_bps(sol_in).unwrap();
+        bonding_curve.virtual_sol_reserves += sol_in;
+        bonding_curve.virtual_token_reserves -= tokens;
+
+        assert_eq!(bonding_curve.get_sell_price_impact_bps(0).unwrap(), 0);
+
+        // Larger sells move the price more
+        let mut previous = 0;
+        for amount in [tokens / 100, tokens / 10, tokens / 2, tokens] {
+            let impact = bonding_curve.get_sell_price_impact_bps(amount).unwrap();
+            assert!(impact > previous);
+            assert!(impact < 10000);
+            previous = impact;
+        }
+
+        // Selling the bought tokens undoes the buy: (1 + buy) * (1 - sell) = 1
+        let sell_impact = bonding_curve.get_sell_price_impact_bps(tokens).unwrap();
+        let expected = 10000 - 10000 * 10000 / (10000 + buy_impact);
+        assert!(sell_impact.abs_diff(expected) <= 2);
+
+        // Dumping far more than the curve holds approaches a 100% drop
+        assert!(bonding_curve.get_sell_price_impact_bps(u64::MAX).unwrap() > 9900);
+
+        bonding_curve.complete = true;
+        assert!(bonding_curve.get_sell_price_impact_bps(tokens).is_err());
+    }
+
+    #[test]
+    fn test_overflow_price_impact() {
+        let bonding_curve = get_large_bonding_curve();
+
+        let impact = bonding_curve.get_price_impact_bps(u64::MAX).unwrap();
+        assert!(impact > 0);
+    }
+
+    #[test]
+    fn test_progress() {
+        let mut bonding_curve: BondingCurveAccount = get_bonding_curve();
+
+        // Half of the initial 1000 real tokens have been sold
+        assert_eq!(bonding_curve.get_progress(1000), 0.5);
+        assert_eq!(bonding_curve.get_progress(500), 0.0);
+        assert_eq!(bonding_curve.get_progress(0), 0.0);
+
+        bonding_curve.complete = true;
+        assert_eq!(bonding_curve.get_progress(1000), 1.0);
+    }
+
     #[test]
     fn test_overflow_buy_price() {
         let bonding_curve = get_large_bonding_curve();
@@ -323,6 +907,109 @@ mod tests {
         assert!(final_market_cap > 0);
     }
 
+    #[test]
+    fn test_get_break_even_small() {
+        let bonding_curve: BondingCurveAccount = get_bonding_curve();
+
+        // Without fees, 100 lamports buy 90 tokens and move the curve to (1100, 910).
+        // Selling the 90 tokens back only recovers the 100 lamports once the virtual SOL
+        // reserves reach 1107 (with 905 virtual tokens), above the post-buy price.
+        let break_even = bonding_curve.get_break_even(100, 0).unwrap();
+        assert_eq!(
+            break_even,
+            BreakEven {
+                tokens: 90,
+                virtual_sol_reserves: 1107,
+                virtual_token_reserves: 905,
+            }
+        );
+    }
+
+    #[test]
+    fn test_get_buy_price_with_fees() {
+        // Fresh curve
+        let curve = BondingCurveAccount::new(
+            1,
+            1_073_000_000_000_000,
+            30_000_000_000,
+            793_100_000_000_000,
+            0,
+            1_000_000_000_000_000,
+            false,
+            Pubkey::default(),
+        );
+
+        // With 95 bps protocol and 30 bps creator fees, 987_654_320 of 1 SOL reach the curve
+        let tokens = curve.get_buy_price_with_fees(1_000_000_000, 125).unwrap();
+        assert_eq!(tokens, 34_199_203_154_141);
+        assert_eq!(tokens, curve.get_buy_price(987_654_320).unwrap());
+        assert!(tokens < curve.get_buy_price(1_000_000_000).unwrap());
+
+        // Replaying the program's buy: curve cost plus the fee rounded up fits the budget
+        let cost = (curve.virtual_sol_reserves as u128) * (tokens as u128)
+            / ((curve.virtual_token_reserves - tokens) as u128)
+            + 1;
+        let fee = (cost * 125).div_ceil(10000);
+        assert_eq!(cost + fee, 999_999_999);
+
+        assert_eq!(curve.get_buy_price_with_fees(0, 125), Ok(0));
+        assert_eq!(
+            curve.get_buy_price_with_fees(1_000_000_000, 0),
+            curve.get_buy_price(999_999_999)
+        );
+    }
+
+    #[test]
+    fn test_get_break_even_with_fees() {
+        // Initial Pump.fun curve: 30 SOL and 1,073,000,000 tokens of virtual reserves
+        let bonding_curve = BondingCurveAccount::new(
+            1,
+            1_073_000_000_000_000,
+            30_000_000_000,
+            793_100_000_000_000,
+            0,
+            1_000_000_000_000_000,
+            false,
+            Pubkey::new_unique(),
+        );
+
+        // Spending 1.01 SOL with a 1% fee puts exactly 1 SOL into the curve, which moves
+        // it to 31 SOL of virtual reserves. Recovering 1.01 SOL after the 1% sell fee
+        // needs the virtual SOL reserves to reach 31.316725 SOL.
+        let break_even = bonding_curve.get_break_even(1_010_000_000, 100).unwrap();
+        assert_eq!(break_even.tokens, 34_612_903_225_806);
+        assert_eq!(break_even.virtual_sol_reserves, 31_316_725_000);
+        assert_eq!(break_even.virtual_token_reserves, 1_027_885_259_394_143);
+
+        let price = break_even.price_lamports_per_token();
+        assert!((price - 30.467).abs() < 0.001);
+
+        // Selling at the break-even state recovers the cost, one lamport earlier does not
+        let state = |virtual_sol_reserves: u64, virtual_token_reserves: u64| BondingCurveAccount {
+            virtual_sol_reserves,
+            virtual_token_reserves,
+            ..bonding_curve.clone()
+        };
+        let at = state(
+            break_even.virtual_sol_reserves,
+            break_even.virtual_token_reserves,
+        );
+        assert!(at.get_sell_price(break_even.tokens, 100).unwrap() >= 1_010_000_000);
+        let k = 31_000_000_000u128 * 1_038_387_096_774_194u128;
+        let before_sol = break_even.virtual_sol_reserves - 1;
+        let before = state(before_sol, k.div_ceil(before_sol as u128) as u64);
+        assert!(before.get_sell_price(break_even.tokens, 100).unwrap() < 1_010_000_000);
+    }
+
+    #[test]
+    fn test_get_break_even_errors() {
+        let mut bonding_curve: BondingCurveAccount = get_bonding_curve();
+        assert!(bonding_curve.get_break_even(0, 100).is_err());
+
+        bonding_curve.complete = true;
+        assert!(bonding_curve.get_break_even(100, 100).is_err());
+    }
+
     #[test]
     fn test_overflow_buy_out_price() {
         let bonding_curve = get_large_bonding_curve();
@@ -27,6 +27,13 @@
 //!
 //! - `new`: Creates a new global account instance
 //! - `get_initial_buy_price`: Calculates the initial amount of tokens received for a given SOL amount
+//! - `get_max_initial_buy_tokens`: Maximum amount of tokens the first buy on a curve can receive
+//! - `get_max_initial_buy_sol`: Smallest SOL amount that buys the maximum initial tokens
+//! - `sol_needed_for_tokens`: Smallest SOL amount for which the first buy receives a token amount
+//! - `get_supply_percent_tokens`: Token amount corresponding to a percentage of the total supply
+//! - `valid_fee_recipients`: Fee recipients the program currently accepts
+//! - `is_valid_fee_recipient`: Whether the program accepts a given fee recipient
+//! - `graduation_market_cap_sol`: Market cap in whole SOL at which a new curve completes
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_sdk::pubkey::Pubkey;
@@ -146,6 +153,133 @@ impl GlobalAccount {
             self.initial_real_token_reserves
         }
     }
+
+    /// Returns the maximum amount of tokens the first buy on a new curve can receive
+    ///
+    /// # Returns
+    /// The initial real token reserves, as a buy can never take more than the curve holds
+    pub fn get_max_initial_buy_tokens(&self) -> u64 {
+        self.initial_real_token_reserves
+    }
+
+    /// Calculates the smallest SOL amount that buys the maximum initial tokens
+    ///
+    /// Spending more than this on the first buy cannot return more tokens, so it is the
+    /// cap a creator should respect for the initial (dev) buy.
+    ///
+    /// # Returns
+    /// Amount of SOL, in lamports, at which `get_initial_buy_price` reaches
+    /// `get_max_initial_buy_tokens`. Returns `u64::MAX` if the reserves are misconfigured
+    /// and the cap cannot be reached.
+    pub fn get_max_initial_buy_sol(&self) -> u64 {
+        self.sol_needed_for_tokens(self.initial_real_token_reserves)
+    }
+
+    /// Calculates the smallest SOL amount for which the first buy on a new curve
+    /// receives at least the given amount of tokens
+    ///
+    /// This is the inverse of `get_initial_buy_price`. Token amounts above
+    /// `get_max_initial_buy_tokens` are clamped to it.
+    ///
+    /// # Arguments
+    /// * `tokens` - Desired amount of tokens, in base units
+    ///
+    /// # Returns
+    /// Amount of SOL, in lamports. Returns `u64::MAX` if the reserves are misconfigured
+    /// and the amount cannot be reached.
+    pub fn sol_needed_for_tokens(&self, tokens: u64) -> u64 {
+        if tokens == 0 {
+            return 0;
+        }
+
+        let virtual_sol: u128 = self.initial_virtual_sol_reserves as u128;
+        let virtual_token: u128 = self.initial_virtual_token_reserves as u128;
+        let tokens: u128 = tokens.min(self.initial_real_token_reserves) as u128;
+
+        if virtual_token <= tokens {
+            return u64::MAX;
+        }
+
+        // Smallest amount such that `n / (virtual_sol + amount) + 1 <= virtual_token - tokens`
+        let n: u128 = virtual_sol * virtual_token;
+        let i: u128 = n / (virtual_token - tokens) + 1;
+
+        u64::try_from(i.saturating_sub(virtual_sol)).unwrap_or(u64::MAX)
+    }
+
+    /// Calculates the amount of tokens corresponding to a percentage of the total supply
+    ///
+    /// # Arguments
+    /// * `supply_percent` - Percentage of `token_total_supply`, e.g. `5.0` for 5%
+    ///
+    /// # Returns
+    /// Amount of tokens in base units, clamped to `get_max_initial_buy_tokens`. Negative
+    /// or non-finite percentages yield 0.
+    pub fn get_supply_percent_tokens(&self, supply_percent: f64) -> u64 {
+        if !supply_percent.is_finite() || supply_percent <= 0.0 {
+            return 0;
+        }
+
+        let tokens = (self.token_total_supply as f64 * supply_percent / 100.0) as u64;
+        tokens.min(self.get_max_initial_buy_tokens())
+    }
+
+    /// Calculates the market cap, in whole SOL, at which a new bonding curve completes
+    ///
+    /// A curve completes once its `initial_real_token_reserves` are bought out. The curve
+    /// keeps its constant product, so the virtual SOL reserves are then
+    /// `initial_virtual_sol_reserves * initial_virtual_token_reserves / remaining` with
+    /// `remaining = initial_virtual_token_reserves - initial_real_token_reserves`, and the
+    /// whole `token_total_supply` is valued at their ratio. The result is the same for every
+    /// curve created under this configuration and does not depend on the mint decimals.
+    ///
+    /// # Returns
+    /// Market cap in whole SOL, ignoring fees. Returns `f64::INFINITY` if the reserves are
+    /// misconfigured and the curve cannot be bought out.
+    pub fn graduation_market_cap_sol(&self) -> f64 {
+        let virtual_token = self.initial_virtual_token_reserves as f64;
+        let remaining = self
+            .initial_virtual_token_reserves
+            .saturating_sub(self.initial_real_token_reserves) as f64;
+        if remaining == 0.0 {
+            return f64::INFINITY;
+        }
+
+        let final_virtual_sol =
+            self.initial_virtual_sol_reserves as f64 * virtual_token / remaining;
+        let market_cap = self.token_total_supply as f64 * final_virtual_sol / remaining;
+        market_cap / 10f64.powi(crate::constants::decimals::SOL_DECIMALS as i32)
+    }
+
+    /// Returns the fee recipients the program currently accepts
+    ///
+    /// The program rotates fees between `fee_recipient` and the `fee_recipients` array, and
+    /// accepts any of them in buys and sells.
+    ///
+    /// # Returns
+    /// `fee_recipient` followed by the other non-default entries of `fee_recipients`, without
+    /// duplicates
+    pub fn valid_fee_recipients(&self) -> Vec<Pubkey> {
+        let mut recipients = vec![self.fee_recipient];
+        for recipient in self.fee_recipients {
+            if recipient != Pubkey::default() && !recipients.contains(&recipient) {
+                recipients.push(recipient);
+            }
+        }
+        recipients
+    }
+
+    /// Checks whether the program accepts the given fee recipient
+    ///
+    /// # Arguments
+    /// * `fee_recipient` - Fee recipient to check
+    ///
+    /// # Returns
+    /// `true` if `fee_recipient` is one of `valid_fee_recipients`
+    pub fn is_valid_fee_recipient(&self, fee_recipient: &Pubkey) -> bool {
+        *fee_recipient != Pubkey::default()
+            && (self.fee_recipient == *fee_recipient || self.fee_recipients.contains(fee_recipient))
+    }
 }
 
 #[cfg(test)]
@@ -214,6 +348,87 @@ mod tests {
         assert_eq!(price, global.initial_real_token_reserves);
     }
 
+    #[test]
+    fn test_sol_needed_for_tokens() {
+        let global: GlobalAccount = get_global();
+        assert_eq!(global.sol_needed_for_tokens(0), 0);
+
+        for tokens in [1, 100, 250, 499, 500] {
+            let sol = global.sol_needed_for_tokens(tokens);
+            assert!(global.get_initial_buy_price(sol) >= tokens);
+            assert!(global.get_initial_buy_price(sol - 1) < tokens);
+        }
+
+        // Amounts above the real reserves are clamped
+        assert_eq!(
+            global.sol_needed_for_tokens(10_000),
+            global.get_max_initial_buy_sol()
+        );
+    }
+
+    #[test]
+    fn test_get_supply_percent_tokens() {
+        let global: GlobalAccount = get_global();
+        assert_eq!(global.get_supply_percent_tokens(5.0), 50);
+        assert_eq!(global.get_supply_percent_tokens(80.0), 500);
+        assert_eq!(global.get_supply_percent_tokens(-1.0), 0);
+        assert_eq!(global.get_supply_percent_tokens(f64::NAN), 0);
+    }
+
+    #[test]
+    fn test_max_initial_buy() {
+        let global: GlobalAccount = get_global();
+        assert_eq!(global.get_max_initial_buy_tokens(), 500);
+
+        // The cap is the exact point at which the initial buy hits the real reserves
+        let max_sol = global.get_max_initial_buy_sol();
+        assert_eq!(max_sol, 1001);
+        assert_eq!(
+            global.get_initial_buy_price(max_sol),
+            global.get_max_initial_buy_tokens()
+        );
+        assert!(global.get_initial_buy_price(max_sol - 1) < global.get_max_initial_buy_tokens());
+
+        let global: GlobalAccount = get_large_global();
+        assert_eq!(
+            global.get_initial_buy_price(global.get_max_initial_buy_sol()),
+            global.get_max_initial_buy_tokens()
+        );
+    }
+
+    #[test]
+    fn test_valid_fee_recipients() {
+        let mut global: GlobalAccount = get_global();
+        let first = global.fee_recipient;
+        let second = Pubkey::new_unique();
+        let third = Pubkey::new_unique();
+        global.fee_recipients = [Pubkey::default(); 7];
+        global.fee_recipients[0] = first;
+        global.fee_recipients[2] = second;
+        global.fee_recipients[5] = third;
+        global.fee_recipients[6] = second;
+
+        assert_eq!(global.valid_fee_recipients(), vec![first, second, third]);
+        assert!(global.is_valid_fee_recipient(&first));
+        assert!(global.is_valid_fee_recipient(&third));
+        assert!(!global.is_valid_fee_recipient(&Pubkey::new_unique()));
+        assert!(!global.is_valid_fee_recipient(&Pubkey::default()));
+    }
+
+    #[test]
+    fn test_graduation_market_cap_sol() {
+        // Mainnet parameters
+        let mut global: GlobalAccount = get_global();
+        global.initial_virtual_token_reserves = 1_073_000_000_000_000;
+        global.initial_virtual_sol_reserves = 30_000_000_000;
+        global.initial_real_token_reserves = 793_100_000_000_000;
+        global.token_total_supply = 1_000_000_000_000_000;
+        assert!((global.graduation_market_cap_sol() - 410.880168).abs() < 1e-6);
+
+        global.initial_real_token_reserves = global.initial_virtual_token_reserves;
+        assert_eq!(global.graduation_market_cap_sol(), f64::INFINITY);
+    }
+
     #[test]
     fn test_global_account_overflow() {
         let global: GlobalAccount = get_large_global();
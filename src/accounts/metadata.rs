@@ -0,0 +1,134 @@
+//! Token metadata account of the Metaplex Token Metadata program
+//!
+//! This module contains the definition for the on-chain metadata account that Pump.fun
+//! creates for every token.
+//!
+//! # Metadata Account
+//!
+//! Only the leading, fixed part of the Metaplex layout is decoded; optional trailing
+//! fields (collection, uses, ...) are ignored.
+//!
+//! # Fields
+//!
+//! - `key`: Metaplex account type discriminator
+//! - `update_authority`: Authority allowed to update the metadata
+//! - `mint`: Mint the metadata belongs to
+//! - `name`: Token name
+//! - `symbol`: Token symbol
+//! - `uri`: URI of the off-chain JSON metadata
+//! - `seller_fee_basis_points`: Royalty in basis points
+//! - `creators`: Optional list of creators
+//! - `primary_sale_happened`: Whether the primary sale has happened
+//! - `is_mutable`: Whether the metadata can still be changed
+//!
+//! # Methods
+//!
+//! - `from_account_data`: Decodes the account and strips the null padding of string fields
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_sdk::pubkey::Pubkey;
+
+/// Creator entry of a metadata account
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct MetadataCreator {
+    /// Creator's address
+    pub address: Pubkey,
+    /// Whether the creator signed the metadata
+    pub verified: bool,
+    /// Share of royalties in percent
+    pub share: u8,
+}
+
+/// Decoded Metaplex token metadata account
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct OnChainMetadata {
+    /// Metaplex account type discriminator
+    pub key: u8,
+    /// Authority allowed to update the metadata
+    pub update_authority: Pubkey,
+    /// Mint the metadata belongs to
+    pub mint: Pubkey,
+    /// Token name
+    pub name: String,
+    /// Token symbol
+    pub symbol: String,
+    /// URI of the off-chain JSON metadata
+    pub uri: String,
+    /// Royalty in basis points
+    pub seller_fee_basis_points: u16,
+    /// Optional list of creators
+    pub creators: Option<Vec<MetadataCreator>>,
+    /// Whether the primary sale has happened
+    pub primary_sale_happened: bool,
+    /// Whether the metadata can still be changed
+    pub is_mutable: bool,
+}
+
+impl OnChainMetadata {
+    /// Decodes a metadata account from its raw data
+    ///
+    /// Metaplex stores `name`, `symbol` and `uri` in fixed-length buffers padded with null
+    /// bytes; the padding is removed so the fields compare equal to the original values.
+    ///
+    /// # Arguments
+    /// * `data` - Raw account data
+    ///
+    /// # Returns
+    /// * `Ok(OnChainMetadata)` - The decoded metadata
+    /// * `Err(std::io::Error)` - Error if the data is not a valid metadata account
+    pub fn from_account_data(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut metadata = solana_sdk::borsh1::try_from_slice_unchecked::<Self>(data)?;
+        metadata.name = metadata.name.trim_end_matches('\0').to_string();
+        metadata.symbol = metadata.symbol.trim_end_matches('\0').to_string();
+        metadata.uri = metadata.uri.trim_end_matches('\0').to_string();
+        Ok(metadata)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pad(value: &str, len: usize) -> String {
+        let mut padded = value.to_string();
+        padded.push_str(&"\0".repeat(len - value.len()));
+        padded
+    }
+
+    #[test]
+    fn test_from_account_data() {
+        let creator = Pubkey::new_unique();
+        let account = OnChainMetadata {
+            key: 4,
+            update_authority: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            name: pad("Example", 32),
+            symbol: pad("EXM", 10),
+            uri: pad("https://ipfs.io/ipfs/QmMetadata", 200),
+            seller_fee_basis_points: 0,
+            creators: Some(vec![MetadataCreator {
+                address: creator,
+                verified: true,
+                share: 100,
+            }]),
+            primary_sale_happened: false,
+            is_mutable: false,
+        };
+
+        // Trailing optional fields of the real layout must be ignored
+        let mut data = borsh::to_vec(&account).unwrap();
+        data.extend_from_slice(&[0; 64]);
+
+        let metadata = OnChainMetadata::from_account_data(&data).unwrap();
+        assert_eq!(metadata.name, "Example");
+        assert_eq!(metadata.symbol, "EXM");
+        assert_eq!(metadata.uri, "https://ipfs.io/ipfs/QmMetadata");
+        assert_eq!(metadata.mint, account.mint);
+        assert_eq!(metadata.creators.unwrap()[0].address, creator);
+    }
+
+    #[test]
+    fn test_from_account_data_invalid() {
+        assert!(OnChainMetadata::from_account_data(&[4, 1, 2, 3]).is_err());
+    }
+}
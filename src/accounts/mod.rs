@@ -6,9 +6,12 @@
 //!
 //! - `BondingCurve`: Represents a bonding curve account.
 //! - `Global`: Represents the global configuration account.
+//! - `OnChainMetadata`: Represents the Metaplex token metadata account of a mint.
 
 mod bonding_curve;
 mod global;
+mod metadata;
 
 pub use bonding_curve::*;
 pub use global::*;
+pub use metadata::*;
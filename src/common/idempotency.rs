@@ -0,0 +1,312 @@
+//! Idempotency keys for launches
+//!
+//! Backends retrying a launch request after a timeout can launch the same token twice.
+//! [`PumpFun::create_and_buy_idempotent`](crate::PumpFun::create_and_buy_idempotent) takes a
+//! caller-chosen key and records it in an [`IdempotencyStore`]: while the key is known, a
+//! retry returns the signature of the first launch instead of launching again.
+//!
+//! The guarantee is at most once per key, within limits:
+//!
+//! - Keys are only remembered for the store's TTL; a retry arriving later launches again.
+//! - The default [`InMemoryIdempotencyStore`] only covers retries reaching the same process
+//!   (and clones of the same client). Implement the trait over a shared store, e.g. Redis,
+//!   for several instances of a backend.
+//! - A launch that fails before its transaction is sent, e.g. on the metadata upload,
+//!   releases its key so it can be retried. If sending or confirming the transaction fails,
+//!   e.g. on an RPC timeout, it may still have landed: the key stays reserved until its TTL
+//!   and retries are rejected. Check the mint of the first attempt on-chain before
+//!   launching again with a new key.
+//! - A retry arriving while the first launch is still running is rejected with
+//!   `ClientError::LaunchInProgress` rather than waiting for it.
+
+use crate::error;
+use async_trait::async_trait;
+use solana_sdk::signature::Signature;
+use std::{
+    collections::HashMap,
+    sync::{Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+
+/// Default time a launch is remembered by [`InMemoryIdempotencyStore`]
+pub const DEFAULT_IDEMPOTENCY_TTL: Duration = Duration::from_secs(600);
+
+/// State of an idempotency key, as returned when reserving it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reservation {
+    /// Key was unknown and is now reserved by the caller, which should launch
+    Reserved,
+    /// A launch with this key is running
+    InProgress,
+    /// A launch with this key succeeded with the given signature
+    Completed(Signature),
+}
+
+/// Store of the idempotency keys of recent launches
+///
+/// Implementations must make [`IdempotencyStore::reserve`] atomic, e.g. with `SET NX` on
+/// Redis, so that concurrent retries cannot both reserve a key, and forget keys after their
+/// TTL.
+///
+/// # Examples
+///
+/// ```no_run
+/// use pumpfun::{
+///     common::{
+///         idempotency::InMemoryIdempotencyStore,
+///         types::{Cluster, PriorityFee},
+///     },
+///     PumpFun,
+/// };
+/// use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+/// use std::{sync::Arc, time::Duration};
+///
+/// // Remember launches for an hour
+/// let store = InMemoryIdempotencyStore::new(Duration::from_secs(3600));
+/// let cluster = Cluster::mainnet(CommitmentConfig::confirmed(), PriorityFee::default());
+/// let client = PumpFun::new(Arc::new(Keypair::new()), cluster)
+///     .with_idempotency_store(Arc::new(store));
+/// ```
+#[async_trait]
+pub trait IdempotencyStore: Send + Sync {
+    /// Reserves a key for a new launch, unless it is already known
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Idempotency key of the launch
+    ///
+    /// # Returns
+    ///
+    /// Returns [`Reservation::Reserved`] if the caller now holds the key, or the state of
+    /// the key otherwise. Returns a ClientError if the store cannot be reached
+    async fn reserve(&self, key: &str) -> Result<Reservation, error::ClientError>;
+
+    /// Records the signature of the successful launch holding a key
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Idempotency key of the launch
+    /// * `signature` - Signature of the launch transaction
+    async fn complete(&self, key: &str, signature: Signature) -> Result<(), error::ClientError>;
+
+    /// Forgets a key after its launch failed, so that it can be retried
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Idempotency key of the launch
+    async fn release(&self, key: &str) -> Result<(), error::ClientError>;
+}
+
+/// Idempotency store kept in the memory of the process
+///
+/// Expired keys are purged when new keys are reserved.
+#[derive(Debug)]
+pub struct InMemoryIdempotencyStore {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Reservation, Instant)>>,
+}
+
+impl Default for InMemoryIdempotencyStore {
+    fn default() -> Self {
+        Self::new(DEFAULT_IDEMPOTENCY_TTL)
+    }
+}
+
+impl InMemoryIdempotencyStore {
+    /// Creates a store remembering keys for the given time
+    ///
+    /// # Arguments
+    ///
+    /// * `ttl` - Time a key is remembered after it was reserved or completed
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, (Reservation, Instant)>> {
+        // Entries are always left consistent, so a poisoned lock can be reused
+        self.entries.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+#[async_trait]
+impl IdempotencyStore for InMemoryIdempotencyStore {
+    async fn reserve(&self, key: &str) -> Result<Reservation, error::ClientError> {
+        let now = Instant::now();
+        let mut entries = self.lock();
+        entries.retain(|_, (_, expires_at)| *expires_at > now);
+
+        if let Some((reservation, _)) = entries.get(key) {
+            return Ok(*reservation);
+        }
+        entries.insert(key.to_string(), (Reservation::InProgress, now + self.ttl));
+
+        Ok(Reservation::Reserved)
+    }
+
+    async fn complete(&self, key: &str, signature: Signature) -> Result<(), error::ClientError> {
+        self.lock().insert(
+            key.to_string(),
+            (Reservation::Completed(signature), Instant::now() + self.ttl),
+        );
+
+        Ok(())
+    }
+
+    async fn release(&self, key: &str) -> Result<(), error::ClientError> {
+        self.lock().remove(key);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        common::types::{Cluster, PriorityFee},
+        utils::CreateTokenMetadata,
+        PumpFun,
+    };
+    use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_in_memory_idempotency_store() {
+        let store = InMemoryIdempotencyStore::default();
+        let signature = Signature::new_unique();
+
+        // The first caller reserves the key, retries see the launch running
+        assert_eq!(store.reserve("a").await.unwrap(), Reservation::Reserved);
+        assert_eq!(store.reserve("a").await.unwrap(), Reservation::InProgress);
+        assert_eq!(store.reserve("b").await.unwrap(), Reservation::Reserved);
+
+        // Retries after success get the signature of the launch
+        store.complete("a", signature).await.unwrap();
+        assert_eq!(
+            store.reserve("a").await.unwrap(),
+            Reservation::Completed(signature)
+        );
+
+        // Failed launches can be retried
+        store.release("b").await.unwrap();
+        assert_eq!(store.reserve("b").await.unwrap(), Reservation::Reserved);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_idempotency_store_expires_keys() {
+        let store = InMemoryIdempotencyStore::new(Duration::from_millis(20));
+        store.reserve("a").await.unwrap();
+        store.complete("a", Signature::new_unique()).await.unwrap();
+
+        tokio::time::sleep(Duration::from_millis(40)).await;
+        assert_eq!(store.reserve("a").await.unwrap(), Reservation::Reserved);
+    }
+
+    #[tokio::test]
+    async fn test_create_and_buy_idempotent() {
+        // Nothing listens on this port, so launches fail without reaching a cluster
+        let cluster = Cluster::new(
+            "http://127.0.0.1:1".to_string(),
+            "ws://127.0.0.1:1".to_string(),
+            CommitmentConfig::confirmed(),
+            PriorityFee::default(),
+        );
+        let store = Arc::new(InMemoryIdempotencyStore::default());
+        let client =
+            PumpFun::new(Arc::new(Keypair::new()), cluster).with_idempotency_store(store.clone());
+        let launch = |key: &'static str| {
+            let client = client.clone();
+            async move {
+                client
+                    .create_and_buy_idempotent(
+                        key,
+                        Keypair::new(),
+                        CreateTokenMetadata::default(),
+                        1_000_000,
+                        None,
+                        None,
+                        None,
+                    )
+                    .await
+            }
+        };
+
+        // Completed launches are not repeated
+        let signature = Signature::new_unique();
+        store.reserve("done").await.unwrap();
+        store.complete("done", signature).await.unwrap();
+        assert_eq!(launch("done").await.unwrap(), signature);
+
+        // Running launches are not joined
+        store.reserve("running").await.unwrap();
+        assert!(matches!(
+            launch("running").await,
+            Err(error::ClientError::LaunchInProgress(key)) if key == "running"
+        ));
+
+        // Launches failing before their transaction is sent release their key
+        assert!(launch("failing").await.is_err());
+        assert_eq!(
+            store.reserve("failing").await.unwrap(),
+            Reservation::Reserved
+        );
+    }
+
+    /// Store that reserves every key but cannot record outcomes
+    struct UnavailableStore;
+
+    #[async_trait]
+    impl IdempotencyStore for UnavailableStore {
+        async fn reserve(&self, _key: &str) -> Result<Reservation, error::ClientError> {
+            Ok(Reservation::Reserved)
+        }
+
+        async fn complete(
+            &self,
+            _key: &str,
+            _signature: Signature,
+        ) -> Result<(), error::ClientError> {
+            Err(error::ClientError::OtherError(
+                "store unavailable".to_string(),
+            ))
+        }
+
+        async fn release(&self, _key: &str) -> Result<(), error::ClientError> {
+            Err(error::ClientError::OtherError(
+                "store unavailable".to_string(),
+            ))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_and_buy_idempotent_keeps_launch_error() {
+        let cluster = Cluster::new(
+            "http://127.0.0.1:1".to_string(),
+            "ws://127.0.0.1:1".to_string(),
+            CommitmentConfig::confirmed(),
+            PriorityFee::default(),
+        );
+        let client = PumpFun::new(Arc::new(Keypair::new()), cluster)
+            .with_idempotency_store(Arc::new(UnavailableStore));
+
+        // The launch error is returned rather than the failure to release the key
+        let result = client
+            .create_and_buy_idempotent(
+                "failing",
+                Keypair::new(),
+                CreateTokenMetadata::default(),
+                1_000_000,
+                None,
+                None,
+                None,
+            )
+            .await;
+        assert!(matches!(
+            result,
+            Err(err) if !matches!(err, error::ClientError::OtherError(_))
+        ));
+    }
+}
@@ -0,0 +1,330 @@
+//! Launch transactions prepared ahead of time
+//!
+//! Sending a create and buy transaction normally uploads the metadata, fetches accounts and
+//! builds the transaction before anything reaches the network. A [`PreparedLaunch`] does
+//! all of that up front with [`PumpFun::prepare_launch`](crate::PumpFun::prepare_launch),
+//! so that firing it only swaps in a recent blockhash, re-signs and sends.
+//!
+//! Drops of many tokens at once go through
+//! [`PumpFun::create_batch`](crate::PumpFun::create_batch), which takes a [`CreateSpec`]
+//! per token and reports a [`LaunchResult`] or an error for each of them.
+
+use crate::{
+    common::types::PriorityFee,
+    error,
+    utils::{transaction::send_and_confirm_transaction, CreateTokenMetadata},
+};
+
+use solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcSendTransactionConfig};
+use solana_sdk::{
+    hash::Hash,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::Signer,
+    transaction::Transaction,
+};
+use std::sync::Arc;
+
+/// Create and buy transaction signed in advance, ready to be sent
+///
+/// The transaction is compiled and signed against a placeholder blockhash when prepared.
+/// It can be fired any number of times, e.g. to retry after the blockhash expired; every
+/// attempt re-signs it with a fresh blockhash.
+pub struct PreparedLaunch {
+    rpc: Arc<RpcClient>,
+    payer: Arc<Keypair>,
+    mint: Keypair,
+    transaction: Transaction,
+    send_config: Option<RpcSendTransactionConfig>,
+}
+
+impl std::fmt::Debug for PreparedLaunch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PreparedLaunch")
+            .field("payer", &self.payer.pubkey())
+            .field("mint", &self.mint.pubkey())
+            .field("instructions", &self.transaction.message.instructions.len())
+            .finish()
+    }
+}
+
+impl PreparedLaunch {
+    /// Compiles and signs `instructions` against a placeholder blockhash
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc` - RPC client used to fetch the blockhash and send the transaction
+    /// * `payer` - Keypair paying for the transaction
+    /// * `mint` - Keypair of the mint created by the transaction
+    /// * `instructions` - Create and buy instructions, including the priority fee ones
+    pub fn new(
+        rpc: Arc<RpcClient>,
+        payer: Arc<Keypair>,
+        mint: Keypair,
+        instructions: &[Instruction],
+    ) -> Self {
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&payer.pubkey()),
+            &[&*payer, &mint],
+            Hash::default(),
+        );
+
+        Self {
+            rpc,
+            payer,
+            mint,
+            transaction,
+            send_config: None,
+        }
+    }
+
+    /// Sets the RPC options used when firing, e.g. to skip preflight
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Send options, or `None` to simulate the transaction before sending it
+    pub fn with_send_config(mut self, config: Option<RpcSendTransactionConfig>) -> Self {
+        self.send_config = config;
+        self
+    }
+
+    /// Returns the address of the mint created by the launch
+    pub fn mint(&self) -> Pubkey {
+        self.mint.pubkey()
+    }
+
+    /// Fetches a recent blockhash, re-signs the transaction and sends it
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature once confirmed, or a ClientError if the blockhash
+    /// cannot be fetched or the transaction fails
+    pub async fn fire(&self) -> Result<Signature, error::ClientError> {
+        let blockhash = self
+            .rpc
+            .get_latest_blockhash()
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        self.fire_with_blockhash(blockhash).await
+    }
+
+    /// Re-signs the transaction with the given blockhash and sends it
+    ///
+    /// Skips the blockhash request of [`PreparedLaunch::fire`] for callers that already
+    /// track recent blockhashes, e.g. from a slot subscription.
+    ///
+    /// # Arguments
+    ///
+    /// * `blockhash` - Recent blockhash to send the transaction with
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature once confirmed, or a ClientError if the transaction
+    /// fails
+    pub async fn fire_with_blockhash(
+        &self,
+        blockhash: Hash,
+    ) -> Result<Signature, error::ClientError> {
+        let transaction = self.signed_with_blockhash(blockhash)?;
+
+        send_and_confirm_transaction(&self.rpc, &transaction, self.send_config).await
+    }
+
+    /// Returns a copy of the transaction re-signed with the given blockhash
+    #[allow(clippy::result_large_err)]
+    fn signed_with_blockhash(&self, blockhash: Hash) -> Result<Transaction, error::ClientError> {
+        let mut transaction = self.transaction.clone();
+        transaction
+            .try_sign(&[&*self.payer, &self.mint], blockhash)
+            .map_err(|err| {
+                error::ClientError::OtherError(format!("Failed to sign launch: {}", err))
+            })?;
+
+        Ok(transaction)
+    }
+}
+
+/// Token to launch as part of a batch
+///
+/// # Fields
+///
+/// * `mint` - Keypair of the new token mint
+/// * `metadata` - Token metadata including name, symbol, description and image file
+/// * `priority_fee` - Optional priority fee of the create transaction. If None, the client's
+///   priority fee strategy or cluster configuration is used
+pub struct CreateSpec {
+    pub mint: Keypair,
+    pub metadata: CreateTokenMetadata,
+    pub priority_fee: Option<PriorityFee>,
+}
+
+impl std::fmt::Debug for CreateSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CreateSpec")
+            .field("mint", &self.mint.pubkey())
+            .field("metadata", &self.metadata)
+            .field("priority_fee", &self.priority_fee)
+            .finish()
+    }
+}
+
+impl CreateSpec {
+    /// Creates a spec launching `metadata` under a freshly generated mint
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    pub fn new(metadata: CreateTokenMetadata) -> Self {
+        Self {
+            mint: Keypair::new(),
+            metadata,
+            priority_fee: None,
+        }
+    }
+
+    /// Launches the token under the given mint instead, e.g. a vanity address
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Keypair of the new token mint
+    pub fn with_mint(mut self, mint: Keypair) -> Self {
+        self.mint = mint;
+        self
+    }
+
+    /// Sets the priority fee of the create transaction
+    ///
+    /// # Arguments
+    ///
+    /// * `priority_fee` - Compute unit limit and price of the transaction
+    pub fn with_priority_fee(mut self, priority_fee: PriorityFee) -> Self {
+        self.priority_fee = Some(priority_fee);
+        self
+    }
+}
+
+/// Token launched by a batch
+///
+/// # Fields
+///
+/// * `mint` - Address of the created mint
+/// * `signature` - Signature of the confirmed create transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LaunchResult {
+    pub mint: Pubkey,
+    pub signature: Signature,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{common::types::Cluster, utils::uploader::MetadataUploader, PumpFun};
+    use async_trait::async_trait;
+    use solana_sdk::{commitment_config::CommitmentConfig, instruction::AccountMeta};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Uploader failing every upload, recording how many run at the same time
+    #[derive(Default)]
+    struct FailingUploader {
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl MetadataUploader for FailingUploader {
+        async fn upload(
+            &self,
+            metadata: &CreateTokenMetadata,
+        ) -> Result<String, error::ClientError> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            Err(error::ClientError::OtherError(format!(
+                "No image for {}",
+                metadata.symbol
+            )))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_batch_isolates_failures() {
+        let uploader = Arc::new(FailingUploader::default());
+        let cluster = Cluster::localnet(CommitmentConfig::confirmed(), PriorityFee::default());
+        let client = PumpFun::new(Arc::new(Keypair::new()), cluster)
+            .with_metadata_uploader(uploader.clone());
+
+        let specs: Vec<_> = (0..5)
+            .map(|i| {
+                CreateSpec::new(CreateTokenMetadata {
+                    symbol: format!("TKN{}", i),
+                    ..Default::default()
+                })
+            })
+            .collect();
+
+        let results = client.create_batch(specs, 2).await;
+        assert_eq!(results.len(), 5);
+        for (i, result) in results.iter().enumerate() {
+            match result {
+                Err(error::ClientError::OtherError(message)) => {
+                    assert_eq!(message, &format!("No image for TKN{}", i))
+                }
+                other => panic!("unexpected result: {:?}", other),
+            }
+        }
+        assert_eq!(uploader.max_in_flight.load(Ordering::SeqCst), 2);
+
+        // Zero concurrency still runs the launches, one at a time
+        let uploader = Arc::new(FailingUploader::default());
+        let client = client.with_metadata_uploader(uploader.clone());
+        let specs = vec![
+            CreateSpec::new(CreateTokenMetadata::default()),
+            CreateSpec::new(CreateTokenMetadata::default()),
+        ];
+        assert!(client
+            .create_batch(specs, 0)
+            .await
+            .iter()
+            .all(Result::is_err));
+        assert_eq!(uploader.max_in_flight.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_prepared_launch_signed_with_blockhash() {
+        let payer = Arc::new(Keypair::new());
+        let mint = Keypair::new();
+        let mint_pubkey = mint.pubkey();
+        let rpc = Arc::new(RpcClient::new("http://localhost:8899".to_string()));
+        let instructions = [Instruction::new_with_bytes(
+            crate::constants::accounts::PUMPFUN,
+            &[0; 8],
+            vec![
+                AccountMeta::new(mint_pubkey, true),
+                AccountMeta::new(payer.pubkey(), true),
+            ],
+        )];
+
+        let launch = PreparedLaunch::new(rpc, payer.clone(), mint, &instructions);
+        assert_eq!(launch.mint(), mint_pubkey);
+        assert_eq!(launch.transaction.message.recent_blockhash, Hash::default());
+
+        let blockhash = Hash::new_unique();
+        let transaction = launch.signed_with_blockhash(blockhash).unwrap();
+        assert_eq!(transaction.message.recent_blockhash, blockhash);
+        assert_eq!(
+            transaction.message.instructions,
+            launch.transaction.message.instructions
+        );
+        assert_eq!(transaction.signatures.len(), 2);
+        assert!(transaction.verify().is_ok());
+        assert_ne!(transaction.signatures, launch.transaction.signatures);
+
+        // The prepared transaction itself is left untouched
+        assert_eq!(launch.transaction.message.recent_blockhash, Hash::default());
+    }
+}
@@ -1,3 +1,7 @@
+pub mod idempotency;
+pub mod launch;
+pub mod priority_fee;
 #[cfg(feature = "stream")]
 pub mod stream;
+pub mod token_index;
 pub mod types;
@@ -0,0 +1,214 @@
+//! Adaptive compute unit prices for priority fees
+//!
+//! A [`PriorityFeeStrategy`] picks the compute unit price of a transaction when no explicit
+//! [`PriorityFee`](super::types::PriorityFee) is passed to a send method, instead of the
+//! static price configured on the cluster. [`PercentileFeeStrategy`] derives the price from
+//! `getRecentPrioritizationFees`; implement the trait to use another source, e.g. a fee API
+//! of an RPC provider.
+
+use crate::error;
+use async_trait::async_trait;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+
+/// Source of compute unit prices for outgoing transactions
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_trait::async_trait;
+/// use pumpfun::{common::priority_fee::PriorityFeeStrategy, error::ClientError};
+/// use solana_client::nonblocking::rpc_client::RpcClient;
+/// use solana_sdk::pubkey::Pubkey;
+///
+/// /// Pays a fixed price, e.g. fetched from a provider's fee API
+/// struct FixedFee(u64);
+///
+/// #[async_trait]
+/// impl PriorityFeeStrategy for FixedFee {
+///     async fn unit_price(
+///         &self,
+///         _rpc: &RpcClient,
+///         _accounts: &[Pubkey],
+///     ) -> Result<Option<u64>, ClientError> {
+///         Ok(Some(self.0))
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait PriorityFeeStrategy: Send + Sync {
+    /// Returns the compute unit price to pay
+    ///
+    /// # Arguments
+    ///
+    /// * `rpc` - RPC client of the sending `PumpFun` client
+    /// * `accounts` - Accounts written by the transaction, whose recent fees are relevant
+    ///
+    /// # Returns
+    ///
+    /// Returns the price in micro-lamports per compute unit, `None` to keep the cluster's
+    /// configured price, or a ClientError if the price cannot be determined
+    async fn unit_price(
+        &self,
+        rpc: &RpcClient,
+        accounts: &[Pubkey],
+    ) -> Result<Option<u64>, error::ClientError>;
+}
+
+/// Strategy paying a percentile of the recent prioritization fees of the written accounts
+///
+/// Queries `getRecentPrioritizationFees`, which reports the lowest fee that landed a
+/// transaction writing the given accounts in each of the recent slots, and pays the
+/// configured percentile of those fees, clamped to `[min_unit_price, max_unit_price]`.
+///
+/// # Fields
+///
+/// * `percentile` - Percentile of the recent fees to pay, from 0 to 100
+/// * `min_unit_price` - Lowest price to pay, in micro-lamports per compute unit
+/// * `max_unit_price` - Optional highest price to pay, in micro-lamports per compute unit
+///
+/// # Examples
+///
+/// ```no_run
+/// use pumpfun::{
+///     common::{priority_fee::PercentileFeeStrategy, types::{Cluster, PriorityFee}},
+///     PumpFun,
+/// };
+/// use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+/// use std::sync::Arc;
+///
+/// let cluster = Cluster::mainnet(CommitmentConfig::confirmed(), PriorityFee::default());
+/// let client = PumpFun::new(Arc::new(Keypair::new()), cluster).with_priority_fee_strategy(
+///     Arc::new(PercentileFeeStrategy::new(90).with_max_unit_price(1_000_000)),
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PercentileFeeStrategy {
+    pub percentile: u8,
+    pub min_unit_price: u64,
+    pub max_unit_price: Option<u64>,
+}
+
+impl Default for PercentileFeeStrategy {
+    fn default() -> Self {
+        Self::new(75)
+    }
+}
+
+impl PercentileFeeStrategy {
+    /// Creates a strategy paying the given percentile of recent fees, without bounds
+    ///
+    /// # Arguments
+    ///
+    /// * `percentile` - Percentile of the recent fees to pay, capped at 100
+    pub fn new(percentile: u8) -> Self {
+        Self {
+            percentile: percentile.min(100),
+            min_unit_price: 0,
+            max_unit_price: None,
+        }
+    }
+
+    /// Sets the lowest price to pay, in micro-lamports per compute unit
+    pub fn with_min_unit_price(mut self, min_unit_price: u64) -> Self {
+        self.min_unit_price = min_unit_price;
+        self
+    }
+
+    /// Sets the highest price to pay, in micro-lamports per compute unit
+    pub fn with_max_unit_price(mut self, max_unit_price: u64) -> Self {
+        self.max_unit_price = Some(max_unit_price);
+        self
+    }
+
+    /// Computes the price to pay for the given recent fees
+    ///
+    /// Uses the nearest-rank percentile of `fees`, so the result is always one of the
+    /// observed fees before clamping. Without any fees the minimum price is paid.
+    ///
+    /// # Arguments
+    ///
+    /// * `fees` - Recent prioritization fees, in micro-lamports per compute unit
+    ///
+    /// # Returns
+    ///
+    /// The price to pay, in micro-lamports per compute unit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pumpfun::common::priority_fee::PercentileFeeStrategy;
+    ///
+    /// let strategy = PercentileFeeStrategy::new(50).with_max_unit_price(350);
+    /// assert_eq!(strategy.price_for(&[400, 100, 300, 200]), 200);
+    /// assert_eq!(strategy.price_for(&[400, 500]), 350);
+    /// ```
+    pub fn price_for(&self, fees: &[u64]) -> u64 {
+        let mut fees = fees.to_vec();
+        fees.sort_unstable();
+
+        let price = match fees.len() {
+            0 => 0,
+            len => {
+                let rank = (len * self.percentile.min(100) as usize).div_ceil(100);
+                fees[rank.saturating_sub(1)]
+            }
+        };
+
+        let price = price.max(self.min_unit_price);
+        match self.max_unit_price {
+            Some(max_unit_price) => price.min(max_unit_price),
+            None => price,
+        }
+    }
+}
+
+#[async_trait]
+impl PriorityFeeStrategy for PercentileFeeStrategy {
+    async fn unit_price(
+        &self,
+        rpc: &RpcClient,
+        accounts: &[Pubkey],
+    ) -> Result<Option<u64>, error::ClientError> {
+        let fees: Vec<u64> = rpc
+            .get_recent_prioritization_fees(accounts)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?
+            .iter()
+            .map(|fee| fee.prioritization_fee)
+            .collect();
+
+        Ok(Some(self.price_for(&fees)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_percentile_fee_strategy_price_for() {
+        let fees: Vec<u64> = (1..=10).map(|fee| fee * 100).collect();
+
+        assert_eq!(PercentileFeeStrategy::new(0).price_for(&fees), 100);
+        assert_eq!(PercentileFeeStrategy::new(50).price_for(&fees), 500);
+        assert_eq!(PercentileFeeStrategy::new(75).price_for(&fees), 800);
+        assert_eq!(PercentileFeeStrategy::new(90).price_for(&fees), 900);
+        assert_eq!(PercentileFeeStrategy::new(100).price_for(&fees), 1000);
+        assert_eq!(PercentileFeeStrategy::new(200).price_for(&fees), 1000);
+
+        // Order of the samples does not matter
+        let mut shuffled = fees.clone();
+        shuffled.reverse();
+        assert_eq!(PercentileFeeStrategy::new(75).price_for(&shuffled), 800);
+
+        // Bounds
+        let strategy = PercentileFeeStrategy::new(50)
+            .with_min_unit_price(600)
+            .with_max_unit_price(700);
+        assert_eq!(strategy.price_for(&fees), 600);
+        assert_eq!(strategy.price_for(&[]), 600);
+        assert_eq!(strategy.price_for(&[5_000]), 700);
+        assert_eq!(PercentileFeeStrategy::default().price_for(&[]), 0);
+    }
+}
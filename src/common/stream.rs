@@ -9,7 +9,10 @@ use solana_client::{
     rpc_config::{RpcTransactionLogsConfig, RpcTransactionLogsFilter},
     rpc_response::{Response, RpcLogsResponse},
 };
-use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey};
+use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature};
+use solana_transaction_status_client_types::{
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction,
+};
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 
@@ -25,9 +28,13 @@ pub struct CreateEvent {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub mint: Pubkey,
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub bonding_curve: Pubkey,
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub user: Pubkey,
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub creator: Pubkey,
     pub timestamp: i64,
     pub virtual_token_reserves: u64,
@@ -42,19 +49,23 @@ pub struct CreateEvent {
 /// exchanged, the type of trade (buy/sell), and the updated bonding curve state.
 #[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize)]
 pub struct TradeEvent {
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub mint: Pubkey,
     pub sol_amount: u64,
     pub token_amount: u64,
     pub is_buy: bool,
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub user: Pubkey,
     pub timestamp: i64,
     pub virtual_sol_reserves: u64,
     pub virtual_token_reserves: u64,
     pub real_sol_reserves: u64,
     pub real_token_reserves: u64,
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub fee_recipient: Pubkey,
     pub fee_basis_points: u64,
     pub fee: u64,
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub creator: Pubkey,
     pub creator_fee_basis_points: u64,
     pub creator_fee: u64,
@@ -71,8 +82,11 @@ pub struct TradeEvent {
 /// providing information about the involved accounts.
 #[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize)]
 pub struct CompleteEvent {
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub user: Pubkey,
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub mint: Pubkey,
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub bonding_curve: Pubkey,
     pub timestamp: i64,
 }
@@ -80,7 +94,10 @@ pub struct CompleteEvent {
 /// Event emitted when global parameters are updated
 ///
 /// This event contains information about updates to the global program parameters,
-/// including fee settings and initial bonding curve configuration values.
+/// including fee settings and initial bonding curve configuration values. It is delivered
+/// as [`PumpFunEvent::SetParams`] by [`subscribe`], so a cached
+/// [`GlobalAccount`](crate::accounts::GlobalAccount) can be kept up to date with
+/// [`SetParamsEvent::apply_to`] instead of being refetched periodically.
 #[derive(BorshSerialize, BorshDeserialize, Debug, Serialize, Deserialize)]
 pub struct SetParamsEvent {
     pub initial_virtual_token_reserves: u64,
@@ -89,16 +106,48 @@ pub struct SetParamsEvent {
     pub final_real_sol_reserves: u64,
     pub token_total_supply: u64,
     pub fee_basis_points: u64,
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub withdraw_authority: Pubkey,
     pub enable_migrate: bool,
     pub pool_migration_fee: u64,
     pub creator_fee_basis_points: u64,
+    #[serde(with = "crate::utils::serde_pubkey::array")]
     pub fee_recipients: [Pubkey; 8],
     pub timestamp: i64,
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub set_creator_authority: Pubkey,
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub admin_set_creator_authority: Pubkey,
 }
 
+impl SetParamsEvent {
+    /// Updates a cached global account with the new parameters
+    ///
+    /// The first of the event's `fee_recipients` is the main `fee_recipient` of the global
+    /// account and the others fill its `fee_recipients` array. Fields that are not part of
+    /// the event, such as the authority, are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `global` - Global account to update, e.g. from `PumpFun::get_global_account`
+    pub fn apply_to(&self, global: &mut crate::accounts::GlobalAccount) {
+        global.initial_virtual_token_reserves = self.initial_virtual_token_reserves;
+        global.initial_virtual_sol_reserves = self.initial_virtual_sol_reserves;
+        global.initial_real_token_reserves = self.initial_real_token_reserves;
+        global.token_total_supply = self.token_total_supply;
+        global.fee_basis_points = self.fee_basis_points;
+        global.withdraw_authority = self.withdraw_authority;
+        global.enable_migrate = self.enable_migrate;
+        global.pool_migration_fee = self.pool_migration_fee;
+        global.creator_fee_basis_points = self.creator_fee_basis_points;
+        global.fee_recipient = self.fee_recipients[0];
+        global
+            .fee_recipients
+            .copy_from_slice(&self.fee_recipients[1..]);
+        global.set_creator_authority = self.set_creator_authority;
+    }
+}
+
 /// Enum representing all possible event types emitted by the Pump.fun program
 ///
 /// This enum acts as a container for the different event types that can be
@@ -204,6 +253,206 @@ pub fn parse_event(
     }
 }
 
+/// Extracts the `CreateEvent`s from the log messages of a transaction
+///
+/// Log lines that are not `Program data:` payloads, or that fail to decode, are skipped.
+///
+/// # Arguments
+///
+/// * `signature` - Transaction signature associated with the logs
+/// * `logs` - Log messages of the transaction
+///
+/// # Returns
+///
+/// Returns the create events in the order they were emitted
+pub fn create_events_from_logs(signature: &str, logs: &[String]) -> Vec<CreateEvent> {
+    logs.iter()
+        .filter_map(|line| line.strip_prefix("Program data: "))
+        .filter_map(|data| match parse_event(signature, data) {
+            Ok(PumpFunEvent::Create(event)) => Some(event),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Extracts the events emitted by the Pump.fun program from the log messages of a transaction
+///
+/// Program invocations are tracked through the `invoke`, `success` and `failed` log lines,
+/// so only `Program data:` payloads logged while the Pump.fun program is executing are
+/// decoded and data logged by other programs is ignored. Payloads that fail to decode are
+/// skipped.
+///
+/// # Arguments
+///
+/// * `signature` - Transaction signature associated with the logs
+/// * `logs` - Log messages of the transaction
+///
+/// # Returns
+///
+/// Returns the events in the order they were emitted
+pub fn events_from_logs(signature: &str, logs: &[String]) -> Vec<PumpFunEvent> {
+    pumpfun_log_data(logs)
+        .into_iter()
+        .filter_map(|data| parse_event(signature, data).ok())
+        .collect()
+}
+
+/// Extracts the events emitted by the Pump.fun program, failing on payloads that do not decode
+///
+/// Strict counterpart of [`events_from_logs`], for callers that must not mistake a payload
+/// they cannot decode, e.g. after a program upgrade, for the absence of events.
+///
+/// # Arguments
+///
+/// * `signature` - Transaction signature associated with the logs
+/// * `logs` - Log messages of the transaction
+///
+/// # Returns
+///
+/// Returns the events in the order they were emitted, or the error of the first Pump.fun
+/// payload that fails to decode
+pub fn try_events_from_logs(
+    signature: &str,
+    logs: &[String],
+) -> Result<Vec<PumpFunEvent>, Box<dyn Error + Send + Sync>> {
+    pumpfun_log_data(logs)
+        .into_iter()
+        .map(|data| parse_event(signature, data))
+        .collect()
+}
+
+/// Returns the `Program data:` payloads logged while the Pump.fun program is executing
+fn pumpfun_log_data(logs: &[String]) -> Vec<&str> {
+    let pumpfun = constants::accounts::PUMPFUN.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut payloads = Vec::new();
+
+    for line in logs {
+        if let Some(data) = line.strip_prefix("Program data: ") {
+            if stack.last() == Some(&pumpfun.as_str()) {
+                payloads.push(data);
+            }
+        } else if let Some(rest) = line.strip_prefix("Program ") {
+            let mut parts = rest.split_whitespace();
+            match (parts.next(), parts.next()) {
+                (Some(program), Some("invoke")) => stack.push(program),
+                (Some(_), Some("success" | "failed:")) => {
+                    stack.pop();
+                }
+                _ => {}
+            }
+        }
+    }
+
+    payloads
+}
+
+/// Extracts the Pump.fun events of a fetched transaction
+///
+/// Transactions without log messages or without a Pump.fun invocation are skipped before
+/// their signature is decoded.
+///
+/// # Arguments
+///
+/// * `transaction` - Transaction as returned by `getTransaction`, in any encoding
+///
+/// # Returns
+///
+/// Returns the transaction signature with its events in emission order, or `None` if the
+/// transaction emitted no Pump.fun event or its signature cannot be read
+pub fn parse_events_from_transaction(
+    transaction: &EncodedConfirmedTransactionWithStatusMeta,
+) -> Option<(Signature, Vec<PumpFunEvent>)> {
+    let meta = transaction.transaction.meta.as_ref()?;
+    let logs: &Vec<String> = Option::from(meta.log_messages.as_ref())?;
+
+    let invoke = format!("Program {} invoke", constants::accounts::PUMPFUN);
+    if !logs.iter().any(|line| line.starts_with(&invoke)) {
+        return None;
+    }
+
+    let signature = match &transaction.transaction.transaction {
+        EncodedTransaction::Json(ui) => ui.signatures.first()?.parse().ok()?,
+        EncodedTransaction::Accounts(list) => list.signatures.first()?.parse().ok()?,
+        encoded => *encoded.decode()?.signatures.first()?,
+    };
+
+    let events = events_from_logs(&signature.to_string(), logs);
+    if events.is_empty() {
+        return None;
+    }
+
+    Some((signature, events))
+}
+
+/// Extracts the Pump.fun events of a batch of fetched transactions
+///
+/// Applies [`parse_events_from_transaction`] to every transaction. Large batches are split
+/// across the available CPU cores with scoped threads.
+///
+/// # Arguments
+///
+/// * `transactions` - Transactions as returned by `getTransaction` or `getBlock`
+///
+/// # Returns
+///
+/// Returns each transaction's signature with its events, in the order of `transactions`.
+/// Transactions without Pump.fun events are left out.
+///
+/// # Examples
+///
+/// ```no_run
+/// use pumpfun::common::stream::{parse_events_from_transactions, PumpFunEvent};
+/// # use solana_transaction_status_client_types::EncodedConfirmedTransactionWithStatusMeta;
+/// # let transactions: Vec<EncodedConfirmedTransactionWithStatusMeta> = Vec::new();
+///
+/// for (signature, events) in parse_events_from_transactions(&transactions) {
+///     for event in events {
+///         if let PumpFunEvent::Trade(trade) = event {
+///             println!("{}: {} lamports for {}", signature, trade.sol_amount, trade.mint);
+///         }
+///     }
+/// }
+/// ```
+pub fn parse_events_from_transactions(
+    transactions: &[EncodedConfirmedTransactionWithStatusMeta],
+) -> Vec<(Signature, Vec<PumpFunEvent>)> {
+    // Below this size, spawning threads costs more than decoding
+    const PARALLEL_THRESHOLD: usize = 64;
+
+    let threads = std::thread::available_parallelism().map_or(1, usize::from);
+    if transactions.len() < PARALLEL_THRESHOLD || threads == 1 {
+        return transactions
+            .iter()
+            .filter_map(parse_events_from_transaction)
+            .collect();
+    }
+
+    let chunk_size = transactions.len().div_ceil(threads);
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = transactions
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .filter_map(parse_events_from_transaction)
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|err| std::panic::resume_unwind(err))
+            })
+            .collect()
+    })
+}
+
 /// Subscribes to Pump.fun program events emitted on-chain
 ///
 /// This function establishes a WebSocket connection to the Solana cluster and
@@ -347,6 +596,139 @@ where
     ))
 }
 
+/// Number of create events buffered by [`subscribe_creates`] before it stops reading
+const CREATE_BUFFER: usize = 1024;
+
+/// Longest delay between two reconnection attempts of [`subscribe_creates`]
+const MAX_RECONNECT_DELAY: std::time::Duration = std::time::Duration::from_secs(30);
+
+/// Stream of the tokens launched on Pump.fun, returned by [`subscribe_creates`]
+///
+/// The WebSocket subscription runs in a background task, which is stopped when the stream
+/// is dropped.
+struct CreateStream {
+    receiver: mpsc::Receiver<CreateEvent>,
+    task: JoinHandle<()>,
+}
+
+impl futures::Stream for CreateStream {
+    type Item = CreateEvent;
+
+    fn poll_next(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Self::Item>> {
+        self.receiver.poll_recv(cx)
+    }
+}
+
+impl Drop for CreateStream {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Extracts the create events of a successful transaction from its logs notification
+fn launches(logs: &RpcLogsResponse) -> Vec<CreateEvent> {
+    if logs.err.is_some() {
+        return Vec::new();
+    }
+
+    events_from_logs(&logs.signature, &logs.logs)
+        .into_iter()
+        .filter_map(|event| match event {
+            PumpFunEvent::Create(event) => Some(event),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Subscribes to every token launched on Pump.fun
+///
+/// Subscribes to the logs of all transactions mentioning the Pump.fun program and yields the
+/// `CreateEvent`s of the successful ones, in the order they are received.
+///
+/// Up to 1024 events are buffered. When the consumer falls behind, the subscription stops
+/// reading until there is room again, so events are never dropped by the client; the RPC node
+/// may however close a subscription that is not read fast enough. Whenever the connection is
+/// closed or cannot be established, the subscription is reopened with an exponential backoff
+/// of up to 30 seconds. Transactions landing while disconnected are missed, use
+/// [`PumpFun::recent_creates`](crate::PumpFun::recent_creates) to backfill them.
+///
+/// Must be called from within a Tokio runtime.
+///
+/// # Arguments
+///
+/// * `cluster` - Solana cluster configuration containing RPC endpoints
+/// * `commitment` - Optional commitment level for the subscription. If None, uses the
+///   default from the cluster configuration
+///
+/// # Returns
+///
+/// Returns a stream of create events that never ends on its own. Dropping it closes the
+/// subscription.
+///
+/// # Examples
+///
+/// ```no_run
+/// use futures::StreamExt;
+/// use pumpfun::common::types::{Cluster, PriorityFee};
+/// use solana_sdk::commitment_config::CommitmentConfig;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let cluster = Cluster::mainnet(CommitmentConfig::confirmed(), PriorityFee::default());
+///
+///     let mut launches = pumpfun::common::stream::subscribe_creates(cluster, None);
+///     while let Some(event) = launches.next().await {
+///         println!("{} ({}) launched at {}", event.name, event.symbol, event.mint);
+///     }
+/// }
+/// ```
+pub fn subscribe_creates(
+    cluster: Cluster,
+    commitment: Option<CommitmentConfig>,
+) -> impl futures::Stream<Item = CreateEvent> + Send + Unpin {
+    let (tx, receiver) = mpsc::channel(CREATE_BUFFER);
+    let commitment = commitment.unwrap_or(cluster.commitment);
+
+    let task = tokio::spawn(async move {
+        let mut delay = std::time::Duration::from_millis(500);
+
+        while !tx.is_closed() {
+            if let Ok(pubsub_client) = PubsubClient::new(&cluster.rpc.ws).await {
+                if let Ok((mut stream, _unsubscribe)) = pubsub_client
+                    .logs_subscribe(
+                        RpcTransactionLogsFilter::Mentions(vec![
+                            constants::accounts::PUMPFUN.to_string()
+                        ]),
+                        RpcTransactionLogsConfig {
+                            commitment: Some(commitment),
+                        },
+                    )
+                    .await
+                {
+                    delay = std::time::Duration::from_millis(500);
+
+                    while let Some(log) = stream.next().await {
+                        for event in launches(&log.value) {
+                            // Waiting for room in the buffer is the backpressure
+                            if tx.send(event).await.is_err() {
+                                return;
+                            }
+                        }
+                    }
+                }
+            }
+
+            crate::utils::sleep(delay).await;
+            delay = (delay * 2).min(MAX_RECONNECT_DELAY);
+        }
+    });
+
+    CreateStream { receiver, task }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::common::types::PriorityFee;
@@ -416,4 +798,243 @@ mod tests {
 
         println!("Received {} events", events.len());
     }
+
+    #[test]
+    fn test_create_events_from_logs() {
+        let event = CreateEvent {
+            name: "Example".to_string(),
+            symbol: "EXM".to_string(),
+            uri: "https://example.com/metadata.json".to_string(),
+            mint: Pubkey::new_unique(),
+            bonding_curve: Pubkey::new_unique(),
+            user: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            timestamp: 1_700_000_000,
+            virtual_token_reserves: 1_073_000_000_000_000,
+            virtual_sol_reserves: 30_000_000_000,
+            real_token_reserves: 793_100_000_000_000,
+            token_total_supply: 1_000_000_000_000_000,
+        };
+        let mut data = vec![27, 114, 169, 77, 222, 235, 99, 118];
+        BorshSerialize::serialize(&event, &mut data).unwrap();
+        let encoded = base64::engine::general_purpose::STANDARD.encode(&data);
+
+        let logs = vec![
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [1]".to_string(),
+            "Program data: not-base64".to_string(),
+            format!("Program data: {}", encoded),
+        ];
+
+        let events = create_events_from_logs("signature", &logs);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].mint, event.mint);
+        assert_eq!(events[0].name, event.name);
+    }
+
+    #[test]
+    fn test_try_events_from_logs() {
+        let logs = vec![
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [1]".to_string(),
+            "Program data: not-base64".to_string(),
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P success".to_string(),
+        ];
+
+        // Undecodable payloads are skipped by the lenient parser only
+        assert!(events_from_logs("signature", &logs).is_empty());
+        assert!(try_events_from_logs("signature", &logs).is_err());
+        assert!(try_events_from_logs("signature", &logs[..1])
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_set_params_event() {
+        let fee_recipients: [Pubkey; 8] = std::array::from_fn(|_| Pubkey::new_unique());
+        let event = SetParamsEvent {
+            initial_virtual_token_reserves: 1_073_000_000_000_000,
+            initial_virtual_sol_reserves: 30_000_000_000,
+            initial_real_token_reserves: 793_100_000_000_000,
+            final_real_sol_reserves: 85_000_000_000,
+            token_total_supply: 1_000_000_000_000_000,
+            fee_basis_points: 95,
+            withdraw_authority: Pubkey::new_unique(),
+            enable_migrate: true,
+            pool_migration_fee: 15_000_001,
+            creator_fee_basis_points: 30,
+            fee_recipients,
+            timestamp: 1_700_000_000,
+            set_creator_authority: Pubkey::new_unique(),
+            admin_set_creator_authority: Pubkey::new_unique(),
+        };
+        let mut data = vec![223, 195, 159, 246, 62, 48, 143, 131];
+        BorshSerialize::serialize(&event, &mut data).unwrap();
+        let encoded = base64::engine::general_purpose::STANDARD.encode(&data);
+
+        let Ok(PumpFunEvent::SetParams(decoded)) = parse_event("signature", &encoded) else {
+            panic!("SetParamsEvent not decoded");
+        };
+        assert_eq!(decoded.fee_basis_points, 95);
+
+        let authority = Pubkey::new_unique();
+        let mut global = crate::accounts::GlobalAccount::new(
+            1,
+            true,
+            authority,
+            Pubkey::new_unique(),
+            0,
+            0,
+            0,
+            0,
+            100,
+            Pubkey::new_unique(),
+            false,
+            0,
+            0,
+            [Pubkey::new_unique(); 7],
+            Pubkey::new_unique(),
+        );
+        decoded.apply_to(&mut global);
+        assert_eq!(global.fee_basis_points, 95);
+        assert_eq!(global.creator_fee_basis_points, 30);
+        assert_eq!(global.initial_virtual_sol_reserves, 30_000_000_000);
+        assert_eq!(global.fee_recipient, fee_recipients[0]);
+        assert_eq!(global.fee_recipients, fee_recipients[1..]);
+        assert_eq!(global.set_creator_authority, event.set_creator_authority);
+        assert_eq!(global.authority, authority);
+    }
+
+    #[test]
+    fn test_launches() {
+        let event = CreateEvent {
+            name: "Example".to_string(),
+            symbol: "EXM".to_string(),
+            uri: "https://example.com/metadata.json".to_string(),
+            mint: Pubkey::new_unique(),
+            bonding_curve: Pubkey::new_unique(),
+            user: Pubkey::new_unique(),
+            creator: Pubkey::new_unique(),
+            timestamp: 1_700_000_000,
+            virtual_token_reserves: 1_073_000_000_000_000,
+            virtual_sol_reserves: 30_000_000_000,
+            real_token_reserves: 793_100_000_000_000,
+            token_total_supply: 1_000_000_000_000_000,
+        };
+        let mut data = vec![27, 114, 169, 77, 222, 235, 99, 118];
+        BorshSerialize::serialize(&event, &mut data).unwrap();
+        let encoded = base64::engine::general_purpose::STANDARD.encode(&data);
+
+        let mut logs = RpcLogsResponse {
+            signature: "signature".to_string(),
+            err: None,
+            logs: vec![
+                "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [1]".to_string(),
+                format!("Program data: {}", encoded),
+                "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P success".to_string(),
+                // Data logged by another program is ignored
+                "Program 11111111111111111111111111111111 invoke [1]".to_string(),
+                format!("Program data: {}", encoded),
+                "Program 11111111111111111111111111111111 success".to_string(),
+            ],
+        };
+
+        let events = launches(&logs);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].mint, event.mint);
+
+        // Failed transactions did not launch anything
+        logs.err = Some(solana_sdk::transaction::TransactionError::AccountInUse);
+        assert!(launches(&logs).is_empty());
+    }
+
+    /// Builds a fetched transaction, in `accounts` encoding, with the given logs
+    fn encoded_transaction(
+        signature: &Signature,
+        logs: Vec<String>,
+    ) -> EncodedConfirmedTransactionWithStatusMeta {
+        serde_json::from_value(serde_json::json!({
+            "slot": 1,
+            "blockTime": null,
+            "transaction": {
+                "signatures": [signature.to_string()],
+                "accountKeys": []
+            },
+            "meta": {
+                "err": null,
+                "status": { "Ok": null },
+                "fee": 5000,
+                "preBalances": [],
+                "postBalances": [],
+                "logMessages": logs
+            }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse_events_from_transactions() {
+        let complete = CompleteEvent {
+            user: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            bonding_curve: Pubkey::new_unique(),
+            timestamp: 1_700_000_000,
+        };
+        let mut data = vec![95, 114, 97, 156, 212, 46, 152, 8];
+        BorshSerialize::serialize(&complete, &mut data).unwrap();
+        let encoded = base64::engine::general_purpose::STANDARD.encode(&data);
+
+        let other = Pubkey::new_unique();
+        let pumpfun_logs = vec![
+            format!("Program {} invoke [1]", other),
+            format!("Program {} invoke [2]", constants::accounts::PUMPFUN),
+            format!("Program data: {}", encoded),
+            format!("Program {} success", constants::accounts::PUMPFUN),
+            // Same payload logged by another program is ignored
+            format!("Program data: {}", encoded),
+            format!("Program {} success", other),
+        ];
+        let other_logs = vec![
+            format!("Program {} invoke [1]", other),
+            format!("Program data: {}", encoded),
+            format!("Program {} success", other),
+        ];
+
+        let signature = Signature::new_unique();
+        let (parsed, events) =
+            parse_events_from_transaction(&encoded_transaction(&signature, pumpfun_logs.clone()))
+                .unwrap();
+        assert_eq!(parsed, signature);
+        assert_eq!(events.len(), 1);
+        assert!(matches!(&events[0], PumpFunEvent::Complete(event) if event.mint == complete.mint));
+        assert!(parse_events_from_transaction(&encoded_transaction(
+            &signature,
+            other_logs.clone()
+        ))
+        .is_none());
+
+        // Large enough for the parallel path, order and association are preserved
+        let signatures: Vec<Signature> = (0..200).map(|_| Signature::new_unique()).collect();
+        let transactions: Vec<_> = signatures
+            .iter()
+            .enumerate()
+            .map(|(i, signature)| match i % 3 {
+                0 => encoded_transaction(signature, other_logs.clone()),
+                _ => encoded_transaction(signature, pumpfun_logs.clone()),
+            })
+            .collect();
+        let parsed = parse_events_from_transactions(&transactions);
+        let expected: Vec<Signature> = signatures
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| i % 3 != 0)
+            .map(|(_, signature)| *signature)
+            .collect();
+        assert_eq!(
+            parsed
+                .iter()
+                .map(|(signature, _)| *signature)
+                .collect::<Vec<_>>(),
+            expected
+        );
+        assert!(parsed.iter().all(|(_, events)| events.len() == 1));
+    }
 }
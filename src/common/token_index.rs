@@ -0,0 +1,199 @@
+//! Lookup of existing tokens resembling a new launch
+//!
+//! Launching a token under the name or symbol of a well-known one is a common way to
+//! impersonate it. A [`TokenIndex`] finds the existing tokens a new name and symbol collide
+//! with, for [`PumpFun::find_similar_tokens`](crate::PumpFun::find_similar_tokens) to warn
+//! creators before they launch. [`RecentCreatesIndex`] searches the latest launches over RPC
+//! (`stream` feature); implement the trait to back the check with a full token index.
+
+use crate::{error, PumpFun};
+use async_trait::async_trait;
+use solana_sdk::pubkey::Pubkey;
+
+/// Source of existing tokens matching a name or symbol
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_trait::async_trait;
+/// use pumpfun::{
+///     common::{
+///         token_index::{is_similar_token, TokenIndex},
+///         types::{Cluster, PriorityFee},
+///     },
+///     error::ClientError,
+///     PumpFun,
+/// };
+/// use solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Keypair};
+/// use std::sync::Arc;
+///
+/// /// Known tokens loaded from a database, as (mint, name, symbol)
+/// struct KnownTokens(Vec<(Pubkey, String, String)>);
+///
+/// #[async_trait]
+/// impl TokenIndex for KnownTokens {
+///     async fn find_similar(
+///         &self,
+///         _client: &PumpFun,
+///         name: &str,
+///         symbol: &str,
+///     ) -> Result<Vec<Pubkey>, ClientError> {
+///         Ok(self
+///             .0
+///             .iter()
+///             .filter(|(_, known_name, known_symbol)| {
+///                 is_similar_token(name, symbol, known_name, known_symbol)
+///             })
+///             .map(|(mint, _, _)| *mint)
+///             .collect())
+///     }
+/// }
+///
+/// let cluster = Cluster::mainnet(CommitmentConfig::confirmed(), PriorityFee::default());
+/// let client = PumpFun::new(Arc::new(Keypair::new()), cluster)
+///     .with_token_index(Arc::new(KnownTokens(Vec::new())));
+/// ```
+#[async_trait]
+pub trait TokenIndex: Send + Sync {
+    /// Finds the existing tokens whose name or symbol resembles the given ones
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Client running the check, e.g. to query its RPC node
+    /// * `name` - Name of the token about to be launched
+    /// * `symbol` - Symbol of the token about to be launched
+    ///
+    /// # Returns
+    ///
+    /// Returns the mints of the matching tokens, or a ClientError if the index cannot be
+    /// queried
+    async fn find_similar(
+        &self,
+        client: &PumpFun,
+        name: &str,
+        symbol: &str,
+    ) -> Result<Vec<Pubkey>, error::ClientError>;
+}
+
+/// Normalizes a name or symbol for comparison
+///
+/// Ignores case, a leading `$` and whitespace at both ends, and treats any run of inner
+/// whitespace as a single space.
+fn normalize(value: &str) -> String {
+    value
+        .trim()
+        .trim_start_matches('$')
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Checks whether two tokens share their name or their symbol
+///
+/// Names and symbols are compared ignoring case, a leading `$` and surrounding or repeated
+/// whitespace, so `" My  Token"` matches `"my token"` and `"$MYTKN"` matches `"mytkn"`.
+/// Empty values never match.
+///
+/// # Arguments
+///
+/// * `name` - Name of the first token
+/// * `symbol` - Symbol of the first token
+/// * `other_name` - Name of the second token
+/// * `other_symbol` - Symbol of the second token
+///
+/// # Returns
+///
+/// Returns `true` if the names or the symbols match
+///
+/// # Examples
+///
+/// ```
+/// use pumpfun::common::token_index::is_similar_token;
+///
+/// assert!(is_similar_token("Bonk", "BONK", "bonk ", "BONK2"));
+/// assert!(is_similar_token("My Bonk", "$bonk", "Bonk", "BONK"));
+/// assert!(!is_similar_token("Bonk", "BONK", "Bonk Inu", "BONKINU"));
+/// ```
+pub fn is_similar_token(name: &str, symbol: &str, other_name: &str, other_symbol: &str) -> bool {
+    let matches = |a: &str, b: &str| {
+        let a = normalize(a);
+        !a.is_empty() && a == normalize(b)
+    };
+
+    matches(name, other_name) || matches(symbol, other_symbol)
+}
+
+/// Index searching the most recent launches on the Pump.fun program
+///
+/// Scans the latest create events with
+/// [`PumpFun::recent_creates`](crate::PumpFun::recent_creates), so it only catches
+/// collisions with tokens launched recently and is read-heavy on the RPC node. Prefer a
+/// dedicated index when one is available.
+///
+/// # Fields
+///
+/// * `limit` - Number of recent launches to search
+#[cfg(feature = "stream")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecentCreatesIndex {
+    pub limit: usize,
+}
+
+#[cfg(feature = "stream")]
+impl RecentCreatesIndex {
+    /// Creates an index searching the given number of recent launches
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Number of recent launches to search
+    pub fn new(limit: usize) -> Self {
+        Self { limit }
+    }
+}
+
+#[cfg(feature = "stream")]
+#[async_trait]
+impl TokenIndex for RecentCreatesIndex {
+    async fn find_similar(
+        &self,
+        client: &PumpFun,
+        name: &str,
+        symbol: &str,
+    ) -> Result<Vec<Pubkey>, error::ClientError> {
+        Ok(client
+            .recent_creates(self.limit)
+            .await?
+            .into_iter()
+            .filter(|event| is_similar_token(name, symbol, &event.name, &event.symbol))
+            .map(|event| event.mint)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_similar_token() {
+        // Names and symbols match independently
+        assert!(is_similar_token("My Token", "AAA", "my token", "BBB"));
+        assert!(is_similar_token("First", "MYTKN", "Second", "mytkn"));
+        assert!(!is_similar_token(
+            "My Token",
+            "MYTKN",
+            "My Tokens",
+            "MYTKN2"
+        ));
+
+        // Case, `$` prefixes and whitespace are ignored
+        assert!(is_similar_token("  My \t Token\n", "X", "MY TOKEN", "Y"));
+        assert!(is_similar_token("A", "$MYTKN", "B", " mytkn "));
+        assert!(!is_similar_token("MyToken", "X", "My Token", "Y"));
+
+        // Empty values never match
+        assert!(!is_similar_token("", "", "", ""));
+        assert!(!is_similar_token(" ", "$", "Other", ""));
+    }
+}
@@ -4,13 +4,29 @@
 //!
 //! - Configuration structures for Solana clusters
 //! - Priority fee settings for transactions
+//! - Per-transaction trade options, slippage modes and associated token account handling
+//! - Trade modes choosing between an exact SOL input and an exact token output
+//! - Fees paid by landed transactions
+//! - Mayhem accounts used by `create_v2`
+//! - Token program variants of Pump.fun mints
+//! - Authenticity checks of Pump.fun mints
+//! - Token positions held by a wallet
+//! - Unsigned transactions built for client-side signing
 //! - Helper methods for connecting to different Solana networks
 //!
 //! These utilities help with configuring the connection to the Solana blockchain
 //! and managing transaction parameters.
 
+use crate::{
+    accounts::{BondingCurveAccount, GlobalAccount},
+    constants, instructions,
+};
 use serde::{Deserialize, Serialize};
-use solana_sdk::commitment_config::CommitmentConfig;
+use solana_client::rpc_config::RpcSendTransactionConfig;
+use solana_sdk::{
+    commitment_config::CommitmentConfig, hash::Hash, instruction::Instruction, pubkey::Pubkey,
+};
+use std::time::Duration;
 
 /// Configuration for priority fee compute unit parameters
 ///
@@ -43,18 +59,571 @@ impl PriorityFee {
     }
 }
 
+/// Per-transaction options for buy and sell operations
+///
+/// Every field is optional and falls back to the value derived from on-chain state,
+/// so `TradeOptions::default()` behaves exactly like the plain `buy`/`sell` methods.
+///
+/// # Fields
+///
+/// * `fee_recipient` - Fee recipient to use instead of `Global.fee_recipient`. Useful when
+///   the program rotates recipients. Must be one of `GlobalAccount::valid_fee_recipients`,
+///   otherwise the trade is rejected with `ClientError::InvalidFeeRecipient`
+/// * `max_price_impact_bps` - Maximum price impact a buy may have, in basis points. Buys
+///   exceeding it are rejected before anything is sent
+/// * `skip_complete_check` - Build the trade even if the bonding curve is complete. By
+///   default such trades are rejected with `ClientError::CurveComplete`, since they would
+///   fail on-chain
+/// * `memo` - Text attached to the transaction as an SPL Memo instruction, e.g. to tag
+///   trades of a bot for analytics
+/// * `ata_mode` - How a buy handles the payer's associated token account. Only used with
+///   the `create-ata` feature
+/// * `slippage` - Slippage protection to use instead of the `slippage_basis_points`
+///   argument of the buy/sell methods, e.g. an absolute limit
+/// * `send_config` - RPC options to send the transaction with instead of the client's
+///   `send_config`, e.g. `skip_preflight` for a snipe
+/// * `blockhash` - Recent blockhash to build the transaction with instead of fetching the
+///   latest one, e.g. from a blockhash cache or a test bank. A blockhash expires about 150
+///   blocks (roughly a minute) after its slot: a stale one is rejected by preflight with
+///   `BlockhashNotFound`, or never lands if preflight is skipped
+/// * `extra_instructions` - Instructions executed atomically with the trade, e.g. a SOL
+///   transfer. They are appended after the trade and memo instructions, in order. They
+///   count towards the compute unit limit of the priority fee, which may need raising
+/// * `global_account` - Global account to quote the trade and pick the fee recipient with
+///   instead of fetching it, e.g. fetched once and reused by a bot across many trades. The
+///   fee settings rarely change, but a stale copy can price the trade wrong
+/// * `bonding_curve` - Bonding curve of the traded mint to quote the trade with instead of
+///   fetching it. It is not checked against the mint, and its reserves go stale with every
+///   trade on the curve, so only reuse it within a short window and with slippage protection
+#[derive(Default, Debug, Clone)]
+pub struct TradeOptions {
+    pub fee_recipient: Option<Pubkey>,
+    pub max_price_impact_bps: Option<u64>,
+    pub skip_complete_check: bool,
+    pub memo: Option<String>,
+    pub ata_mode: AtaMode,
+    pub slippage: Option<SlippageMode>,
+    pub send_config: Option<RpcSendTransactionConfig>,
+    pub blockhash: Option<Hash>,
+    pub extra_instructions: Vec<Instruction>,
+    pub global_account: Option<GlobalAccount>,
+    pub bonding_curve: Option<BondingCurveAccount>,
+}
+
+impl TradeOptions {
+    /// Sets the fee recipient override
+    ///
+    /// # Arguments
+    ///
+    /// * `fee_recipient` - Fee recipient to pass to the buy/sell instruction
+    ///
+    /// # Returns
+    ///
+    /// The updated `TradeOptions`
+    pub fn with_fee_recipient(mut self, fee_recipient: Pubkey) -> Self {
+        self.fee_recipient = Some(fee_recipient);
+        self
+    }
+
+    /// Sets the maximum price impact allowed for buys
+    ///
+    /// # Arguments
+    ///
+    /// * `max_price_impact_bps` - Maximum price impact in basis points (1 bp = 0.01%)
+    ///
+    /// # Returns
+    ///
+    /// The updated `TradeOptions`
+    pub fn with_max_price_impact_bps(mut self, max_price_impact_bps: u64) -> Self {
+        self.max_price_impact_bps = Some(max_price_impact_bps);
+        self
+    }
+
+    /// Disables the check rejecting trades on completed bonding curves
+    ///
+    /// # Returns
+    ///
+    /// The updated `TradeOptions`
+    pub fn with_skip_complete_check(mut self) -> Self {
+        self.skip_complete_check = true;
+        self
+    }
+
+    /// Sets a memo to attach to the transaction
+    ///
+    /// # Arguments
+    ///
+    /// * `memo` - Memo text, recorded on-chain as-is
+    ///
+    /// # Returns
+    ///
+    /// The updated `TradeOptions`
+    pub fn with_memo(mut self, memo: impl Into<String>) -> Self {
+        self.memo = Some(memo.into());
+        self
+    }
+
+    /// Sets how a buy handles the payer's associated token account
+    ///
+    /// # Arguments
+    ///
+    /// * `ata_mode` - Associated token account handling
+    ///
+    /// # Returns
+    ///
+    /// The updated `TradeOptions`
+    pub fn with_ata_mode(mut self, ata_mode: AtaMode) -> Self {
+        self.ata_mode = ata_mode;
+        self
+    }
+
+    /// Sets the slippage protection, overriding the `slippage_basis_points` argument
+    ///
+    /// # Arguments
+    ///
+    /// * `slippage` - Slippage tolerance or absolute limit
+    ///
+    /// # Returns
+    ///
+    /// The updated `TradeOptions`
+    pub fn with_slippage(mut self, slippage: SlippageMode) -> Self {
+        self.slippage = Some(slippage);
+        self
+    }
+
+    /// Sets the RPC options to send the transaction with
+    ///
+    /// # Arguments
+    ///
+    /// * `send_config` - Send options, e.g. with `skip_preflight` set
+    ///
+    /// # Returns
+    ///
+    /// The updated `TradeOptions`
+    pub fn with_send_config(mut self, send_config: RpcSendTransactionConfig) -> Self {
+        self.send_config = Some(send_config);
+        self
+    }
+
+    /// Sets the recent blockhash to build the transaction with
+    ///
+    /// # Arguments
+    ///
+    /// * `blockhash` - Blockhash used instead of fetching the latest one, which must still
+    ///   be valid when the transaction is sent
+    ///
+    /// # Returns
+    ///
+    /// The updated `TradeOptions`
+    pub fn with_blockhash(mut self, blockhash: Hash) -> Self {
+        self.blockhash = Some(blockhash);
+        self
+    }
+
+    /// Adds instructions to execute atomically with the trade
+    ///
+    /// # Arguments
+    ///
+    /// * `instructions` - Instructions appended after the trade, and after any
+    ///   previously added extra instructions
+    ///
+    /// # Returns
+    ///
+    /// The updated `TradeOptions`
+    pub fn with_extra_instructions(mut self, instructions: Vec<Instruction>) -> Self {
+        self.extra_instructions.extend(instructions);
+        self
+    }
+
+    /// Sets a pre-fetched global account, skipping its RPC request
+    ///
+    /// # Arguments
+    ///
+    /// * `global_account` - Global account, e.g. from `PumpFun::get_global_account`
+    ///
+    /// # Returns
+    ///
+    /// The updated `TradeOptions`
+    pub fn with_global_account(mut self, global_account: GlobalAccount) -> Self {
+        self.global_account = Some(global_account);
+        self
+    }
+
+    /// Sets a pre-fetched bonding curve of the traded mint, skipping its RPC requests
+    ///
+    /// # Arguments
+    ///
+    /// * `bonding_curve` - Bonding curve, e.g. from `PumpFun::get_bonding_curve_account`
+    ///
+    /// # Returns
+    ///
+    /// The updated `TradeOptions`
+    pub fn with_bonding_curve(mut self, bonding_curve: BondingCurveAccount) -> Self {
+        self.bonding_curve = Some(bonding_curve);
+        self
+    }
+}
+
+/// Slippage protection of a trade
+///
+/// Determines the `max_sol_cost` of a buy or the `min_sol_output` of a sell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlippageMode {
+    /// Tolerance in basis points (1 bp = 0.01%) applied to the quoted amount
+    Bps(u64),
+    /// Absolute limit in lamports, used as-is regardless of the quote: the maximum SOL cost
+    /// of a buy or the minimum SOL output of a sell
+    AbsoluteLimit(u64),
+}
+
+impl Default for SlippageMode {
+    fn default() -> Self {
+        Self::Bps(500)
+    }
+}
+
+impl SlippageMode {
+    /// Returns the maximum SOL cost of a buy quoted at `amount_sol` lamports
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pumpfun::common::types::SlippageMode;
+    ///
+    /// assert_eq!(SlippageMode::Bps(100).max_sol_cost(10_000), 10_100);
+    /// assert_eq!(SlippageMode::AbsoluteLimit(12_345).max_sol_cost(10_000), 12_345);
+    /// ```
+    pub fn max_sol_cost(&self, amount_sol: u64) -> u64 {
+        match *self {
+            Self::Bps(basis_points) => {
+                crate::utils::calculate_with_slippage_buy(amount_sol, basis_points)
+            }
+            Self::AbsoluteLimit(max_sol_cost) => max_sol_cost,
+        }
+    }
+
+    /// Returns the minimum SOL output of a sell quoted at `amount_sol` lamports
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pumpfun::common::types::SlippageMode;
+    ///
+    /// assert_eq!(SlippageMode::Bps(100).min_sol_output(10_000), 9_900);
+    /// assert_eq!(SlippageMode::AbsoluteLimit(9_000).min_sol_output(10_000), 9_000);
+    /// ```
+    pub fn min_sol_output(&self, amount_sol: u64) -> u64 {
+        match *self {
+            Self::Bps(basis_points) => {
+                crate::utils::calculate_with_slippage_sell(amount_sol, basis_points)
+            }
+            Self::AbsoluteLimit(min_sol_output) => min_sol_output,
+        }
+    }
+}
+
+/// Side of a buy held exact
+///
+/// Pump.fun buy instructions take an exact token amount and a maximum SOL cost. The mode
+/// decides which side the caller fixes; the other one is quoted from the bonding curve.
+/// Sells always take an exact token amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeMode {
+    /// Spends the given lamports on the curve, fees excluded, for as many tokens as they buy
+    ExactSolIn(u64),
+    /// Buys the given amount of tokens (base units) for as little SOL as the curve allows
+    ExactTokensOut(u64),
+}
+
+impl TradeMode {
+    /// Quotes the buy against a bonding curve
+    ///
+    /// # Arguments
+    ///
+    /// * `curve` - Bonding curve the buy is quoted against
+    ///
+    /// # Returns
+    ///
+    /// Returns the tokens bought and the lamports going into the curve, fees excluded, or an
+    /// error message if the curve is complete or cannot fill the requested tokens
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pumpfun::{accounts::BondingCurveAccount, common::types::TradeMode};
+    /// use solana_sdk::pubkey::Pubkey;
+    ///
+    /// let curve = BondingCurveAccount::new(
+    ///     1,
+    ///     1_073_000_000_000_000,
+    ///     30_000_000_000,
+    ///     793_100_000_000_000,
+    ///     0,
+    ///     1_000_000_000_000_000,
+    ///     false,
+    ///     Pubkey::new_unique(),
+    /// );
+    ///
+    /// let (tokens, sol) = TradeMode::ExactSolIn(1_000_000_000).quote(&curve).unwrap();
+    /// assert_eq!(sol, 1_000_000_000);
+    /// assert_eq!(tokens, curve.get_buy_price(sol).unwrap());
+    ///
+    /// let (tokens, sol) = TradeMode::ExactTokensOut(1_000_000_000_000).quote(&curve).unwrap();
+    /// assert_eq!(tokens, 1_000_000_000_000);
+    /// assert!(curve.get_buy_price(sol).unwrap() >= tokens);
+    ///
+    /// assert!(TradeMode::ExactTokensOut(900_000_000_000_000).quote(&curve).is_err());
+    /// ```
+    pub fn quote(&self, curve: &BondingCurveAccount) -> Result<(u64, u64), &'static str> {
+        match *self {
+            Self::ExactSolIn(amount_sol) => Ok((curve.get_buy_price(amount_sol)?, amount_sol)),
+            Self::ExactTokensOut(amount) => Ok((amount, curve.get_buy_cost(amount)?)),
+        }
+    }
+
+    /// Builds the buy instruction arguments for this mode
+    ///
+    /// The token amount and SOL quote come from [`Self::quote`]; the slippage tolerance is
+    /// applied to the quoted SOL to get the maximum SOL cost.
+    ///
+    /// # Arguments
+    ///
+    /// * `curve` - Bonding curve the buy is quoted against
+    /// * `slippage` - Slippage protection applied to the quoted SOL
+    ///
+    /// # Returns
+    ///
+    /// Returns the buy arguments, or an error message if the buy cannot be quoted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pumpfun::{
+    ///     accounts::BondingCurveAccount,
+    ///     common::types::{SlippageMode, TradeMode},
+    /// };
+    /// use solana_sdk::pubkey::Pubkey;
+    ///
+    /// let curve = BondingCurveAccount::new(
+    ///     1,
+    ///     1_073_000_000_000_000,
+    ///     30_000_000_000,
+    ///     793_100_000_000_000,
+    ///     0,
+    ///     1_000_000_000_000_000,
+    ///     false,
+    ///     Pubkey::new_unique(),
+    /// );
+    ///
+    /// let mode = TradeMode::ExactTokensOut(1_000_000_000_000);
+    /// let args = mode.buy_args(&curve, SlippageMode::Bps(100)).unwrap();
+    /// let (_, sol) = mode.quote(&curve).unwrap();
+    /// assert_eq!(args.amount, 1_000_000_000_000);
+    /// assert_eq!(args.max_sol_cost, SlippageMode::Bps(100).max_sol_cost(sol));
+    /// ```
+    pub fn buy_args(
+        &self,
+        curve: &BondingCurveAccount,
+        slippage: SlippageMode,
+    ) -> Result<instructions::Buy, &'static str> {
+        let (amount, amount_sol) = self.quote(curve)?;
+        Ok(instructions::Buy::tokens(amount).max_sol(slippage.max_sol_cost(amount_sol)))
+    }
+}
+
+/// How a buy handles the payer's associated token account
+///
+/// Trades an RPC round-trip against the risk of a failing transaction.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AtaMode {
+    /// Fetches the account and adds an idempotent create instruction if it is missing
+    #[default]
+    Auto,
+    /// Skips the lookup and never creates the account. The buy fails on-chain if the
+    /// account does not exist
+    AssumeExists,
+    /// Skips the lookup and always adds an idempotent create instruction, which costs a
+    /// little compute but succeeds whether or not the account exists
+    AlwaysCreate,
+}
+
+/// Token program variant of a Pump.fun mint
+///
+/// Tokens launched with `create` are SPL Token mints, while tokens launched with
+/// `create_v2` are Token-2022 mints, with or without mayhem mode. Trades must pass the
+/// matching token program and associated token accounts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenVariant {
+    /// Created with `create`, owned by the SPL Token program
+    Classic,
+    /// Created with `create_v2`, owned by the Token-2022 program
+    MayhemV2,
+}
+
+impl TokenVariant {
+    /// Returns the token program owning mints of this variant
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pumpfun::{common::types::TokenVariant, constants};
+    ///
+    /// assert_eq!(
+    ///     TokenVariant::MayhemV2.token_program(),
+    ///     constants::accounts::TOKEN_2022_PROGRAM
+    /// );
+    /// ```
+    pub fn token_program(&self) -> Pubkey {
+        match self {
+            Self::Classic => constants::accounts::TOKEN_PROGRAM,
+            Self::MayhemV2 => constants::accounts::TOKEN_2022_PROGRAM,
+        }
+    }
+}
+
+/// Outcome of checking whether a mint was launched on Pump.fun
+///
+/// Returned by `PumpFun::verify_pumpfun_token`. Every variant other than `Verified` names
+/// the first check the mint failed, e.g. to explain a missing verified badge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenVerification {
+    /// Mint authority and bonding curve match a token launched on Pump.fun
+    Verified,
+    /// No account exists at the mint address
+    MintNotFound,
+    /// Account is not a mint of the Token or Token-2022 program
+    NotAMint,
+    /// Mint can be minted by an authority other than the Pump.fun program
+    WrongMintAuthority(Pubkey),
+    /// No bonding curve account exists for the mint
+    MissingBondingCurve,
+    /// Bonding curve address is owned by another program than Pump.fun
+    WrongCurveOwner(Pubkey),
+}
+
+impl TokenVerification {
+    /// Returns whether the mint passed every check
+    pub fn is_verified(&self) -> bool {
+        *self == Self::Verified
+    }
+}
+
+/// Pump.fun token held by a wallet, valued against its bonding curve
+///
+/// # Fields
+///
+/// * `mint` - Mint of the token
+/// * `token_account` - Token account holding the tokens
+/// * `token_program` - Token program owning the account (Token or Token-2022)
+/// * `amount` - Balance of the token account, in base units
+/// * `bonding_curve` - Current state of the token's bonding curve
+/// * `sell_value` - SOL received for selling the whole balance to the curve after fees, in
+///   lamports, or `None` if the curve is complete and the token trades on the AMM
+#[derive(Debug, Clone)]
+pub struct Position {
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub token_program: Pubkey,
+    pub amount: u64,
+    pub bonding_curve: BondingCurveAccount,
+    pub sell_value: Option<u64>,
+}
+
+/// Fees paid by a confirmed transaction
+///
+/// # Fields
+///
+/// * `fee` - Network fee charged for the transaction (base fee plus priority fee), in lamports
+/// * `payer_balance_change` - Change of the fee payer's SOL balance, in lamports. Includes the
+///   fee as well as any SOL moved by the transaction itself (e.g. the cost of a buy)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionFee {
+    pub fee: u64,
+    pub payer_balance_change: i64,
+}
+
+/// Predicted SOL balance of a payer after a launch
+///
+/// # Fields
+///
+/// * `balance_after` - Predicted balance after the launch, in lamports, or `0` if the
+///   launch costs more than the current balance
+/// * `rent_exempt_minimum` - Minimum balance keeping the payer's account rent-exempt, in
+///   lamports
+/// * `below_rent_exempt` - Whether `balance_after` is below `rent_exempt_minimum`, in which
+///   case the launch would fail
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BalancePrediction {
+    pub balance_after: u64,
+    pub rent_exempt_minimum: u64,
+    pub below_rent_exempt: bool,
+}
+
+/// Estimated time until a bonding curve graduates, with the trades it was sampled from
+///
+/// # Fields
+///
+/// * `time_to_graduation` - Estimated time left until graduation, `Some(Duration::ZERO)` if
+///   the curve is already complete, or `None` if more SOL left the curve than went in
+/// * `net_sol_inflow` - Lamports bought minus lamports sold over `sampled_window`, fees
+///   excluded
+/// * `sampled_transactions` - Number of successful transactions whose trades were summed
+/// * `sampled_window` - Period the sampled transactions cover. Equal to the requested
+///   window, unless the sample was truncated
+/// * `truncated` - Whether the window held more transactions than the sampling cap, in
+///   which case only the most recent ones were sampled
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GraduationEstimate {
+    pub time_to_graduation: Option<Duration>,
+    pub net_sol_inflow: i128,
+    pub sampled_transactions: usize,
+    pub sampled_window: Duration,
+    pub truncated: bool,
+}
+
+/// Mayhem program accounts passed to a `create_v2` instruction
+///
+/// `create_v2` always passes these accounts; the Mayhem program only initializes them
+/// when mayhem mode is enabled.
+///
+/// # Fields
+///
+/// * `is_mayhem_mode` - Whether mayhem mode was requested for the token
+/// * `mayhem_state` - Mayhem state PDA of the mint
+/// * `sol_vault` - Mayhem SOL vault PDA
+/// * `global_params` - Mayhem global params PDA
+/// * `token_vault` - Token account of the SOL vault for the mint
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MayhemAccounts {
+    pub is_mayhem_mode: bool,
+    pub mayhem_state: Pubkey,
+    pub sol_vault: Pubkey,
+    pub global_params: Pubkey,
+    pub token_vault: Pubkey,
+}
+
 /// RPC connection endpoints for a Solana cluster
 ///
 /// # Fields
 ///
 /// * `http` - HTTP endpoint URL for JSON RPC requests
 /// * `ws` - WebSocket endpoint URL for subscription-based requests
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct RpcEndpoint {
     pub http: String,
     pub ws: String,
 }
 
+impl std::fmt::Debug for RpcEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Provider URLs often embed API keys, so only show where they point to
+        f.debug_struct("RpcEndpoint")
+            .field("http", &crate::utils::redact_url(&self.http))
+            .field("ws", &crate::utils::redact_url(&self.ws))
+            .finish()
+    }
+}
+
 impl RpcEndpoint {
     /// Creates a new RPC endpoint configuration
     ///
@@ -190,3 +759,19 @@ impl Cluster {
         )
     }
 }
+
+/// Unsigned transaction serialized for signing by a wallet
+///
+/// # Fields
+///
+/// * `transaction` - Base64-encoded v0 transaction with empty signatures, as accepted by
+///   wallet adapters
+/// * `blockhash` - Recent blockhash the transaction was built with
+/// * `last_valid_block_height` - Last block height at which the blockhash is accepted, after
+///   which the transaction expires and must be rebuilt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsignedTransaction {
+    pub transaction: String,
+    pub blockhash: Hash,
+    pub last_valid_block_height: u64,
+}
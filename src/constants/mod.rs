@@ -4,11 +4,17 @@
 //!
 //! - Seeds for deriving Program Derived Addresses (PDAs)
 //! - Program account addresses and public keys
+//! - Decimal places used by Pump.fun tokens and SOL
+//! - Length limits of token metadata
+//! - Data sizes of the accounts created with a token
 //!
 //! The constants are organized into submodules for better organization:
 //!
 //! - `seeds`: Contains seed values used for PDA derivation
 //! - `accounts`: Contains important program account addresses
+//! - `decimals`: Contains decimal places for converting between UI and base amounts
+//! - `metadata`: Contains the maximum byte lengths of on-chain token metadata fields
+//! - `sizes`: Contains the data sizes of the accounts allocated when a token is created
 
 /// Constants used as seeds for deriving PDAs (Program Derived Addresses)
 pub mod seeds {
@@ -26,6 +32,54 @@ pub mod seeds {
 
     /// Seed for creator vault PDA
     pub const CREATOR_VAULT_SEED: &[u8] = b"creator-vault";
+
+    /// Seed for the PDA that owns the AMM pool of a migrated token
+    pub const POOL_AUTHORITY_SEED: &[u8] = b"pool-authority";
+
+    /// Seed for PumpSwap AMM pool PDAs
+    pub const POOL_SEED: &[u8] = b"pool";
+
+    /// Seed for the PDA recording the last withdrawal of a completed curve
+    pub const LAST_WITHDRAW_SEED: &[u8] = b"last-withdraw";
+
+    /// Seed for the Anchor event authority PDA signing self-CPI events
+    pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
+}
+
+/// Constants for the number of decimal places of token and SOL amounts
+pub mod decimals {
+    /// Decimal places of every token minted by Pump.fun
+    pub const TOKEN_DECIMALS: u8 = 6;
+
+    /// Decimal places of SOL (1 SOL = 1,000,000,000 lamports)
+    pub const SOL_DECIMALS: u8 = 9;
+}
+
+/// Maximum lengths, in bytes, of the metadata fields stored by the Metaplex program
+pub mod metadata {
+    /// Maximum length of a token name
+    pub const MAX_NAME_LENGTH: usize = 32;
+
+    /// Maximum length of a token symbol
+    pub const MAX_SYMBOL_LENGTH: usize = 10;
+
+    /// Maximum length of a metadata URI
+    pub const MAX_URI_LENGTH: usize = 200;
+}
+
+/// Data sizes, in bytes, of the accounts allocated when a token is created
+pub mod sizes {
+    /// Size of an SPL token mint
+    pub const MINT_SIZE: usize = 82;
+
+    /// Size of an SPL token account, e.g. the associated bonding curve
+    pub const TOKEN_ACCOUNT_SIZE: usize = 165;
+
+    /// Size of a bonding curve account as allocated by the Pump.fun program
+    pub const BONDING_CURVE_SIZE: usize = 150;
+
+    /// Maximum size of a Metaplex metadata account
+    pub const METADATA_SIZE: usize = 679;
 }
 
 /// Constants related to program accounts and authorities
@@ -38,7 +92,7 @@ pub mod accounts {
     /// Public key for the MPL Token Metadata program
     pub const MPL_TOKEN_METADATA: Pubkey = pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
 
-    /// Authority for program events
+    /// Authority for program events, the PDA of `seeds::EVENT_AUTHORITY_SEED`
     pub const EVENT_AUTHORITY: Pubkey = pubkey!("Ce6TQqeHC9p8KetsN6JsjHK7UTZk7nasjjnr7XxXp9F1");
 
     /// System Program ID
@@ -69,4 +123,13 @@ pub mod accounts {
 
     /// Rent Sysvar ID
     pub const RENT: Pubkey = pubkey!("SysvarRent111111111111111111111111111111111");
+
+    /// PumpSwap AMM program ID, where completed curves migrate to
+    pub const PUMP_AMM: Pubkey = pubkey!("pAMMBay6oceH9fJKBRHGP5D4bD4sWpmSwMn52FMfXEA");
+
+    /// SPL Memo program ID
+    pub const MEMO_PROGRAM: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+    /// Wrapped SOL mint, the quote mint of migrated pools
+    pub const WSOL_MINT: Pubkey = pubkey!("So11111111111111111111111111111111111111112");
 }
@@ -9,10 +9,21 @@
 //!
 //! - `BondingCurveNotFound`: The bonding curve account was not found.
 //! - `BondingCurveError`: An error occurred while interacting with the bonding curve.
+//! - `CurveComplete`: The bonding curve is complete and the token has migrated to the AMM.
 //! - `BorshError`: An error occurred while serializing or deserializing data using Borsh.
 //! - `SolanaClientError`: An error occurred while interacting with the Solana RPC client.
 //! - `PubsubClientError`: An error occurred while interacting with the Solana Pubsub client.
 //! - `UploadMetadataError`: An error occurred while uploading metadata to IPFS.
+//! - `InitialBuyTooLarge`: The creator's initial buy exceeds what the bonding curve allows.
+//! - `PriceImpactTooHigh`: A trade would move the price more than the configured maximum.
+//! - `InvalidCreator`: The creator of a new token cannot earn creator fees.
+//! - `NotPumpFunToken`: A mint was not launched on Pump.fun.
+//! - `InvalidFeeRecipient`: A fee recipient is not accepted by the program.
+//! - `InsufficientFunds`: The payer cannot cover the cost of a transaction.
+//! - `InvalidPubkey`: A string passed as an address is not a valid base58 public key.
+//! - `NotYetConfirmed`: A transaction has not been confirmed yet.
+//! - `Timeout`: An operation did not complete within the allotted time.
+//! - `LaunchInProgress`: A launch with the same idempotency key is still running.
 //! - `OtherError`: An error occurred that is not covered by the other error types.
 
 #[derive(Debug)]
@@ -22,6 +33,13 @@ pub enum ClientError {
     BondingCurveNotFound,
     /// Error related to bonding curve operations
     BondingCurveError(&'static str),
+    /// Bonding curve is complete, so it no longer accepts trades
+    CurveComplete {
+        /// Mint of the token
+        mint: solana_sdk::pubkey::Pubkey,
+        /// PumpSwap AMM pool the token migrated to, if it could be derived
+        pool: Option<solana_sdk::pubkey::Pubkey>,
+    },
     /// Error deserializing data using Borsh
     BorshError(std::io::Error),
     /// Error from Solana RPC client
@@ -30,7 +48,47 @@ pub enum ClientError {
     #[cfg(feature = "stream")]
     PubsubClientError(solana_client::pubsub_client::PubsubClientError),
     /// Error uploading metadata
-    UploadMetadataError(Box<dyn std::error::Error>),
+    UploadMetadataError(Box<dyn std::error::Error + Send + Sync>),
+    /// Initial buy on a new curve exceeds the maximum allowed amount of SOL
+    InitialBuyTooLarge {
+        /// Requested amount of SOL in lamports
+        requested: u64,
+        /// Maximum amount of SOL in lamports
+        max: u64,
+    },
+    /// Price impact of a trade exceeds the configured maximum
+    PriceImpactTooHigh {
+        /// Price impact of the trade in basis points
+        impact_bps: u64,
+        /// Maximum allowed price impact in basis points
+        max_bps: u64,
+    },
+    /// Creator of a new token is not an address that can receive creator fees
+    InvalidCreator(solana_sdk::pubkey::Pubkey),
+    /// Mint has no Pump.fun bonding curve or is not a token mint
+    NotPumpFunToken(solana_sdk::pubkey::Pubkey),
+    /// Fee recipient is not one of the recipients of the global account
+    InvalidFeeRecipient(solana_sdk::pubkey::Pubkey),
+    /// Payer balance is lower than the lamports a transaction needs
+    InsufficientFunds {
+        /// Current balance of the payer in lamports
+        have: u64,
+        /// Lamports needed by the transaction
+        need: u64,
+    },
+    /// String passed as an address is not a valid base58 public key
+    InvalidPubkey {
+        /// Name of the argument holding the address, e.g. `creator`
+        field: &'static str,
+        /// Value that failed to parse
+        value: String,
+    },
+    /// Transaction has not been confirmed at the requested commitment yet
+    NotYetConfirmed(solana_sdk::signature::Signature),
+    /// Operation did not complete before the timeout elapsed
+    Timeout(std::time::Duration),
+    /// Launch with the same idempotency key is still running
+    LaunchInProgress(String),
     /// Other error
     OtherError(String),
 }
@@ -40,11 +98,56 @@ impl std::fmt::Display for ClientError {
         match self {
             Self::BondingCurveNotFound => write!(f, "Bonding curve not found"),
             Self::BondingCurveError(msg) => write!(f, "Bonding curve error: {}", msg),
+            Self::CurveComplete { mint, pool } => match pool {
+                Some(pool) => write!(
+                    f,
+                    "Bonding curve of {} is complete, trade on AMM pool {} instead",
+                    mint, pool
+                ),
+                None => write!(f, "Bonding curve of {} is complete", mint),
+            },
             Self::BorshError(err) => write!(f, "Borsh serialization error: {}", err),
             Self::SolanaClientError(err) => write!(f, "Solana client error: {}", err),
             #[cfg(feature = "stream")]
             Self::PubsubClientError(err) => write!(f, "Solana pubsub client error: {}", err),
             Self::UploadMetadataError(err) => write!(f, "Metadata upload error: {}", err),
+            Self::InitialBuyTooLarge { requested, max } => write!(
+                f,
+                "Initial buy of {} lamports exceeds the maximum of {} lamports",
+                requested, max
+            ),
+            Self::PriceImpactTooHigh {
+                impact_bps,
+                max_bps,
+            } => write!(
+                f,
+                "Price impact of {} bps exceeds the maximum of {} bps",
+                impact_bps, max_bps
+            ),
+            Self::InvalidCreator(creator) => write!(f, "Invalid token creator: {}", creator),
+            Self::NotPumpFunToken(mint) => write!(f, "{} is not a Pump.fun token", mint),
+            Self::InvalidFeeRecipient(fee_recipient) => {
+                write!(f, "Invalid fee recipient: {}", fee_recipient)
+            }
+            Self::InsufficientFunds { have, need } => write!(
+                f,
+                "Insufficient funds: payer has {} lamports but needs {} lamports",
+                have, need
+            ),
+            Self::InvalidPubkey { field, value } => {
+                write!(
+                    f,
+                    "Invalid {} address {:?}: not a base58 public key",
+                    field, value
+                )
+            }
+            Self::NotYetConfirmed(signature) => {
+                write!(f, "Transaction not yet confirmed: {}", signature)
+            }
+            Self::Timeout(duration) => write!(f, "Timed out after {:?}", duration),
+            Self::LaunchInProgress(key) => {
+                write!(f, "Launch with idempotency key {:?} is in progress", key)
+            }
             Self::OtherError(msg) => write!(f, "Other error: {}", msg),
         }
     }
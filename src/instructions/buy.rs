@@ -20,7 +20,7 @@ use spl_associated_token_account::get_associated_token_address;
 /// * `amount` - Amount of tokens to buy (in token smallest units)
 /// * `max_sol_cost` - Maximum acceptable SOL cost for the purchase (slippage protection)
 /// * `track_volume` - Whether to track this purchase in volume accumulators
-#[derive(BorshSerialize, BorshDeserialize, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
 pub struct Buy {
     pub amount: u64,
     pub max_sol_cost: u64,
@@ -28,17 +28,62 @@ pub struct Buy {
 }
 
 impl Buy {
-    /// Instruction discriminator used to identify this instruction
-    pub const DISCRIMINATOR: [u8; 8] = [102, 6, 61, 18, 1, 218, 235, 234];
+    /// Default instruction discriminator used to identify this instruction
+    ///
+    /// The value written by `data()` can be overridden through `Discriminators`.
+    pub const DISCRIMINATOR: [u8; 8] = super::discriminator_for("buy");
 
-    /// Serializes the instruction data with the appropriate discriminator
+    /// Starts building a buy of `amount` tokens
+    ///
+    /// Names the unit of each value at the call site, where the struct literal takes two bare
+    /// `u64`s. The maximum SOL cost starts at `0`, which the program rejects, so it must be
+    /// set with [`Buy::max_sol`].
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount of tokens to buy, in token base units
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pumpfun::instructions::Buy;
+    ///
+    /// let args = Buy::tokens(1_000_000).max_sol(50_000_000).with_track_volume(true);
+    /// let literal = Buy {
+    ///     amount: 1_000_000,
+    ///     max_sol_cost: 50_000_000,
+    ///     track_volume: Some(true),
+    /// };
+    /// assert_eq!(args.data(), literal.data());
+    /// ```
+    pub fn tokens(amount: u64) -> Self {
+        Self {
+            amount,
+            max_sol_cost: 0,
+            track_volume: None,
+        }
+    }
+
+    /// Sets the maximum SOL cost of the buy, in lamports
+    pub fn max_sol(mut self, lamports: u64) -> Self {
+        self.max_sol_cost = lamports;
+        self
+    }
+
+    /// Sets whether the buy is tracked in the volume accumulators
+    pub fn with_track_volume(mut self, track_volume: bool) -> Self {
+        self.track_volume = Some(track_volume);
+        self
+    }
+
+    /// Serializes the instruction data with the currently configured discriminator
     ///
     /// # Returns
     ///
     /// Byte vector containing the serialized instruction data
     pub fn data(&self) -> Vec<u8> {
         let mut data = Vec::with_capacity(256);
-        data.extend_from_slice(&Self::DISCRIMINATOR);
+        data.extend_from_slice(&super::Discriminators::current().buy);
         self.serialize(&mut data).unwrap();
         data
     }
@@ -53,7 +98,7 @@ impl Buy {
 ///
 /// # Arguments
 ///
-/// * `payer` - Keypair that will provide the SOL to buy tokens
+/// * `payer` - Public key of the account that will provide the SOL to buy tokens
 /// * `mint` - Public key of the token mint to buy
 /// * `fee_recipient` - Public key of the account that will receive the transaction fee
 /// * `creator` - Public key of the token's creator
@@ -82,8 +127,8 @@ impl Buy {
 /// 14. User volume accumulator (writable)
 /// 15. Fee configuration account (readonly)
 /// 16. Fee configuration program ID (readonly)
-pub fn buy(
-    payer: &Keypair,
+pub fn buy_instruction(
+    payer: &Pubkey,
     mint: &Pubkey,
     fee_recipient: &Pubkey,
     creator: &Pubkey,
@@ -99,9 +144,9 @@ pub fn buy(
             AccountMeta::new(*fee_recipient, false),
             AccountMeta::new_readonly(*mint, false),
             AccountMeta::new(bonding_curve, false),
-            AccountMeta::new(get_associated_token_address(&bonding_curve, mint), false),
-            AccountMeta::new(get_associated_token_address(&payer.pubkey(), mint), false),
-            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(PumpFun::get_associated_bonding_curve_v1(mint), false),
+            AccountMeta::new(get_associated_token_address(payer, mint), false),
+            AccountMeta::new(*payer, true),
             AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::TOKEN_PROGRAM, false),
             AccountMeta::new(creator_vault, false),
@@ -109,7 +154,7 @@ pub fn buy(
             AccountMeta::new_readonly(constants::accounts::PUMPFUN, false),
             AccountMeta::new(constants::accounts::GLOBAL_VOLUME_ACCUMULATOR, false),
             AccountMeta::new(
-                PumpFun::get_user_volume_accumulator_pda(&payer.pubkey()),
+                PumpFun::get_user_volume_accumulator_pda(payer),
                 false,
             ),
             AccountMeta::new_readonly(constants::accounts::FEE_CONFIG, false),
@@ -118,6 +163,31 @@ pub fn buy(
     )
 }
 
+/// Creates an instruction to buy tokens from a bonding curve using the payer keypair
+///
+/// Convenience wrapper around [`buy_instruction`] for callers holding the payer keypair.
+///
+/// # Arguments
+///
+/// * `payer` - Keypair that will provide the SOL to buy tokens
+/// * `mint` - Public key of the token mint to buy
+/// * `fee_recipient` - Public key of the account that will receive the transaction fee
+/// * `creator` - Public key of the token's creator
+/// * `args` - Buy instruction data containing the token amount and maximum acceptable SOL price
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will buy tokens from the bonding curve
+pub fn buy(
+    payer: &Keypair,
+    mint: &Pubkey,
+    fee_recipient: &Pubkey,
+    creator: &Pubkey,
+    args: Buy,
+) -> Instruction {
+    buy_instruction(&payer.pubkey(), mint, fee_recipient, creator, args)
+}
+
 /// Creates an instruction to buy tokens from a bonding curve with a specified token program
 ///
 /// This is a variant of the `buy` function that allows specifying the token program to use.
@@ -125,7 +195,7 @@ pub fn buy(
 ///
 /// # Arguments
 ///
-/// * `payer` - Keypair that will provide the SOL to buy tokens
+/// * `payer` - Public key of the account that will provide the SOL to buy tokens
 /// * `mint` - Public key of the token mint to buy
 /// * `fee_recipient` - Public key of the account that will receive the transaction fee
 /// * `creator` - Public key of the token's creator
@@ -135,8 +205,8 @@ pub fn buy(
 /// # Returns
 ///
 /// Returns a Solana instruction that when executed will buy tokens from the bonding curve
-pub fn buy_with_token_program(
-    payer: &Keypair,
+pub fn buy_with_token_program_instruction(
+    payer: &Pubkey,
     mint: &Pubkey,
     fee_recipient: &Pubkey,
     creator: &Pubkey,
@@ -145,17 +215,11 @@ pub fn buy_with_token_program(
 ) -> Instruction {
     let bonding_curve: Pubkey = PumpFun::get_bonding_curve_pda(mint).unwrap();
     let creator_vault: Pubkey = PumpFun::get_creator_vault_pda(creator).unwrap();
-    
-    // Derive associated_bonding_curve PDA with Token 2022 program ID
-    // The PDA seeds are: [bonding_curve, token_program, mint]
-    // For create_v2, we must use TOKEN_2022_PROGRAM instead of TOKEN_PROGRAM
-    let associated_bonding_curve = PumpFun::get_associated_token_address_with_program(
-        &bonding_curve,
-        mint,
-        token_program,
-    );
+
+    // The associated bonding curve must be derived with the mint's token program
+    let associated_bonding_curve = PumpFun::get_associated_bonding_curve(mint, token_program);
     let associated_user = PumpFun::get_associated_token_address_with_program(
-        &payer.pubkey(),
+        payer,
         mint,
         token_program,
     );
@@ -170,7 +234,7 @@ pub fn buy_with_token_program(
             AccountMeta::new(bonding_curve, false),
             AccountMeta::new(associated_bonding_curve, false),
             AccountMeta::new(associated_user, false),
-            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(*payer, true),
             AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
             AccountMeta::new_readonly(*token_program, false),
             AccountMeta::new(creator_vault, false),
@@ -178,11 +242,46 @@ pub fn buy_with_token_program(
             AccountMeta::new_readonly(constants::accounts::PUMPFUN, false),
             AccountMeta::new(constants::accounts::GLOBAL_VOLUME_ACCUMULATOR, false),
             AccountMeta::new(
-                PumpFun::get_user_volume_accumulator_pda(&payer.pubkey()),
+                PumpFun::get_user_volume_accumulator_pda(payer),
                 false,
             ),
             AccountMeta::new_readonly(constants::accounts::FEE_CONFIG, false),
             AccountMeta::new_readonly(constants::accounts::FEE_CONFIG_PROGRAM, false),
         ],
     )
+}
+
+/// Creates an instruction to buy tokens with a specified token program using the payer keypair
+///
+/// Convenience wrapper around [`buy_with_token_program_instruction`] for callers holding the
+/// payer keypair.
+///
+/// # Arguments
+///
+/// * `payer` - Keypair that will provide the SOL to buy tokens
+/// * `mint` - Public key of the token mint to buy
+/// * `fee_recipient` - Public key of the account that will receive the transaction fee
+/// * `creator` - Public key of the token's creator
+/// * `token_program` - Public key of the token program to use (Token or Token 2022)
+/// * `args` - Buy instruction data containing the token amount and maximum acceptable SOL price
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will buy tokens from the bonding curve
+pub fn buy_with_token_program(
+    payer: &Keypair,
+    mint: &Pubkey,
+    fee_recipient: &Pubkey,
+    creator: &Pubkey,
+    token_program: &Pubkey,
+    args: Buy,
+) -> Instruction {
+    buy_with_token_program_instruction(
+        &payer.pubkey(),
+        mint,
+        fee_recipient,
+        creator,
+        token_program,
+        args,
+    )
 }
\ No newline at end of file
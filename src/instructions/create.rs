@@ -3,7 +3,7 @@
 //! This module provides the functionality to create new tokens with associated bonding curves.
 //! It includes the instruction data structure and helper function to build the Solana instruction.
 
-use crate::{constants, PumpFun};
+use crate::{constants, error, PumpFun};
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
@@ -11,7 +11,6 @@ use solana_sdk::{
     signature::Keypair,
     signer::Signer,
 };
-use spl_associated_token_account::get_associated_token_address;
 
 /// Instruction data for creating a new token
 ///
@@ -21,29 +20,179 @@ use spl_associated_token_account::get_associated_token_address;
 /// * `symbol` - Symbol/ticker of the token to be created
 /// * `uri` - Metadata URI containing token information (image, description, etc.)
 /// * `creator` - Public key of the token creator
-#[derive(BorshSerialize, BorshDeserialize, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub struct Create {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub creator: Pubkey,
 }
 
 impl Create {
-    /// Instruction discriminator used to identify this instruction
-    pub const DISCRIMINATOR: [u8; 8] = [24, 30, 200, 40, 5, 28, 7, 119];
+    /// Default instruction discriminator used to identify this instruction
+    ///
+    /// The value written by `data()` can be overridden through `Discriminators`.
+    pub const DISCRIMINATOR: [u8; 8] = super::discriminator_for("create");
 
-    /// Serializes the instruction data with the appropriate discriminator
+    /// Starts building the arguments from string inputs, e.g. read from a CLI or a form
+    ///
+    /// The creator is given as a base58 string and parsed by [`CreateBuilder::build`], which
+    /// reports an invalid address as `ClientError::InvalidPubkey`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pumpfun::instructions::Create;
+    ///
+    /// let args = Create::builder()
+    ///     .name("My Token")
+    ///     .symbol("MYTKN")
+    ///     .uri("https://ipfs.io/ipfs/QmMetadata")
+    ///     .creator("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P")
+    ///     .build()?;
+    /// assert_eq!(args.creator.to_string(), "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
+    ///
+    /// assert!(Create::builder().creator("not-an-address").build().is_err());
+    /// # Ok::<(), pumpfun::error::ClientError>(())
+    /// ```
+    pub fn builder() -> CreateBuilder {
+        CreateBuilder::default()
+    }
+
+    /// Serializes the instruction data with the currently configured discriminator
     ///
     /// # Returns
     ///
     /// Byte vector containing the serialized instruction data
     pub fn data(&self) -> Vec<u8> {
         let mut data = Vec::with_capacity(256);
-        data.extend_from_slice(&Self::DISCRIMINATOR);
+        data.extend_from_slice(&super::Discriminators::current().create);
         self.serialize(&mut data).unwrap();
         data
     }
+
+    /// Truncates the name, symbol and URI to the Metaplex metadata limits
+    ///
+    /// The limits in [`constants::metadata`] are in bytes. Each field is cut on a character
+    /// boundary with [`crate::utils::truncate_to_bytes`], so multibyte names never produce
+    /// invalid UTF-8 on-chain. Fields within their limits are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pumpfun::instructions::Create;
+    /// use solana_sdk::pubkey::Pubkey;
+    ///
+    /// let mut args = Create {
+    ///     name: "火箭".repeat(6),
+    ///     symbol: "ROCKETS🚀".to_string(),
+    ///     uri: "https://ipfs.io/ipfs/QmMetadata".to_string(),
+    ///     creator: Pubkey::new_unique(),
+    /// };
+    /// args.truncate_to_limits();
+    /// assert_eq!(args.name, "火箭".repeat(5));
+    /// assert_eq!(args.symbol, "ROCKETS");
+    /// ```
+    pub fn truncate_to_limits(&mut self) {
+        truncate_field(&mut self.name, constants::metadata::MAX_NAME_LENGTH);
+        truncate_field(&mut self.symbol, constants::metadata::MAX_SYMBOL_LENGTH);
+        truncate_field(&mut self.uri, constants::metadata::MAX_URI_LENGTH);
+    }
+}
+
+/// Builder of [`Create`] and [`CreateV2`] arguments taking the creator as a string
+///
+/// Created with [`Create::builder`]. Fields that are not set are empty, and a missing
+/// creator fails to parse like any other invalid address.
+#[derive(Debug, Clone, Default)]
+pub struct CreateBuilder {
+    name: String,
+    symbol: String,
+    uri: String,
+    creator: String,
+}
+
+impl CreateBuilder {
+    /// Sets the name of the token
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the symbol of the token
+    pub fn symbol(mut self, symbol: impl Into<String>) -> Self {
+        self.symbol = symbol.into();
+        self
+    }
+
+    /// Sets the metadata URI of the token
+    pub fn uri(mut self, uri: impl Into<String>) -> Self {
+        self.uri = uri.into();
+        self
+    }
+
+    /// Sets the creator as a base58 address, parsed when the arguments are built
+    ///
+    /// Surrounding whitespace is ignored, e.g. a trailing newline read from stdin.
+    pub fn creator(mut self, creator: impl AsRef<str>) -> Self {
+        self.creator = creator.as_ref().trim().to_string();
+        self
+    }
+
+    /// Parses the creator address
+    #[allow(clippy::result_large_err)]
+    fn parse_creator(&self) -> Result<Pubkey, error::ClientError> {
+        self.creator
+            .parse()
+            .map_err(|_| error::ClientError::InvalidPubkey {
+                field: "creator",
+                value: self.creator.clone(),
+            })
+    }
+
+    /// Builds the arguments of the `create` instruction
+    ///
+    /// # Returns
+    ///
+    /// Returns the arguments, or `ClientError::InvalidPubkey` if the creator is missing or
+    /// not a valid base58 address
+    #[allow(clippy::result_large_err)]
+    pub fn build(self) -> Result<Create, error::ClientError> {
+        Ok(Create {
+            creator: self.parse_creator()?,
+            name: self.name,
+            symbol: self.symbol,
+            uri: self.uri,
+        })
+    }
+
+    /// Builds the arguments of the `create_v2` instruction
+    ///
+    /// # Arguments
+    ///
+    /// * `is_mayhem_mode` - Whether to enable mayhem mode for the token
+    ///
+    /// # Returns
+    ///
+    /// Returns the arguments, or `ClientError::InvalidPubkey` if the creator is missing or
+    /// not a valid base58 address
+    #[allow(clippy::result_large_err)]
+    pub fn build_v2(self, is_mayhem_mode: bool) -> Result<CreateV2, error::ClientError> {
+        Ok(CreateV2 {
+            creator: self.parse_creator()?,
+            name: self.name,
+            symbol: self.symbol,
+            uri: self.uri,
+            is_mayhem_mode,
+        })
+    }
+}
+
+/// Truncates a metadata field in place without splitting a character
+fn truncate_field(field: &mut String, max_bytes: usize) {
+    let len = crate::utils::truncate_to_bytes(field, max_bytes).len();
+    field.truncate(len);
 }
 
 /// Creates an instruction to create a new token with bonding curve
@@ -53,14 +202,21 @@ impl Create {
 ///
 /// # Arguments
 ///
-/// * `payer` - Keypair that will pay for account creation and transaction fees
-/// * `mint` - Keypair for the new token mint account that will be created
+/// * `payer` - Public key of the account that will pay for account creation and transaction fees
+/// * `mint` - Public key of the new token mint account that will be created
 /// * `args` - Create instruction data containing token name, symbol, metadata URI, and creator
 ///
 /// # Returns
 ///
 /// Returns a Solana instruction that when executed will create the token and its accounts
 ///
+/// # Signers
+///
+/// Only `payer` and `mint` sign. `args.creator` never signs: it is recorded on the bonding
+/// curve and earns the creator fees, which accrue in the creator vault PDA derived from it
+/// (see [`PumpFun::get_creator_vault_pda`]). It may therefore differ from the payer, e.g.
+/// when launching a token on behalf of someone else.
+///
 /// # Account Requirements
 ///
 /// The instruction requires the following accounts in this order:
@@ -78,23 +234,23 @@ impl Create {
 /// 12. Rent sysvar (readonly)
 /// 13. Event authority (readonly)
 /// 14. Pump.fun program ID (readonly)
-pub fn create(payer: &Keypair, mint: &Keypair, args: Create) -> Instruction {
-    let bonding_curve: Pubkey = PumpFun::get_bonding_curve_pda(&mint.pubkey()).unwrap();
+pub fn create_instruction(payer: &Pubkey, mint: &Pubkey, args: Create) -> Instruction {
+    let bonding_curve: Pubkey = PumpFun::get_bonding_curve_pda(mint).unwrap();
     Instruction::new_with_bytes(
         constants::accounts::PUMPFUN,
         &args.data(),
         vec![
-            AccountMeta::new(mint.pubkey(), true),
+            AccountMeta::new(*mint, true),
             AccountMeta::new(PumpFun::get_mint_authority_pda(), false),
             AccountMeta::new(bonding_curve, false),
             AccountMeta::new(
-                get_associated_token_address(&bonding_curve, &mint.pubkey()),
+                PumpFun::get_associated_bonding_curve_v1(mint),
                 false,
             ),
             AccountMeta::new_readonly(PumpFun::get_global_pda(), false),
             AccountMeta::new_readonly(constants::accounts::MPL_TOKEN_METADATA, false),
-            AccountMeta::new(PumpFun::get_metadata_pda(&mint.pubkey()), false),
-            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(PumpFun::get_metadata_pda(mint), false),
+            AccountMeta::new(*payer, true),
             AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::TOKEN_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::ASSOCIATED_TOKEN_PROGRAM, false),
@@ -105,6 +261,23 @@ pub fn create(payer: &Keypair, mint: &Keypair, args: Create) -> Instruction {
     )
 }
 
+/// Creates an instruction to create a new token from keypairs
+///
+/// Convenience wrapper around [`create_instruction`] for callers holding the signing keypairs.
+///
+/// # Arguments
+///
+/// * `payer` - Keypair that will pay for account creation and transaction fees
+/// * `mint` - Keypair for the new token mint account that will be created
+/// * `args` - Create instruction data
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will create the token and its accounts
+pub fn create(payer: &Keypair, mint: &Keypair, args: Create) -> Instruction {
+    create_instruction(&payer.pubkey(), &mint.pubkey(), args)
+}
+
 /// Instruction data for creating a new token with Token 2022 (create_v2)
 ///
 /// # Fields
@@ -114,30 +287,62 @@ pub fn create(payer: &Keypair, mint: &Keypair, args: Create) -> Instruction {
 /// * `uri` - Metadata URI containing token information (image, description, etc.)
 /// * `creator` - Public key of the token creator
 /// * `is_mayhem_mode` - Whether to enable mayhem mode for this token
-#[derive(BorshSerialize, BorshDeserialize, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, serde::Serialize, serde::Deserialize, Clone, Debug)]
 pub struct CreateV2 {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    #[serde(with = "crate::utils::serde_pubkey")]
     pub creator: Pubkey,
     pub is_mayhem_mode: bool,
 }
 
 impl CreateV2 {
-    /// Instruction discriminator used to identify this instruction
-    pub const DISCRIMINATOR: [u8; 8] = [214, 144, 76, 236, 95, 139, 49, 180];
+    /// Default instruction discriminator used to identify this instruction
+    ///
+    /// The value written by `data()` can be overridden through `Discriminators`.
+    pub const DISCRIMINATOR: [u8; 8] = super::discriminator_for("create_v2");
 
-    /// Serializes the instruction data with the appropriate discriminator
+    /// Serializes the instruction data with the currently configured discriminator
     ///
     /// # Returns
     ///
     /// Byte vector containing the serialized instruction data
     pub fn data(&self) -> Vec<u8> {
         let mut data = Vec::with_capacity(256);
-        data.extend_from_slice(&Self::DISCRIMINATOR);
+        data.extend_from_slice(&super::Discriminators::current().create_v2);
         self.serialize(&mut data).unwrap();
         data
     }
+
+    /// Truncates the name, symbol and URI to the Metaplex metadata limits
+    ///
+    /// The limits in [`constants::metadata`] are in bytes. Each field is cut on a character
+    /// boundary with [`crate::utils::truncate_to_bytes`], so multibyte names never produce
+    /// invalid UTF-8 on-chain. Fields within their limits are left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pumpfun::instructions::CreateV2;
+    /// use solana_sdk::pubkey::Pubkey;
+    ///
+    /// let mut args = CreateV2 {
+    ///     name: "火箭".repeat(6),
+    ///     symbol: "ROCKETS🚀".to_string(),
+    ///     uri: "https://ipfs.io/ipfs/QmMetadata".to_string(),
+    ///     creator: Pubkey::new_unique(),
+    ///     is_mayhem_mode: false,
+    /// };
+    /// args.truncate_to_limits();
+    /// assert_eq!(args.name, "火箭".repeat(5));
+    /// assert_eq!(args.symbol, "ROCKETS");
+    /// ```
+    pub fn truncate_to_limits(&mut self) {
+        truncate_field(&mut self.name, constants::metadata::MAX_NAME_LENGTH);
+        truncate_field(&mut self.symbol, constants::metadata::MAX_SYMBOL_LENGTH);
+        truncate_field(&mut self.uri, constants::metadata::MAX_URI_LENGTH);
+    }
 }
 
 /// Creates an instruction to create a new Token 2022 token with bonding curve (create_v2)
@@ -148,8 +353,8 @@ impl CreateV2 {
 ///
 /// # Arguments
 ///
-/// * `payer` - Keypair that will pay for account creation and transaction fees
-/// * `mint` - Keypair for the new token mint account that will be created
+/// * `payer` - Public key of the account that will pay for account creation and transaction fees
+/// * `mint` - Public key of the new token mint account that will be created
 /// * `args` - CreateV2 instruction data containing token name, symbol, metadata URI, creator, and mayhem mode flag
 ///
 /// # Returns
@@ -175,28 +380,22 @@ impl CreateV2 {
 /// 14. Mayhem token vault (writable)
 /// 15. Event authority (readonly)
 /// 16. Pump.fun program ID (readonly)
-pub fn create_v2(payer: &Keypair, mint: &Keypair, args: CreateV2) -> Instruction {
-    let bonding_curve: Pubkey = PumpFun::get_bonding_curve_pda(&mint.pubkey()).unwrap();
+pub fn create_v2_instruction(payer: &Pubkey, mint: &Pubkey, args: CreateV2) -> Instruction {
+    let bonding_curve: Pubkey = PumpFun::get_bonding_curve_pda(mint).unwrap();
     let mayhem_program = constants::accounts::MAYHEM_PROGRAM;
     let global_params = PumpFun::get_global_params_pda();
     let sol_vault = PumpFun::get_sol_vault_pda();
-    let mayhem_state = PumpFun::get_mayhem_state_pda(&mint.pubkey());
-    let mayhem_token_vault = PumpFun::get_token_vault_pda(&mint.pubkey());
-
-    // Derive associated_bonding_curve PDA with Token 2022 program ID
-    // The PDA seeds are: [bonding_curve, token_program, mint]
-    // For create_v2, we must use TOKEN_2022_PROGRAM instead of TOKEN_PROGRAM
-    let associated_bonding_curve = PumpFun::get_associated_token_address_with_program(
-        &bonding_curve,
-        &mint.pubkey(),
-        &constants::accounts::TOKEN_2022_PROGRAM,
-    );
+    let mayhem_state = PumpFun::get_mayhem_state_pda(mint);
+    let mayhem_token_vault = PumpFun::get_token_vault_pda(mint);
+
+    // For create_v2, the associated bonding curve must be derived with TOKEN_2022_PROGRAM
+    let associated_bonding_curve = PumpFun::get_associated_bonding_curve_v2(mint);
 
     Instruction::new_with_bytes(
         constants::accounts::PUMPFUN,
         &args.data(),
         vec![
-            AccountMeta::new(mint.pubkey(), true),
+            AccountMeta::new(*mint, true),
             AccountMeta::new(PumpFun::get_mint_authority_pda(), false),
             AccountMeta::new(bonding_curve, false), // writable in IDL, but AccountMeta::new already makes it writable
             AccountMeta::new(
@@ -204,7 +403,7 @@ pub fn create_v2(payer: &Keypair, mint: &Keypair, args: CreateV2) -> Instruction
                 false, // writable in IDL, but AccountMeta::new already makes it writable
             ),
             AccountMeta::new_readonly(PumpFun::get_global_pda(), false),
-            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(*payer, true),
             AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::TOKEN_2022_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::ASSOCIATED_TOKEN_PROGRAM, false),
@@ -217,4 +416,82 @@ pub fn create_v2(payer: &Keypair, mint: &Keypair, args: CreateV2) -> Instruction
             AccountMeta::new_readonly(constants::accounts::PUMPFUN, false),
         ],
     )
-}
\ No newline at end of file
+}
+
+/// Creates an instruction to create a new Token 2022 token from keypairs
+///
+/// Convenience wrapper around [`create_v2_instruction`] for callers holding the signing keypairs.
+///
+/// # Arguments
+///
+/// * `payer` - Keypair that will pay for account creation and transaction fees
+/// * `mint` - Keypair for the new token mint account that will be created
+/// * `args` - CreateV2 instruction data
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will create the Token 2022 token and its accounts
+pub fn create_v2(payer: &Keypair, mint: &Keypair, args: CreateV2) -> Instruction {
+    create_v2_instruction(&payer.pubkey(), &mint.pubkey(), args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_serde_round_trip() {
+        let creator = Pubkey::new_unique();
+        let args = Create {
+            name: "Example".to_string(),
+            symbol: "EXM".to_string(),
+            uri: "https://example.com/metadata.json".to_string(),
+            creator,
+        };
+
+        // Creator is emitted as a base58 address
+        let json = serde_json::to_value(&args).unwrap();
+        assert_eq!(json["creator"], creator.to_string());
+
+        let decoded: Create = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.name, args.name);
+        assert_eq!(decoded.symbol, args.symbol);
+        assert_eq!(decoded.uri, args.uri);
+        assert_eq!(decoded.creator, creator);
+        assert_eq!(decoded.data(), args.data());
+    }
+
+    #[test]
+    fn test_create_builder() {
+        let creator = Pubkey::new_unique();
+        let builder = Create::builder()
+            .name("Example")
+            .symbol("EXM")
+            .uri("https://example.com/metadata.json")
+            .creator(format!(" {}\n", creator));
+
+        let args = builder.clone().build().unwrap();
+        assert_eq!(args.name, "Example");
+        assert_eq!(args.symbol, "EXM");
+        assert_eq!(args.creator, creator);
+
+        let args = builder.build_v2(true).unwrap();
+        assert_eq!(args.creator, creator);
+        assert!(args.is_mayhem_mode);
+
+        for invalid in ["", "not-a-pubkey", "0OIl"] {
+            let err = Create::builder().creator(invalid).build().unwrap_err();
+            assert!(matches!(
+                err,
+                error::ClientError::InvalidPubkey { field: "creator", ref value } if value == invalid
+            ));
+        }
+        assert!(Create::builder().build_v2(false).is_err());
+    }
+
+    #[test]
+    fn test_create_serde_rejects_invalid_pubkey() {
+        let json = r#"{"name":"Example","symbol":"EXM","uri":"","creator":"not-a-pubkey"}"#;
+        assert!(serde_json::from_str::<Create>(json).is_err());
+    }
+}
@@ -0,0 +1,153 @@
+//! Instruction list for creating a token and buying it in the same transaction
+//!
+//! This module assembles the instructions of a launch with an initial (dev) buy without
+//! touching the network, for callers that sign and send transactions themselves.
+
+use super::{buy_instruction, create_instruction, Buy, Create};
+use crate::constants;
+use solana_sdk::{
+    compute_budget::ComputeBudgetInstruction, instruction::Instruction, pubkey::Pubkey,
+};
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+/// Builds the ordered instructions creating a token and buying it in one transaction
+///
+/// The instructions are, in order:
+/// 1. Compute unit limit and price, if `with_priority_fee` is set
+/// 2. Create instruction for the token and its bonding curve
+/// 3. Idempotent creation of the payer's associated token account for the mint
+/// 4. Buy instruction
+///
+/// The buy's creator is `create_args.creator`, since the creator vault is derived from it.
+///
+/// # Arguments
+///
+/// * `payer` - Public key of the account paying for the launch and the buy
+/// * `mint` - Public key of the new token mint account that will be created
+/// * `fee_recipient` - Fee recipient of the buy, usually `Global.fee_recipient`
+/// * `create_args` - Create instruction data containing token name, symbol, URI and creator
+/// * `buy_args` - Buy instruction data containing the token amount and maximum SOL cost
+/// * `with_priority_fee` - Optional `(unit_limit, unit_price)` compute budget, the price in
+///   micro-lamports per compute unit
+///
+/// # Returns
+///
+/// Returns the instructions in execution order. The transaction must be signed by the payer
+/// and the mint
+///
+/// # Examples
+///
+/// ```
+/// use pumpfun::instructions::{build_create_and_buy, Buy, Create};
+/// use solana_sdk::pubkey::Pubkey;
+///
+/// let payer = Pubkey::new_unique();
+/// let mint = Pubkey::new_unique();
+/// let instructions = build_create_and_buy(
+///     &payer,
+///     &mint,
+///     &Pubkey::new_unique(),
+///     Create {
+///         name: "My Token".to_string(),
+///         symbol: "MYTKN".to_string(),
+///         uri: "https://ipfs.io/ipfs/QmMetadata".to_string(),
+///         creator: payer,
+///     },
+///     Buy { amount: 1_000_000, max_sol_cost: 10_000_000, track_volume: Some(true) },
+///     Some((200_000, 1_000)),
+/// );
+/// assert_eq!(instructions.len(), 5);
+/// ```
+pub fn build_create_and_buy(
+    payer: &Pubkey,
+    mint: &Pubkey,
+    fee_recipient: &Pubkey,
+    create_args: Create,
+    buy_args: Buy,
+    with_priority_fee: Option<(u32, u64)>,
+) -> Vec<Instruction> {
+    let mut instructions = Vec::with_capacity(5);
+
+    if let Some((unit_limit, unit_price)) = with_priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(unit_limit));
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(unit_price));
+    }
+
+    let creator = create_args.creator;
+    instructions.push(create_instruction(payer, mint, create_args));
+    instructions.push(create_associated_token_account_idempotent(
+        payer,
+        payer,
+        mint,
+        &constants::accounts::TOKEN_PROGRAM,
+    ));
+    instructions.push(buy_instruction(
+        payer,
+        mint,
+        fee_recipient,
+        &creator,
+        buy_args,
+    ));
+
+    instructions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_create_and_buy() {
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+        let create_args = Create {
+            name: "Example".to_string(),
+            symbol: "EXM".to_string(),
+            uri: "https://ipfs.io/ipfs/QmMetadata".to_string(),
+            creator,
+        };
+        let buy_args = Buy {
+            amount: 1_000_000,
+            max_sol_cost: 10_000_000,
+            track_volume: None,
+        };
+
+        let instructions = build_create_and_buy(
+            &payer,
+            &mint,
+            &Pubkey::new_unique(),
+            create_args.clone(),
+            buy_args.clone(),
+            Some((200_000, 1_000)),
+        );
+        let compute_budget = ComputeBudgetInstruction::set_compute_unit_limit(0).program_id;
+        let programs: Vec<Pubkey> = instructions.iter().map(|ix| ix.program_id).collect();
+        assert_eq!(
+            programs,
+            vec![
+                compute_budget,
+                compute_budget,
+                constants::accounts::PUMPFUN,
+                constants::accounts::ASSOCIATED_TOKEN_PROGRAM,
+                constants::accounts::PUMPFUN,
+            ]
+        );
+        assert_eq!(instructions[2].data, create_args.data());
+        assert_eq!(instructions[4].data, buy_args.data());
+
+        // The buy pays into the vault of the recorded creator, not the payer
+        let creator_vault = crate::PumpFun::get_creator_vault_pda(&creator).unwrap();
+        assert_eq!(instructions[4].accounts[9].pubkey, creator_vault);
+
+        let instructions = build_create_and_buy(
+            &payer,
+            &mint,
+            &Pubkey::new_unique(),
+            create_args,
+            buy_args,
+            None,
+        );
+        assert_eq!(instructions.len(), 3);
+    }
+}
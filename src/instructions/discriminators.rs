@@ -0,0 +1,373 @@
+//! Configurable instruction discriminators
+//!
+//! Pump.fun program upgrades occasionally change instruction discriminators. The
+//! `DISCRIMINATOR` constants on each instruction remain the defaults, but the values
+//! actually written into instruction data come from the process-wide [`Discriminators`]
+//! configuration, which can be replaced at runtime (for example from an updated IDL)
+//! without a new release of this crate.
+//!
+//! The defaults are the Anchor discriminators of the instruction names, computed at compile
+//! time by [`discriminator_for`] rather than hardcoded, so they cannot drift from the names.
+
+use crate::{error, instructions};
+use serde::Deserialize;
+use std::sync::RwLock;
+
+/// Process-wide override, `None` means the built-in defaults are used
+static OVERRIDE: RwLock<Option<Discriminators>> = RwLock::new(None);
+
+/// Discriminator used by the `extend_account` instruction
+pub const EXTEND_ACCOUNT_DISCRIMINATOR: [u8; 8] = discriminator_for("extend_account");
+
+/// Computes the Anchor discriminator of an instruction
+///
+/// Anchor identifies an instruction by the first 8 bytes of the SHA-256 hash of
+/// `global:<name>`, where `name` is the snake_case name of the instruction handler. This is
+/// a `const fn`: the `DISCRIMINATOR` constants of the instructions are computed with it at
+/// compile time.
+///
+/// # Arguments
+///
+/// * `name` - Instruction name as found in the IDL, e.g. `create_v2`
+///
+/// # Returns
+///
+/// Returns the 8-byte discriminator
+///
+/// # Examples
+///
+/// ```rust
+/// use pumpfun::instructions::{discriminator_for, Create};
+///
+/// assert_eq!(discriminator_for("create"), Create::DISCRIMINATOR);
+///
+/// // Also usable in constants
+/// const MIGRATE: [u8; 8] = discriminator_for("migrate");
+/// ```
+pub const fn discriminator_for(name: &str) -> [u8; 8] {
+    let hash = sha256(b"global:", name.as_bytes());
+    let mut discriminator = [0u8; 8];
+    let mut i = 0;
+    while i < 8 {
+        discriminator[i] = hash[i];
+        i += 1;
+    }
+    discriminator
+}
+
+/// Round constants of SHA-256
+const SHA256_K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// SHA-256 of `prefix` followed by `data`, usable in constants
+///
+/// `solana_sdk::hash::hashv` computes the same hash, but cannot be evaluated at compile
+/// time.
+const fn sha256(prefix: &[u8], data: &[u8]) -> [u8; 32] {
+    let len = prefix.len() + data.len();
+    // Message, a 0x80 byte and the 8-byte bit length, padded to whole 64-byte blocks
+    let blocks = (len + 9).div_ceil(64);
+    let bit_len = (len as u64).wrapping_mul(8).to_be_bytes();
+
+    let mut state: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+    let mut block = 0;
+    while block < blocks {
+        // Message schedule, reading the padded message byte by byte
+        let mut w = [0u32; 64];
+        let mut i = 0;
+        while i < 64 {
+            let position = block * 64 + i;
+            let byte = if position < prefix.len() {
+                prefix[position]
+            } else if position < len {
+                data[position - prefix.len()]
+            } else if position == len {
+                0x80
+            } else if position >= blocks * 64 - 8 {
+                bit_len[position - (blocks * 64 - 8)]
+            } else {
+                0
+            };
+            w[i / 4] |= (byte as u32) << (24 - 8 * (i % 4));
+            i += 1;
+        }
+        let mut i = 16;
+        while i < 64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[i - 7])
+                .wrapping_add(s1);
+            i += 1;
+        }
+
+        // Compression
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+        let mut i = 0;
+        while i < 64 {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let t1 = h
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(SHA256_K[i])
+                .wrapping_add(w[i]);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let t2 = s0.wrapping_add(maj);
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(t1);
+            d = c;
+            c = b;
+            b = a;
+            a = t1.wrapping_add(t2);
+            i += 1;
+        }
+        let compressed = [a, b, c, d, e, f, g, h];
+        let mut i = 0;
+        while i < 8 {
+            state[i] = state[i].wrapping_add(compressed[i]);
+            i += 1;
+        }
+        block += 1;
+    }
+
+    let mut hash = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        hash[i] = (state[i / 4] >> (24 - 8 * (i % 4))) as u8;
+        i += 1;
+    }
+    hash
+}
+
+/// Set of 8-byte discriminators used to identify Pump.fun instructions
+///
+/// # Fields
+///
+/// * `create` - Discriminator of the `create` instruction
+/// * `create_v2` - Discriminator of the `create_v2` instruction
+/// * `buy` - Discriminator of the `buy` instruction
+/// * `sell` - Discriminator of the `sell` instruction
+/// * `extend_account` - Discriminator of the `extend_account` instruction
+/// * `withdraw` - Discriminator of the `withdraw` instruction, with the `admin` feature
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Discriminators {
+    pub create: [u8; 8],
+    pub create_v2: [u8; 8],
+    pub buy: [u8; 8],
+    pub sell: [u8; 8],
+    pub extend_account: [u8; 8],
+    #[cfg(feature = "admin")]
+    pub withdraw: [u8; 8],
+}
+
+impl Default for Discriminators {
+    fn default() -> Self {
+        Self {
+            create: instructions::Create::DISCRIMINATOR,
+            create_v2: instructions::CreateV2::DISCRIMINATOR,
+            buy: instructions::Buy::DISCRIMINATOR,
+            sell: instructions::Sell::DISCRIMINATOR,
+            extend_account: EXTEND_ACCOUNT_DISCRIMINATOR,
+            #[cfg(feature = "admin")]
+            withdraw: instructions::Withdraw::DISCRIMINATOR,
+        }
+    }
+}
+
+/// Subset of an Anchor IDL needed to read instruction discriminators
+#[derive(Deserialize)]
+struct Idl {
+    instructions: Vec<IdlInstruction>,
+}
+
+#[derive(Deserialize)]
+struct IdlInstruction {
+    name: String,
+    discriminator: Option<[u8; 8]>,
+}
+
+impl Discriminators {
+    /// Loads discriminators from an Anchor IDL
+    ///
+    /// Instructions that are missing from the IDL, or that have no `discriminator`
+    /// entry, keep their default value.
+    ///
+    /// # Arguments
+    ///
+    /// * `json` - Contents of the program's IDL JSON file
+    ///
+    /// # Returns
+    ///
+    /// Returns the discriminators found in the IDL, or a `ClientError::OtherError` if the
+    /// JSON cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pumpfun::instructions::Discriminators;
+    ///
+    /// let idl = r#"{"instructions": [{"name": "buy", "discriminator": [1, 2, 3, 4, 5, 6, 7, 8]}]}"#;
+    /// let discriminators = Discriminators::from_idl_json(idl).unwrap();
+    /// assert_eq!(discriminators.buy, [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// assert_eq!(discriminators.sell, Discriminators::default().sell);
+    /// ```
+    #[allow(clippy::result_large_err)]
+    pub fn from_idl_json(json: &str) -> Result<Self, error::ClientError> {
+        let idl: Idl = serde_json::from_str(json).map_err(|err| {
+            error::ClientError::OtherError(format!("Failed to parse IDL: {}", err))
+        })?;
+
+        let mut discriminators = Self::default();
+        for instruction in idl.instructions {
+            let Some(discriminator) = instruction.discriminator else {
+                continue;
+            };
+
+            match instruction.name.as_str() {
+                "create" => discriminators.create = discriminator,
+                "create_v2" | "createV2" => discriminators.create_v2 = discriminator,
+                "buy" => discriminators.buy = discriminator,
+                "sell" => discriminators.sell = discriminator,
+                "extend_account" | "extendAccount" => discriminators.extend_account = discriminator,
+                #[cfg(feature = "admin")]
+                "withdraw" => discriminators.withdraw = discriminator,
+                _ => {}
+            }
+        }
+
+        Ok(discriminators)
+    }
+
+    /// Returns the discriminators currently used to build instructions
+    ///
+    /// # Returns
+    ///
+    /// The override installed with [`Discriminators::set_global`], or the defaults
+    pub fn current() -> Self {
+        OVERRIDE
+            .read()
+            .map(|guard| guard.unwrap_or_default())
+            .unwrap_or_default()
+    }
+
+    /// Installs these discriminators for every instruction built afterwards
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// use pumpfun::instructions::Discriminators;
+    ///
+    /// // Patch a changed buy discriminator during an incident
+    /// let mut discriminators = Discriminators::current();
+    /// discriminators.buy = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// discriminators.set_global();
+    /// ```
+    pub fn set_global(self) {
+        if let Ok(mut guard) = OVERRIDE.write() {
+            *guard = Some(self);
+        }
+    }
+
+    /// Removes any override installed with [`Discriminators::set_global`]
+    pub fn reset_global() {
+        if let Ok(mut guard) = OVERRIDE.write() {
+            *guard = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_defaults_match_instruction_names() {
+        let defaults = Discriminators::default();
+        assert_eq!(defaults.create, discriminator_for("create"));
+        assert_eq!(defaults.create_v2, discriminator_for("create_v2"));
+        assert_eq!(defaults.buy, discriminator_for("buy"));
+        assert_eq!(defaults.sell, discriminator_for("sell"));
+        assert_eq!(defaults.extend_account, discriminator_for("extend_account"));
+        #[cfg(feature = "admin")]
+        assert_eq!(defaults.withdraw, discriminator_for("withdraw"));
+    }
+
+    #[test]
+    fn test_discriminator_for_matches_sha256() {
+        // Names around the 55 and 119 byte padding boundaries cover one to three blocks
+        for len in [0, 1, 47, 48, 49, 56, 64, 111, 112, 113, 200] {
+            let name = "a".repeat(len);
+            let hash = solana_sdk::hash::hashv(&[b"global:", name.as_bytes()]);
+            assert_eq!(
+                discriminator_for(&name),
+                hash.to_bytes()[..8],
+                "length {}",
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn test_computed_discriminators_are_unchanged() {
+        let defaults = Discriminators::default();
+        assert_eq!(defaults.create, [24, 30, 200, 40, 5, 28, 7, 119]);
+        assert_eq!(defaults.create_v2, [214, 144, 76, 236, 95, 139, 49, 180]);
+        assert_eq!(defaults.buy, [102, 6, 61, 18, 1, 218, 235, 234]);
+        assert_eq!(defaults.sell, [51, 230, 133, 164, 1, 127, 131, 173]);
+        assert_eq!(
+            defaults.extend_account,
+            [234, 102, 194, 203, 150, 72, 62, 229]
+        );
+        #[cfg(feature = "admin")]
+        assert_eq!(defaults.withdraw, [183, 18, 70, 156, 148, 109, 161, 34]);
+    }
+
+    #[test]
+    fn test_from_idl_json() {
+        let idl = r#"{
+            "address": "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P",
+            "instructions": [
+                {"name": "create", "discriminator": [1, 1, 1, 1, 1, 1, 1, 1], "accounts": []},
+                {"name": "sell", "discriminator": [2, 2, 2, 2, 2, 2, 2, 2]},
+                {"name": "extend_account", "discriminator": [3, 3, 3, 3, 3, 3, 3, 3]},
+                {"name": "withdraw", "discriminator": [4, 4, 4, 4, 4, 4, 4, 4]},
+                {"name": "buy"}
+            ]
+        }"#;
+
+        let discriminators = Discriminators::from_idl_json(idl).unwrap();
+        let defaults = Discriminators::default();
+        assert_eq!(discriminators.create, [1; 8]);
+        assert_eq!(discriminators.sell, [2; 8]);
+        assert_eq!(discriminators.extend_account, [3; 8]);
+        #[cfg(feature = "admin")]
+        assert_eq!(discriminators.withdraw, [4; 8]);
+        assert_eq!(discriminators.buy, defaults.buy);
+        assert_eq!(discriminators.create_v2, defaults.create_v2);
+    }
+
+    #[test]
+    fn test_from_idl_json_invalid() {
+        assert!(Discriminators::from_idl_json("not json").is_err());
+        assert!(Discriminators::from_idl_json(
+            r#"{"instructions": [{"name": "buy", "discriminator": [1]}]}"#
+        )
+        .is_err());
+    }
+}
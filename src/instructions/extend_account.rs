@@ -18,7 +18,7 @@ use solana_sdk::{
 ///
 /// # Arguments
 ///
-/// * `payer` - Keypair that will pay for the account extension
+/// * `payer` - Public key of the account that will pay for the account extension
 /// * `account` - Public key of the account to extend
 ///
 /// # Returns
@@ -33,13 +33,13 @@ use solana_sdk::{
 /// 3. System program (readonly)
 /// 4. Event authority (readonly)
 /// 5. Pump.fun program ID (readonly)
-pub fn extend_account(payer: &Keypair, account: &Pubkey) -> Instruction {
+pub fn extend_account_instruction(payer: &Pubkey, account: &Pubkey) -> Instruction {
     Instruction::new_with_bytes(
         constants::accounts::PUMPFUN,
-        &[234, 102, 194, 203, 150, 72, 62, 229], // extend_account discriminator
+        &super::Discriminators::current().extend_account,
         vec![
             AccountMeta::new(*account, false),
-            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(*payer, true),
             AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::EVENT_AUTHORITY, false),
             AccountMeta::new_readonly(constants::accounts::PUMPFUN, false),
@@ -47,3 +47,18 @@ pub fn extend_account(payer: &Keypair, account: &Pubkey) -> Instruction {
     )
 }
 
+/// Creates an instruction to extend a program-owned account using the payer keypair
+///
+/// Convenience wrapper around [`extend_account_instruction`] for callers holding the payer keypair.
+///
+/// # Arguments
+///
+/// * `payer` - Keypair that will pay for the account extension
+/// * `account` - Public key of the account to extend
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will extend the account
+pub fn extend_account(payer: &Keypair, account: &Pubkey) -> Instruction {
+    extend_account_instruction(&payer.pubkey(), account)
+}
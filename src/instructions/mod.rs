@@ -7,13 +7,32 @@
 //! - `Create`: Creates a new token with an associated bonding curve.
 //! - `Buy`: Buys tokens from a bonding curve by providing SOL.
 //! - `Sell`: Sells tokens back to the bonding curve in exchange for SOL.
+//! - `withdraw`: Withdraws the reserves of a completed bonding curve (`admin` feature).
+//!
+//! `build_create_and_buy` assembles the full instruction list of a launch with an
+//! initial buy.
+//!
+//! Each builder comes in two flavours: a keypair-based function (e.g. `buy`) and a
+//! `*_instruction` variant (e.g. `buy_instruction`) that only takes public keys, for
+//! building transactions that are signed elsewhere.
+//!
+//! Instruction discriminators default to the constants on each instruction type and
+//! can be overridden at runtime with `Discriminators`.
 
 mod buy;
 mod create;
+mod create_and_buy;
+mod discriminators;
 mod extend_account;
 mod sell;
+#[cfg(feature = "admin")]
+mod withdraw;
 
 pub use buy::*;
 pub use create::*;
+pub use create_and_buy::*;
+pub use discriminators::*;
 pub use extend_account::*;
 pub use sell::*;
+#[cfg(feature = "admin")]
+pub use withdraw::*;
@@ -19,24 +19,61 @@ use spl_associated_token_account::get_associated_token_address;
 ///
 /// * `amount` - Amount of tokens to sell (in token smallest units)
 /// * `min_sol_output` - Minimum acceptable SOL received for the sale (slippage protection)
-#[derive(BorshSerialize, BorshDeserialize, Clone)]
+#[derive(BorshSerialize, BorshDeserialize, Clone, Debug)]
 pub struct Sell {
     pub amount: u64,
     pub min_sol_output: u64,
 }
 
 impl Sell {
-    /// Instruction discriminator used to identify this instruction
-    pub const DISCRIMINATOR: [u8; 8] = [51, 230, 133, 164, 1, 127, 131, 173];
+    /// Default instruction discriminator used to identify this instruction
+    ///
+    /// The value written by `data()` can be overridden through `Discriminators`.
+    pub const DISCRIMINATOR: [u8; 8] = super::discriminator_for("sell");
+
+    /// Starts building a sale of `amount` tokens
+    ///
+    /// Names the unit of each value at the call site, where the struct literal takes two bare
+    /// `u64`s. The minimum SOL output starts at `0`, i.e. without slippage protection, until
+    /// it is set with [`Sell::min_sol`].
+    ///
+    /// # Arguments
+    ///
+    /// * `amount` - Amount of tokens to sell, in token base units
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pumpfun::instructions::Sell;
+    ///
+    /// let args = Sell::tokens(1_000_000).min_sol(45_000_000);
+    /// let literal = Sell {
+    ///     amount: 1_000_000,
+    ///     min_sol_output: 45_000_000,
+    /// };
+    /// assert_eq!(args.data(), literal.data());
+    /// ```
+    pub fn tokens(amount: u64) -> Self {
+        Self {
+            amount,
+            min_sol_output: 0,
+        }
+    }
 
-    /// Serializes the instruction data with the appropriate discriminator
+    /// Sets the minimum SOL output of the sale, in lamports
+    pub fn min_sol(mut self, lamports: u64) -> Self {
+        self.min_sol_output = lamports;
+        self
+    }
+
+    /// Serializes the instruction data with the currently configured discriminator
     ///
     /// # Returns
     ///
     /// Byte vector containing the serialized instruction data
     pub fn data(&self) -> Vec<u8> {
         let mut data = Vec::with_capacity(256);
-        data.extend_from_slice(&Self::DISCRIMINATOR);
+        data.extend_from_slice(&super::Discriminators::current().sell);
         self.serialize(&mut data).unwrap();
         data
     }
@@ -51,7 +88,7 @@ impl Sell {
 ///
 /// # Arguments
 ///
-/// * `payer` - Keypair that owns the tokens to sell
+/// * `payer` - Public key of the account that owns the tokens to sell
 /// * `mint` - Public key of the token mint to sell
 /// * `fee_recipient` - Public key of the account that will receive the transaction fee
 /// * `creator` - Public key of the token's creator
@@ -78,8 +115,8 @@ impl Sell {
 /// 12. Pump.fun program ID (readonly)
 /// 13. Fee configuration account (readonly)
 /// 14. Fee configuration program ID (readonly)
-pub fn sell(
-    payer: &Keypair,
+pub fn sell_instruction(
+    payer: &Pubkey,
     mint: &Pubkey,
     fee_recipient: &Pubkey,
     creator: &Pubkey,
@@ -95,9 +132,9 @@ pub fn sell(
             AccountMeta::new(*fee_recipient, false),
             AccountMeta::new_readonly(*mint, false),
             AccountMeta::new(bonding_curve, false),
-            AccountMeta::new(get_associated_token_address(&bonding_curve, mint), false),
-            AccountMeta::new(get_associated_token_address(&payer.pubkey(), mint), false),
-            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(PumpFun::get_associated_bonding_curve_v1(mint), false),
+            AccountMeta::new(get_associated_token_address(payer, mint), false),
+            AccountMeta::new(*payer, true),
             AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
             AccountMeta::new(creator_vault, false),
             AccountMeta::new_readonly(constants::accounts::TOKEN_PROGRAM, false),
@@ -108,3 +145,28 @@ pub fn sell(
         ],
     )
 }
+
+/// Creates an instruction to sell tokens back to a bonding curve using the payer keypair
+///
+/// Convenience wrapper around [`sell_instruction`] for callers holding the payer keypair.
+///
+/// # Arguments
+///
+/// * `payer` - Keypair that owns the tokens to sell
+/// * `mint` - Public key of the token mint to sell
+/// * `fee_recipient` - Public key of the account that will receive the transaction fee
+/// * `creator` - Public key of the token's creator
+/// * `args` - Sell instruction data containing token amount and minimum acceptable SOL output
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will sell tokens to the bonding curve
+pub fn sell(
+    payer: &Keypair,
+    mint: &Pubkey,
+    fee_recipient: &Pubkey,
+    creator: &Pubkey,
+    args: Sell,
+) -> Instruction {
+    sell_instruction(&payer.pubkey(), mint, fee_recipient, creator, args)
+}
@@ -0,0 +1,127 @@
+//! Instruction for withdrawing the reserves of a completed bonding curve
+//!
+//! This module provides the `withdraw` instruction, which moves the SOL and tokens left in a
+//! completed bonding curve to the withdraw authority so they can be migrated. It is only
+//! compiled with the `admin` feature.
+//!
+//! # Authority Requirements
+//!
+//! The program only accepts the instruction when it is signed by the `withdraw_authority`
+//! stored in the global account, and only for a complete bonding curve. On mainnet that
+//! authority belongs to Pump.fun and migrations go through the PumpSwap AMM, so the
+//! instruction always fails for other signers. It is meant for deployments of the program
+//! where you control the global account, e.g. a fork or a local validator.
+
+use crate::{constants, PumpFun};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+};
+use spl_associated_token_account::get_associated_token_address;
+
+/// Instruction data for withdrawing the reserves of a completed bonding curve
+///
+/// The instruction takes no arguments, its data is only the discriminator.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Withdraw;
+
+impl Withdraw {
+    /// Default instruction discriminator used to identify this instruction
+    ///
+    /// The value written by `data()` can be overridden through `Discriminators`.
+    pub const DISCRIMINATOR: [u8; 8] = super::discriminator_for("withdraw");
+
+    /// Serializes the instruction data with the currently configured discriminator
+    ///
+    /// # Returns
+    ///
+    /// Byte vector containing the serialized instruction data
+    pub fn data(&self) -> Vec<u8> {
+        super::Discriminators::current().withdraw.to_vec()
+    }
+}
+
+/// Gets the PDA recording the time of the last withdrawal
+///
+/// # Returns
+///
+/// Returns the PDA public key derived from the `last-withdraw` seed
+pub fn get_last_withdraw_pda() -> Pubkey {
+    Pubkey::find_program_address(
+        &[constants::seeds::LAST_WITHDRAW_SEED],
+        &constants::accounts::PUMPFUN,
+    )
+    .0
+}
+
+/// Creates an instruction to withdraw the reserves of a completed bonding curve
+///
+/// Transfers the SOL and the remaining tokens of the bonding curve to the withdraw
+/// authority. The program only accepts it when signed by the `withdraw_authority` of the
+/// global account, so it always fails on mainnet; it is meant for forks of the program.
+///
+/// # Arguments
+///
+/// * `authority` - Public key of the global account's withdraw authority
+/// * `mint` - Public key of the token mint of the completed bonding curve
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will withdraw the curve's reserves
+///
+/// # Account Requirements
+///
+/// The instruction requires the following accounts in this order:
+/// 1. Global configuration PDA (readonly)
+/// 2. Last withdraw PDA (writable)
+/// 3. Token mint account (readonly)
+/// 4. Bonding curve PDA (writable)
+/// 5. Bonding curve token account (writable)
+/// 6. Authority's token account (writable)
+/// 7. Withdraw authority (signer, writable)
+/// 8. System program (readonly)
+/// 9. Token program (readonly)
+/// 10. Rent sysvar (readonly)
+/// 11. Event authority (readonly)
+/// 12. Pump.fun program ID (readonly)
+pub fn withdraw_instruction(authority: &Pubkey, mint: &Pubkey) -> Instruction {
+    let bonding_curve: Pubkey = PumpFun::get_bonding_curve_pda(mint).unwrap();
+    Instruction::new_with_bytes(
+        constants::accounts::PUMPFUN,
+        &Withdraw.data(),
+        vec![
+            AccountMeta::new_readonly(PumpFun::get_global_pda(), false),
+            AccountMeta::new(get_last_withdraw_pda(), false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(bonding_curve, false),
+            AccountMeta::new(PumpFun::get_associated_bonding_curve_v1(mint), false),
+            AccountMeta::new(get_associated_token_address(authority, mint), false),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
+            AccountMeta::new_readonly(constants::accounts::TOKEN_PROGRAM, false),
+            AccountMeta::new_readonly(constants::accounts::RENT, false),
+            AccountMeta::new_readonly(constants::accounts::EVENT_AUTHORITY, false),
+            AccountMeta::new_readonly(constants::accounts::PUMPFUN, false),
+        ],
+    )
+}
+
+/// Creates an instruction to withdraw the reserves of a completed bonding curve using the
+/// authority keypair
+///
+/// Convenience wrapper around [`withdraw_instruction`] for callers holding the authority
+/// keypair.
+///
+/// # Arguments
+///
+/// * `authority` - Keypair of the global account's withdraw authority
+/// * `mint` - Public key of the token mint of the completed bonding curve
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will withdraw the curve's reserves
+pub fn withdraw(authority: &Keypair, mint: &Pubkey) -> Instruction {
+    withdraw_instruction(&authority.pubkey(), mint)
+}
@@ -5,10 +5,18 @@ pub mod common;
 pub mod constants;
 pub mod error;
 pub mod instructions;
+pub mod parse;
+pub mod prelude;
 pub mod utils;
 
-use common::types::{Cluster, PriorityFee};
-use solana_client::nonblocking::rpc_client::RpcClient;
+use common::types::{
+    BalancePrediction, Cluster, MayhemAccounts, PriorityFee, SlippageMode, TokenVariant,
+    TokenVerification, TradeMode, TradeOptions, TransactionFee,
+};
+use solana_client::{
+    nonblocking::rpc_client::RpcClient, rpc_client::SerializableTransaction,
+    rpc_config::RpcSendTransactionConfig,
+};
 use solana_sdk::{
     compute_budget::ComputeBudgetInstruction,
     instruction::Instruction,
@@ -18,12 +26,13 @@ use solana_sdk::{
 };
 use spl_associated_token_account::get_associated_token_address;
 #[cfg(feature = "create-ata")]
-use spl_associated_token_account::instruction::create_associated_token_account;
+use spl_associated_token_account::instruction::{
+    create_associated_token_account, create_associated_token_account_idempotent,
+};
 #[cfg(feature = "close-ata")]
 use spl_token::instruction::close_account;
 use std::sync::Arc;
-use utils::transaction::get_transaction;
-use tracing::{info, error};
+use utils::transaction::{get_transaction, get_transaction_with_blockhash};
 
 /// Main client for interacting with the Pump.fun program
 ///
@@ -45,6 +54,41 @@ use tracing::{info, error};
 /// let cluster = Cluster::devnet(commitment, priority_fee);
 /// let client = PumpFun::new(payer, cluster);
 /// ```
+///
+/// # Concurrency
+///
+/// `PumpFun` is `Send + Sync`, and every fetch and send method takes `&self`, so a single
+/// client can be shared by many tasks (e.g. stored in web framework app state). Cloning is
+/// cheap: the payer and RPC client are behind `Arc`s and shared between clones, so all
+/// clones use the same underlying connection pool.
+///
+/// The `Debug` output only includes the payer's public key, never its secret key, and only
+/// the scheme and host of the RPC URLs, which often embed an API key.
+///
+/// ```
+/// use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+/// use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, signer::Signer};
+/// use std::sync::Arc;
+///
+/// let payer = Arc::new(Keypair::new());
+/// let cluster = Cluster::new(
+///     "https://rpc.example.com/?api-key=secret".to_string(),
+///     "wss://rpc.example.com/?api-key=secret".to_string(),
+///     CommitmentConfig::confirmed(),
+///     PriorityFee::default(),
+/// );
+/// let client = PumpFun::new(payer.clone(), cluster);
+///
+/// let shared = client.clone();
+/// assert!(Arc::ptr_eq(&client.rpc, &shared.rpc));
+///
+/// let debug = format!("{:?}", client);
+/// assert!(debug.contains(&payer.pubkey().to_string()));
+/// assert!(!debug.contains(&payer.to_base58_string()));
+/// assert!(debug.contains("https://rpc.example.com"));
+/// assert!(!debug.contains("secret"));
+/// ```
+#[derive(Clone)]
 pub struct PumpFun {
     /// Keypair used to sign transactions
     pub payer: Arc<Keypair>,
@@ -52,8 +96,66 @@ pub struct PumpFun {
     pub rpc: Arc<RpcClient>,
     /// Cluster configuration
     pub cluster: Cluster,
+    /// Strategy choosing the compute unit price when a send method gets no explicit
+    /// priority fee. If None, the cluster's priority fee is used as is
+    pub priority_fee_strategy: Option<Arc<dyn common::priority_fee::PriorityFeeStrategy>>,
+    /// Directory where [`PumpFun::create_and_buy`] saves the mint keypair of each new token.
+    /// If None, mint keypairs are not saved
+    pub mint_keypair_dir: Option<std::path::PathBuf>,
+    /// RPC options used when sending transactions, e.g. to skip preflight. If None,
+    /// transactions are simulated at the client's commitment before being sent
+    pub send_config: Option<RpcSendTransactionConfig>,
+    /// Uploader producing the metadata URI of the tokens created by the client. If None,
+    /// metadata is uploaded to the Pump.fun IPFS API
+    pub metadata_uploader: Option<Arc<dyn utils::uploader::MetadataUploader>>,
+    /// Normalization applied to the symbol of the tokens created by the client, before the
+    /// metadata is uploaded. Leaves symbols unchanged by default
+    pub symbol_normalization: utils::SymbolNormalization,
+    /// Index searched by [`PumpFun::find_similar_tokens`] for tokens resembling a new launch.
+    /// If None, no tokens are reported
+    pub token_index: Option<Arc<dyn common::token_index::TokenIndex>>,
+    /// Store of the idempotency keys used by [`PumpFun::create_and_buy_idempotent`]. Shared
+    /// by clones of the client, in memory by default
+    pub idempotency_store: Arc<dyn common::idempotency::IdempotencyStore>,
+}
+
+impl std::fmt::Debug for PumpFun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        // Only expose the payer's public key, never the secret key
+        f.debug_struct("PumpFun")
+            .field("payer", &self.payer.pubkey())
+            .field("rpc", &utils::redact_url(&self.rpc.url()))
+            .field("cluster", &self.cluster)
+            .field(
+                "priority_fee_strategy",
+                &self.priority_fee_strategy.is_some(),
+            )
+            .field("mint_keypair_dir", &self.mint_keypair_dir)
+            .field("send_config", &self.send_config)
+            .field("metadata_uploader", &self.metadata_uploader.is_some())
+            .field("symbol_normalization", &self.symbol_normalization)
+            .field("token_index", &self.token_index.is_some())
+            .finish()
+    }
 }
 
+// Compile-time guarantee that the client can be shared across threads and tasks, and that
+// its futures can be spawned on a multi-threaded runtime
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    fn assert_send<T: Send>(_: T) {}
+    assert_send_sync::<PumpFun>();
+    assert_send_sync::<error::ClientError>();
+
+    let _ = |client: &PumpFun, mint: Keypair, metadata: utils::CreateTokenMetadata| {
+        assert_send(client.buy(mint.pubkey(), 0, None, None, None));
+        assert_send(client.sell(mint.pubkey(), None, None, None));
+        assert_send(client.get_positions(&mint.pubkey()));
+        assert_send(client.create_batch(Vec::new(), 1));
+        assert_send(client.create_and_buy(mint, metadata, 0, None, None, None));
+    };
+};
+
 impl PumpFun {
     /// Creates a new PumpFun client instance
     ///
@@ -95,9 +197,191 @@ impl PumpFun {
             payer,
             rpc,
             cluster,
+            priority_fee_strategy: None,
+            mint_keypair_dir: None,
+            send_config: None,
+            metadata_uploader: None,
+            symbol_normalization: utils::SymbolNormalization::default(),
+            token_index: None,
+            idempotency_store: Arc::new(common::idempotency::InMemoryIdempotencyStore::default()),
         }
     }
 
+    /// Sets the strategy choosing the compute unit price of sent transactions
+    ///
+    /// Send methods called without an explicit priority fee then ask the strategy for the
+    /// compute unit price, keeping the cluster's compute unit limit. An explicit
+    /// `priority_fee` argument always takes precedence.
+    ///
+    /// # Arguments
+    ///
+    /// * `strategy` - Strategy to ask for compute unit prices, e.g.
+    ///   [`common::priority_fee::PercentileFeeStrategy`]
+    ///
+    /// # Returns
+    ///
+    /// Returns the client using the strategy
+    pub fn with_priority_fee_strategy(
+        mut self,
+        strategy: Arc<dyn common::priority_fee::PriorityFeeStrategy>,
+    ) -> Self {
+        self.priority_fee_strategy = Some(strategy);
+        self
+    }
+
+    /// Sets a directory where the mint keypair of each new token is saved
+    ///
+    /// [`PumpFun::create_and_buy`] then writes the mint keypair to `<dir>/<mint>.json` with
+    /// [`utils::save_keypair`] before uploading or sending anything, so the key is kept even
+    /// if the launch fails. The file can be read back with [`utils::load_keypair`] or by the
+    /// Solana CLI.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - Directory to save keypairs in, created if missing
+    ///
+    /// # Returns
+    ///
+    /// Returns the client saving mint keypairs
+    pub fn with_mint_keypair_dir(mut self, dir: impl Into<std::path::PathBuf>) -> Self {
+        self.mint_keypair_dir = Some(dir.into());
+        self
+    }
+
+    /// Sets the RPC options used when sending transactions
+    ///
+    /// Every send method of the client then sends its transaction with `config` and polls
+    /// its status until it is confirmed, see
+    /// [`utils::transaction::send_and_confirm_transaction`]. Buys and sells can override it
+    /// per transaction with [`TradeOptions::with_send_config`].
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Send options, e.g. `skip_preflight` to save the simulation round trip
+    ///
+    /// # Returns
+    ///
+    /// Returns the client using the send options
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// use solana_client::rpc_config::RpcSendTransactionConfig;
+    /// use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// use std::sync::Arc;
+    ///
+    /// let cluster = Cluster::mainnet(CommitmentConfig::confirmed(), PriorityFee::default());
+    /// let client = PumpFun::new(Arc::new(Keypair::new()), cluster).with_send_config(
+    ///     RpcSendTransactionConfig {
+    ///         skip_preflight: true,
+    ///         max_retries: Some(0),
+    ///         ..Default::default()
+    ///     },
+    /// );
+    /// ```
+    pub fn with_send_config(mut self, config: RpcSendTransactionConfig) -> Self {
+        self.send_config = Some(config);
+        self
+    }
+
+    /// Sets the uploader producing the metadata URI of created tokens
+    ///
+    /// Every create method of the client then passes its `CreateTokenMetadata` to the
+    /// uploader instead of the Pump.fun IPFS API, e.g. to use another pinning service or
+    /// metadata that is already hosted. See [`utils::uploader::MetadataUploader`].
+    ///
+    /// # Arguments
+    ///
+    /// * `uploader` - Uploader returning the metadata URI of each new token
+    ///
+    /// # Returns
+    ///
+    /// Returns the client using the uploader
+    pub fn with_metadata_uploader(
+        mut self,
+        uploader: Arc<dyn utils::uploader::MetadataUploader>,
+    ) -> Self {
+        self.metadata_uploader = Some(uploader);
+        self
+    }
+
+    /// Sets the normalization applied to the symbol of created tokens
+    ///
+    /// Every create method of the client then normalizes `CreateTokenMetadata::symbol` before
+    /// uploading the metadata, and writes the same normalized symbol to the `Create`
+    /// instruction, so the uploaded and on-chain symbols always match.
+    ///
+    /// # Arguments
+    ///
+    /// * `normalization` - Trimming and uppercasing to apply, see
+    ///   [`utils::SymbolNormalization`]
+    ///
+    /// # Returns
+    ///
+    /// Returns the client normalizing symbols
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pumpfun::{
+    ///     common::types::{Cluster, PriorityFee},
+    ///     utils::SymbolNormalization,
+    ///     PumpFun,
+    /// };
+    /// use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// use std::sync::Arc;
+    ///
+    /// let cluster = Cluster::mainnet(CommitmentConfig::confirmed(), PriorityFee::default());
+    /// let client = PumpFun::new(Arc::new(Keypair::new()), cluster).with_symbol_normalization(
+    ///     SymbolNormalization { trim: true, uppercase: true },
+    /// );
+    /// ```
+    pub fn with_symbol_normalization(mut self, normalization: utils::SymbolNormalization) -> Self {
+        self.symbol_normalization = normalization;
+        self
+    }
+
+    /// Sets the index searched for tokens resembling a new launch
+    ///
+    /// [`PumpFun::find_similar_tokens`] then asks the index for existing tokens sharing the
+    /// name or symbol of a token about to be launched. See
+    /// [`common::token_index::TokenIndex`].
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of existing tokens, e.g.
+    ///   [`common::token_index::RecentCreatesIndex`] with the `stream` feature
+    ///
+    /// # Returns
+    ///
+    /// Returns the client using the index
+    pub fn with_token_index(mut self, index: Arc<dyn common::token_index::TokenIndex>) -> Self {
+        self.token_index = Some(index);
+        self
+    }
+
+    /// Sets the store remembering the idempotency keys of recent launches
+    ///
+    /// Replaces the in-memory store used by [`PumpFun::create_and_buy_idempotent`], e.g. to
+    /// share keys between instances of a backend. See [`common::idempotency`] for the limits
+    /// of the guarantee.
+    ///
+    /// # Arguments
+    ///
+    /// * `store` - Store of idempotency keys
+    ///
+    /// # Returns
+    ///
+    /// Returns the client using the store
+    pub fn with_idempotency_store(
+        mut self,
+        store: Arc<dyn common::idempotency::IdempotencyStore>,
+    ) -> Self {
+        self.idempotency_store = store;
+        self
+    }
+
     /// Creates a new token with metadata by uploading metadata to IPFS and initializing on-chain accounts
     ///
     /// This method handles the complete process of creating a new token on Pump.fun:
@@ -145,6 +429,8 @@ impl PumpFun {
     ///     twitter: None,
     ///     telegram: None,
     ///     website: Some("https://example.com".to_string()),
+    ///     created_on: None,
+    ///     extra: Default::default(),
     /// };
     ///
     /// let signature = client.create(mint, metadata, None).await?;
@@ -158,13 +444,13 @@ impl PumpFun {
         metadata: utils::CreateTokenMetadata,
         priority_fee: Option<PriorityFee>,
     ) -> Result<Signature, error::ClientError> {
-        // First upload metadata and image to IPFS
-        let ipfs: utils::TokenMetadataResponse = utils::create_token_metadata(metadata)
-            .await
-            .map_err(error::ClientError::UploadMetadataError)?;
+        // First upload metadata and image
+        let ipfs = self.upload_metadata(&metadata).await?;
 
-        // Add priority fee if provided or default to cluster priority fee
-        let priority_fee = priority_fee.unwrap_or(self.cluster.priority_fee);
+        // Add priority fee if provided or default to the strategy or cluster priority fee
+        let priority_fee = self
+            .resolve_priority_fee(priority_fee, &Self::get_priority_fee_accounts(None))
+            .await?;
         let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
 
         // Add create token instruction
@@ -183,44 +469,113 @@ impl PumpFun {
         .await?;
 
         // Send and confirm transaction
-        let signature = self
-            .rpc
-            .send_and_confirm_transaction(&transaction)
-            .await
-            .map_err(error::ClientError::SolanaClientError)?;
+        let signature = utils::transaction::send_and_confirm_transaction(
+            &self.rpc,
+            &transaction,
+            self.send_config,
+        )
+        .await?;
 
         Ok(signature)
     }
 
-    /// Creates a new token and immediately buys an initial amount in a single atomic transaction
+    /// Creates many tokens at once, with a bounded number of launches in flight
     ///
-    /// This method combines token creation and an initial purchase into a single atomic transaction.
-    /// This is often preferred for new token launches as it:
-    /// 1. Creates the token and its bonding curve
-    /// 2. Makes an initial purchase to establish liquidity
-    /// 3. Guarantees that the creator becomes the first holder
+    /// Runs [`PumpFun::create`] for every spec, at most `concurrency` at a time. All launches
+    /// share the client's configuration, including its metadata uploader: give it an
+    /// [`utils::UploadOptions`] circuit breaker to stop hitting a failing IPFS API for the
+    /// rest of the batch. A failed launch, e.g. because of a missing image, only fails its own
+    /// entry; the other tokens are still created.
     ///
-    /// The entire operation is executed as a single transaction, ensuring atomicity.
+    /// # Arguments
+    ///
+    /// * `specs` - Mint, metadata and priority fee of each token to create
+    /// * `concurrency` - Maximum number of launches in flight. Zero is treated as one
+    ///
+    /// # Returns
+    ///
+    /// Returns one entry per spec, in the order of `specs`: the mint and signature of the
+    /// created token, or the ClientError that made its launch fail
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::{launch::CreateSpec, types::{Cluster, PriorityFee}}, utils::CreateTokenMetadata};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let specs = (1..=50)
+    ///     .map(|i| {
+    ///         CreateSpec::new(CreateTokenMetadata {
+    ///             name: format!("Drop #{}", i),
+    ///             symbol: format!("DROP{}", i),
+    ///             description: "Themed drop".to_string(),
+    ///             file: format!("images/{}.png", i),
+    ///             ..Default::default()
+    ///         })
+    ///     })
+    ///     .collect();
+    ///
+    /// for result in client.create_batch(specs, 5).await {
+    ///     match result {
+    ///         Ok(launch) => println!("{} created: {}", launch.mint, launch.signature),
+    ///         Err(err) => eprintln!("Launch failed: {}", err),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_batch(
+        &self,
+        specs: Vec<common::launch::CreateSpec>,
+        concurrency: usize,
+    ) -> Vec<Result<common::launch::LaunchResult, error::ClientError>> {
+        use futures::StreamExt;
+
+        // Launches run concurrently on the current task, results keep the order of `specs`
+        futures::stream::iter(specs)
+            .map(|spec| async move {
+                let mint = spec.mint.pubkey();
+                let signature = self
+                    .create(spec.mint, spec.metadata, spec.priority_fee)
+                    .await?;
+                Ok(common::launch::LaunchResult { mint, signature })
+            })
+            .buffered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Creates a new token on behalf of another wallet, which becomes its creator
+    ///
+    /// Works like [`PumpFun::create`], except that `creator` instead of the payer is recorded
+    /// as the token's creator. The payer funds the launch and signs together with the mint;
+    /// `creator` does not need to sign, so this works for wallets you don't control. Creator
+    /// fees accrue in the vault returned by [`PumpFun::get_creator_vault_pda`] for `creator`.
     ///
     /// # Arguments
     ///
     /// * `mint` - Keypair for the new token mint account that will be created
     /// * `metadata` - Token metadata including name, symbol, description and image file
-    /// * `amount_sol` - Amount of SOL to spend on the initial buy, in lamports (1 SOL = 1,000,000,000 lamports)
-    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
-    ///   If None, defaults to 500 (5%)
+    /// * `creator` - Wallet recorded as the token's creator, earning the creator fees
     /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
     ///   default from the cluster configuration
     ///
     /// # Returns
     ///
-    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    /// Returns the transaction signature together with the creator vault of `creator`, or a
+    /// ClientError if the operation fails
     ///
     /// # Errors
     ///
     /// Returns an error if:
+    /// - `creator` cannot receive creator fees (`ClientError::InvalidCreator`)
     /// - Metadata upload to IPFS fails
-    /// - Account retrieval fails
     /// - Transaction creation fails
     /// - Transaction execution on Solana fails
     ///
@@ -228,7 +583,7 @@ impl PumpFun {
     ///
     /// ```no_run
     /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils::CreateTokenMetadata};
-    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, signature::Keypair};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, pubkey, signature::Keypair};
     /// # use std::sync::Arc;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -244,52 +599,44 @@ impl PumpFun {
     ///     file: "path/to/image.png".to_string(),
     ///     twitter: None,
     ///     telegram: None,
-    ///     website: Some("https://example.com".to_string()),
+    ///     website: None,
+    ///     created_on: None,
+    ///     extra: Default::default(),
     /// };
     ///
-    /// // Create token and buy 0.1 SOL worth with 5% slippage tolerance
-    /// let amount_sol = sol_to_lamports(0.1f64); // 0.1 SOL in lamports
-    /// let slippage_bps = Some(500); // 5%
-    /// let track_volume = Some(true); // Track this initial buy in volume stats
-    ///
-    /// let signature = client.create_and_buy(mint, metadata, amount_sol, track_volume, slippage_bps, None).await?;
-    /// println!("Token created and bought! Signature: {}", signature);
+    /// let client_wallet = pubkey!("C1ientWa11etXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let (signature, creator_vault) = client
+    ///     .create_on_behalf_of(mint, metadata, client_wallet, None)
+    ///     .await?;
+    /// println!("Token created ({}), fees accrue in {}", signature, creator_vault);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn create_and_buy(
+    pub async fn create_on_behalf_of(
         &self,
         mint: Keypair,
         metadata: utils::CreateTokenMetadata,
-        amount_sol: u64,
-        track_volume: Option<bool>,
-        slippage_basis_points: Option<u64>,
+        creator: Pubkey,
         priority_fee: Option<PriorityFee>,
-    ) -> Result<Signature, error::ClientError> {
-        // Upload metadata to IPFS first
-        let ipfs: utils::TokenMetadataResponse = utils::create_token_metadata(metadata)
-            .await
-            .map_err(error::ClientError::UploadMetadataError)?;
+    ) -> Result<(Signature, Pubkey), error::ClientError> {
+        // Validate the creator before uploading anything
+        Self::check_creator(&creator, &mint.pubkey())?;
+        let creator_vault = Self::get_creator_vault_pda(&creator)
+            .ok_or(error::ClientError::InvalidCreator(creator))?;
+
+        // First upload metadata and image
+        let ipfs = self.upload_metadata(&metadata).await?;
 
-        // Add priority fee if provided or default to cluster priority fee
-        let priority_fee = priority_fee.unwrap_or(self.cluster.priority_fee);
+        // Add priority fee if provided or default to the strategy or cluster priority fee
+        let priority_fee = self
+            .resolve_priority_fee(priority_fee, &Self::get_priority_fee_accounts(None))
+            .await?;
         let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
 
-        // Add create token instruction
-        let create_ix = self.get_create_instruction(&mint, ipfs);
+        // Add create token instruction recording the other wallet as creator
+        let create_ix = self.get_create_instruction_for(&mint, ipfs, creator);
         instructions.push(create_ix);
 
-        // Add buy instruction
-        let buy_ix = self
-            .get_buy_instructions(
-                mint.pubkey(),
-                amount_sol,
-                track_volume,
-                slippage_basis_points,
-            )
-            .await?;
-        instructions.extend(buy_ix);
-
         // Create and sign transaction
         let transaction = get_transaction(
             self.rpc.clone(),
@@ -302,34 +649,41 @@ impl PumpFun {
         .await?;
 
         // Send and confirm transaction
-        let signature = self
-            .rpc
-            .send_and_confirm_transaction(&transaction)
-            .await
-            .map_err(error::ClientError::SolanaClientError)?;
+        let signature = utils::transaction::send_and_confirm_transaction(
+            &self.rpc,
+            &transaction,
+            self.send_config,
+        )
+        .await?;
 
-        Ok(signature)
+        Ok((signature, creator_vault))
     }
 
-    /// Buys tokens from a bonding curve by spending SOL
+    /// Creates a new token using a durable nonce so that retries cannot double-execute
     ///
-    /// This method purchases tokens from a bonding curve by providing SOL. The amount of tokens
-    /// received is determined by the bonding curve formula for the specific token. As more tokens
-    /// are purchased, the price increases according to the curve function.
+    /// Works like [`PumpFun::create`], but builds the transaction against the value stored in
+    /// a durable nonce account instead of a recent blockhash, and advances the nonce as the
+    /// first instruction. If a submission times out ambiguously, calling this method again
+    /// with the same `mint` keypair and nonce account is safe: whichever transaction lands
+    /// first advances the nonce, and every other one is rejected by the runtime.
     ///
-    /// The method:
-    /// 1. Calculates how many tokens will be received for the given SOL amount
-    /// 2. Creates an associated token account for the buyer if needed
-    /// 3. Executes the buy transaction with slippage protection
+    /// # Nonce account setup
     ///
-    /// A portion of the SOL is taken as a fee according to the global configuration.
+    /// The nonce account must exist before calling this method. Create it once with
+    /// `solana_system_interface::instruction::create_nonce_account`, or with the Solana CLI:
+    ///
+    /// ```sh
+    /// solana-keygen new -o nonce-account.json
+    /// solana create-nonce-account nonce-account.json 0.0015
+    /// ```
     ///
     /// # Arguments
     ///
-    /// * `mint` - Public key of the token mint to buy
-    /// * `amount_sol` - Amount of SOL to spend, in lamports (1 SOL = 1,000,000,000 lamports)
-    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
-    ///   If None, defaults to 500 (5%)
+    /// * `mint` - Keypair for the new token mint account that will be created. Reuse the same
+    ///   keypair when retrying
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    /// * `nonce_account` - Public key of the durable nonce account
+    /// * `nonce_authority` - Keypair authorized to advance the nonce account
     /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
     ///   default from the cluster configuration
     ///
@@ -340,91 +694,157 @@ impl PumpFun {
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The bonding curve account cannot be found
-    /// - The buy price calculation fails
+    /// - Metadata upload to IPFS fails
+    /// - The nonce account cannot be fetched or is not an initialized nonce account
     /// - Transaction creation fails
-    /// - Transaction execution on Solana fails
+    /// - Transaction execution on Solana fails, including when the nonce was already used
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
-    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, pubkey, signature::Keypair};
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils::CreateTokenMetadata};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, pubkey, signature::Keypair};
     /// # use std::sync::Arc;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let payer = Arc::new(Keypair::new());
     /// # let commitment = CommitmentConfig::confirmed();
     /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
-    /// # let client = PumpFun::new(payer, cluster);
-    /// let token_mint = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
-    ///
-    /// // Buy 0.01 SOL worth of tokens with 3% max slippage
-    /// let amount_sol = sol_to_lamports(0.01f64); // 0.01 SOL in lamports
-    /// let slippage_bps = Some(300); // 3%
-    /// let track_volume = Some(true); // Track this buy in volume stats
+    /// # let client = PumpFun::new(payer.clone(), cluster);
+    /// # let metadata = CreateTokenMetadata {
+    /// #     name: "My Token".to_string(),
+    /// #     symbol: "MYTKN".to_string(),
+    /// #     description: "A test token created with Pump.fun".to_string(),
+    /// #     file: "path/to/image.png".to_string(),
+    /// #     twitter: None,
+    /// #     telegram: None,
+    /// #     website: None,
+    /// #     created_on: None,
+    /// #     extra: Default::default(),
+    /// # };
+    /// let mint = Keypair::new();
+    /// let nonce_account = pubkey!("NonceAccount1111111111111111111111111111111");
     ///
-    /// let signature = client.buy(token_mint, amount_sol, track_volume, slippage_bps, None).await?;
-    /// println!("Tokens purchased! Signature: {}", signature);
+    /// let signature = client
+    ///     .create_with_nonce(mint, metadata, nonce_account, &payer, None)
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn buy(
+    pub async fn create_with_nonce(
         &self,
-        mint: Pubkey,
-        amount_sol: u64,
-        track_volume: Option<bool>,
-        slippage_basis_points: Option<u64>,
+        mint: Keypair,
+        metadata: utils::CreateTokenMetadata,
+        nonce_account: Pubkey,
+        nonce_authority: &Keypair,
         priority_fee: Option<PriorityFee>,
     ) -> Result<Signature, error::ClientError> {
-        // Add priority fee if provided or default to cluster priority fee
-        let priority_fee = priority_fee.unwrap_or(self.cluster.priority_fee);
-        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
+        // First upload metadata and image
+        let ipfs = self.upload_metadata(&metadata).await?;
 
-        // Add buy instruction
-        let buy_ix = self
-            .get_buy_instructions(mint, amount_sol, track_volume, slippage_basis_points)
+        // Advancing the nonce must be the first instruction of the transaction
+        let mut instructions = vec![
+            solana_system_interface::instruction::advance_nonce_account(
+                &nonce_account,
+                &nonce_authority.pubkey(),
+            ),
+        ];
+
+        // Add priority fee if provided or default to the strategy or cluster priority fee
+        let priority_fee = self
+            .resolve_priority_fee(priority_fee, &Self::get_priority_fee_accounts(None))
             .await?;
-        instructions.extend(buy_ix);
+        instructions.extend(Self::get_priority_fee_instructions(&priority_fee));
 
-        // Create and sign transaction
-        let transaction = get_transaction(
-            self.rpc.clone(),
+        // Add create token instruction
+        let create_ix = self.get_create_instruction(&mint, ipfs);
+        instructions.push(create_ix);
+
+        // Create and sign transaction against the stored nonce value
+        let nonce_blockhash = self.get_nonce_blockhash(&nonce_account).await?;
+        let signers: Vec<&Keypair> = if nonce_authority.pubkey() == self.payer.pubkey() {
+            vec![&mint]
+        } else {
+            vec![&mint, nonce_authority]
+        };
+        let transaction = get_transaction_with_blockhash(
             self.payer.clone(),
             &instructions,
-            None,
+            Some(&signers),
+            nonce_blockhash,
             #[cfg(feature = "versioned-tx")]
             None,
-        )
-        .await?;
+        )?;
 
         // Send and confirm transaction
-        let signature = self
-            .rpc
-            .send_and_confirm_transaction(&transaction)
-            .await
-            .map_err(error::ClientError::SolanaClientError)?;
+        let signature = utils::transaction::send_and_confirm_transaction(
+            &self.rpc,
+            &transaction,
+            self.send_config,
+        )
+        .await?;
 
         Ok(signature)
     }
 
-    /// Sells tokens back to the bonding curve in exchange for SOL
+    /// Gets the value currently stored in a durable nonce account
     ///
-    /// This method sells tokens back to the bonding curve, receiving SOL in return. The amount of SOL
-    /// received is determined by the bonding curve formula for the specific token. As more tokens
-    /// are sold, the price decreases according to the curve function.
+    /// This value is used in place of a recent blockhash by durable nonce transactions.
     ///
-    /// The method:
-    /// 1. Determines how many tokens to sell (all tokens or a specific amount)
-    /// 2. Calculates how much SOL will be received for the tokens
-    /// 3. Executes the sell transaction with slippage protection
+    /// # Arguments
     ///
-    /// A portion of the SOL is taken as a fee according to the global configuration.
+    /// * `nonce_account` - Public key of the durable nonce account
+    ///
+    /// # Returns
+    ///
+    /// Returns the stored nonce value if successful, or a ClientError if the account cannot
+    /// be fetched or is not an initialized nonce account
+    pub async fn get_nonce_blockhash(
+        &self,
+        nonce_account: &Pubkey,
+    ) -> Result<solana_sdk::hash::Hash, error::ClientError> {
+        let account = solana_client::nonce_utils::nonblocking::get_account_with_commitment(
+            &self.rpc,
+            nonce_account,
+            self.cluster.commitment,
+        )
+        .await
+        .map_err(|err| {
+            error::ClientError::OtherError(format!(
+                "Failed to fetch nonce account {}: {}",
+                nonce_account, err
+            ))
+        })?;
+
+        let data = solana_client::nonce_utils::nonblocking::data_from_account(&account).map_err(
+            |err| {
+                error::ClientError::OtherError(format!(
+                    "Invalid nonce account {}: {}",
+                    nonce_account, err
+                ))
+            },
+        )?;
+
+        Ok(data.blockhash())
+    }
+
+    /// Creates a new token and immediately buys an initial amount in a single atomic transaction
+    ///
+    /// This method combines token creation and an initial purchase into a single atomic transaction.
+    /// This is often preferred for new token launches as it:
+    /// 1. Creates the token and its bonding curve
+    /// 2. Makes an initial purchase to establish liquidity
+    /// 3. Guarantees that the creator becomes the first holder
+    ///
+    /// The entire operation is executed as a single transaction, ensuring atomicity.
     ///
     /// # Arguments
     ///
-    /// * `mint` - Public key of the token mint to sell
-    /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells the entire balance
+    /// * `mint` - Keypair for the new token mint account that will be created
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    /// * `amount_sol` - Amount of SOL to spend on the initial buy, in lamports (1 SOL = 1,000,000,000 lamports).
+    ///   `0` creates the token and the payer's associated token account without buying, so a
+    ///   later buy does not pay for the account creation
     /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
     ///   If None, defaults to 500 (5%)
     /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
@@ -437,17 +857,20 @@ impl PumpFun {
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The token account cannot be found
-    /// - The bonding curve account cannot be found
-    /// - The sell price calculation fails
+    /// - `amount_sol` exceeds the maximum initial buy (`ClientError::InitialBuyTooLarge`)
+    /// - The payer cannot cover the rent, the buy and the fees
+    ///   (`ClientError::InsufficientFunds`)
+    /// - The mint keypair cannot be saved to the client's `mint_keypair_dir`
+    /// - Metadata upload to IPFS fails
+    /// - Account retrieval fails
     /// - Transaction creation fails
     /// - Transaction execution on Solana fails
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
-    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils::CreateTokenMetadata};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, signature::Keypair};
     /// # use std::sync::Arc;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -455,378 +878,495 @@ impl PumpFun {
     /// # let commitment = CommitmentConfig::confirmed();
     /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
     /// # let client = PumpFun::new(payer, cluster);
-    /// let token_mint = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
-    ///
-    /// // Sell 1000 tokens with 2% max slippage
-    /// let amount_tokens = Some(1000);
-    /// let slippage_bps = Some(200); // 2%
+    /// let mint = Keypair::new();
+    /// let metadata = CreateTokenMetadata {
+    ///     name: "My Token".to_string(),
+    ///     symbol: "MYTKN".to_string(),
+    ///     description: "A test token created with Pump.fun".to_string(),
+    ///     file: "path/to/image.png".to_string(),
+    ///     twitter: None,
+    ///     telegram: None,
+    ///     website: Some("https://example.com".to_string()),
+    ///     created_on: None,
+    ///     extra: Default::default(),
+    /// };
     ///
-    /// let signature = client.sell(token_mint, amount_tokens, slippage_bps, None).await?;
-    /// println!("Tokens sold! Signature: {}", signature);
+    /// // Create token and buy 0.1 SOL worth with 5% slippage tolerance
+    /// let amount_sol = sol_to_lamports(0.1f64); // 0.1 SOL in lamports
+    /// let slippage_bps = Some(500); // 5%
+    /// let track_volume = Some(true); // Track this initial buy in volume stats
     ///
-    /// // Or sell all tokens with default slippage (5%)
-    /// let signature = client.sell(token_mint, None, None, None).await?;
-    /// println!("All tokens sold! Signature: {}", signature);
+    /// let signature = client.create_and_buy(mint, metadata, amount_sol, track_volume, slippage_bps, None).await?;
+    /// println!("Token created and bought! Signature: {}", signature);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn sell(
+    pub async fn create_and_buy(
         &self,
-        mint: Pubkey,
-        amount_token: Option<u64>,
+        mint: Keypair,
+        metadata: utils::CreateTokenMetadata,
+        amount_sol: u64,
+        track_volume: Option<bool>,
         slippage_basis_points: Option<u64>,
         priority_fee: Option<PriorityFee>,
     ) -> Result<Signature, error::ClientError> {
-        // Add priority fee if provided or default to cluster priority fee
-        let priority_fee = priority_fee.unwrap_or(self.cluster.priority_fee);
-        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
-
-        // Add sell instruction
-        let sell_ix = self
-            .get_sell_instructions(mint, amount_token, slippage_basis_points)
-            .await?;
-        instructions.extend(sell_ix);
-
-        // Create and sign transaction
-        let transaction = get_transaction(
-            self.rpc.clone(),
-            self.payer.clone(),
-            &instructions,
-            None,
-            #[cfg(feature = "versioned-tx")]
-            None,
+        self.create_and_buy_with_instructions(
+            mint,
+            metadata,
+            amount_sol,
+            track_volume,
+            slippage_basis_points,
+            priority_fee,
+            Vec::new(),
         )
-        .await?;
-
-        // Send and confirm transaction
-        let signature = self
-            .rpc
-            .send_and_confirm_transaction(&transaction)
-            .await
-            .map_err(error::ClientError::SolanaClientError)?;
-
-        Ok(signature)
+        .await
     }
 
-    /// Subscribes to real-time events from the Pump.fun program
-    ///
-    /// This method establishes a WebSocket connection to the Solana cluster and subscribes
-    /// to program log events from the Pump.fun program. It parses the emitted events into
-    /// structured data types and delivers them through the provided callback function.
+    /// Creates a new token and buys it at most once per idempotency key
     ///
-    /// Event types include:
-    /// - `CreateEvent`: Emitted when a new token is created
-    /// - `TradeEvent`: Emitted when tokens are bought or sold
-    /// - `CompleteEvent`: Emitted when a bonding curve operation completes
-    /// - `SetParamsEvent`: Emitted when global parameters are updated
+    /// Behaves like [`PumpFun::create_and_buy`], but first reserves `idempotency_key` in the
+    /// client's [`common::idempotency::IdempotencyStore`]. If a launch with the same key
+    /// already succeeded within the store's TTL, its signature is returned and nothing is
+    /// launched; `mint` and the other arguments are then ignored. A launch failing before its
+    /// transaction is sent releases the key so that it can be retried. Once sent, the
+    /// transaction may land even if sending or confirming it fails, so the key then stays
+    /// reserved until the store's TTL, see [`common::idempotency`].
     ///
     /// # Arguments
     ///
-    /// * `mentioned` - Optional public key to filter events by mentions. If None, subscribes to all Pump.fun events
-    /// * `commitment` - Optional commitment level for the subscription. If None, uses the
+    /// * `idempotency_key` - Key identifying the launch request, e.g. a request ID sent by
+    ///   the caller's frontend and kept across retries
+    /// * `mint` - Keypair for the new token mint account that will be created
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    /// * `amount_sol` - Amount of SOL to spend on the initial buy, in lamports
+    /// * `track_volume` - Optional flag to track the initial buy in volume stats
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
     ///   default from the cluster configuration
-    /// * `callback` - A function that will be called for each event with the following parameters:
-    ///   * `signature`: The transaction signature as a String
-    ///   * `event`: The parsed PumpFunEvent if successful, or None if parsing failed
-    ///   * `error`: Any error that occurred during parsing, or None if successful
-    ///   * `response`: The complete RPC logs response for additional context
     ///
     /// # Returns
     ///
-    /// Returns a `Subscription` object that manages the lifecycle of the subscription.
-    /// When this object is dropped, the subscription is automatically terminated. If
-    /// the subscription cannot be established, returns a ClientError.
+    /// Returns the signature of the launch, or of the earlier launch with the same key
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - The WebSocket connection cannot be established
-    /// - The subscription request fails
+    /// In addition to the errors of [`PumpFun::create_and_buy`], returns
+    /// `ClientError::LaunchInProgress` if a launch with the same key is still running, and
+    /// the errors of the store when reserving the key. Failures to record the outcome of the
+    /// launch are logged as warnings rather than returned, so that the signature or the
+    /// launch error always reaches the caller
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
-    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
-    /// # use std::{sync::Arc, error::Error};
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils::CreateTokenMetadata};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, signature::Keypair};
+    /// # use std::sync::Arc;
     /// #
-    /// # async fn example() -> Result<(), Box<dyn Error>> {
+    /// # async fn example(metadata: CreateTokenMetadata) -> Result<(), Box<dyn std::error::Error>> {
     /// # let payer = Arc::new(Keypair::new());
     /// # let commitment = CommitmentConfig::confirmed();
     /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
     /// # let client = PumpFun::new(payer, cluster);
-    /// #
-    /// // Subscribe to token events
-    /// let subscription = client.subscribe(None, None, |signature, event, error, _| {
-    ///     match event {
-    ///         Some(pumpfun::common::stream::PumpFunEvent::Create(create_event)) => {
-    ///             println!("New token created: {} ({})", create_event.name, create_event.symbol);
-    ///             println!("Mint address: {}", create_event.mint);
-    ///         },
-    ///         Some(pumpfun::common::stream::PumpFunEvent::Trade(trade_event)) => {
-    ///             let action = if trade_event.is_buy { "bought" } else { "sold" };
-    ///             println!(
-    ///                 "User {} {} {} tokens for {} SOL",
-    ///                 trade_event.user,
-    ///                 action,
-    ///                 trade_event.token_amount,
-    ///                 trade_event.sol_amount as f64 / 1_000_000_000.0
-    ///             );
-    ///         },
-    ///         Some(event) => println!("Other event received: {:#?}", event),
-    ///         None => {
-    ///             if let Some(err) = error {
-    ///                 eprintln!("Error parsing event in tx {}: {}", signature, err);
-    ///             }
-    ///         }
-    ///     }
-    /// }).await?;
-    ///
-    /// // Keep the subscription active
-    /// // When no longer needed, drop the subscription to unsubscribe
+    /// // Request ID from the API call, identical when the frontend retries
+    /// let request_id = "5f0c1f7e-launch-42";
+    ///
+    /// let signature = client
+    ///     .create_and_buy_idempotent(
+    ///         request_id,
+    ///         Keypair::new(),
+    ///         metadata,
+    ///         sol_to_lamports(0.1f64),
+    ///         None,
+    ///         None,
+    ///         None,
+    ///     )
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    #[cfg(feature = "stream")]
-    pub async fn subscribe<F>(
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_and_buy_idempotent(
         &self,
-        mentioned: Option<String>,
-        commitment: Option<solana_sdk::commitment_config::CommitmentConfig>,
-        callback: F,
-    ) -> Result<common::stream::Subscription, error::ClientError>
-    where
-        F: Fn(
-                String,
-                Option<common::stream::PumpFunEvent>,
-                Option<Box<dyn std::error::Error + Send + Sync>>,
-                solana_client::rpc_response::Response<solana_client::rpc_response::RpcLogsResponse>,
-            ) + Send
-            + Sync
-            + 'static,
-    {
-        common::stream::subscribe(self.cluster.clone(), mentioned, commitment, callback).await
-    }
+        idempotency_key: &str,
+        mint: Keypair,
+        metadata: utils::CreateTokenMetadata,
+        amount_sol: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        match self.idempotency_store.reserve(idempotency_key).await? {
+            common::idempotency::Reservation::Reserved => {}
+            common::idempotency::Reservation::InProgress => {
+                return Err(error::ClientError::LaunchInProgress(
+                    idempotency_key.to_string(),
+                ))
+            }
+            common::idempotency::Reservation::Completed(signature) => return Ok(signature),
+        }
 
-    /// Creates compute budget instructions for priority fees
-    ///
-    /// Generates Solana compute budget instructions based on the provided priority fee
-    /// configuration. These instructions are used to set the maximum compute units a
-    /// transaction can consume and the price per compute unit, which helps prioritize
-    /// transaction processing during network congestion.
-    ///
-    /// # Arguments
-    ///
-    /// * `priority_fee` - Priority fee configuration containing optional unit limit and unit price
-    ///
-    /// # Returns
-    ///
-    /// Returns a vector of instructions to set compute budget parameters, which can be
-    /// empty if no priority fee parameters are provided
-    ///
-    /// # Examples
-    ///
-    /// ```no_run
-    /// # use pumpfun::{PumpFun, common::types::PriorityFee};
-    /// # use solana_sdk::instruction::Instruction;
-    /// #
-    /// // Set both compute unit limit and price
-    /// let priority_fee = PriorityFee {
-    ///     unit_limit: Some(200_000),
-    ///     unit_price: Some(1_000), // 1000 micro-lamports per compute unit
-    /// };
-    ///
-    /// let compute_instructions: Vec<Instruction> = PumpFun::get_priority_fee_instructions(&priority_fee);
-    /// ```
-    pub fn get_priority_fee_instructions(priority_fee: &PriorityFee) -> Vec<Instruction> {
-        let mut instructions = Vec::new();
+        // The outcome of the launch takes precedence over store failures: its signature or
+        // error must reach the caller even if it could not be recorded
+        let warn_unrecorded = |err: error::ClientError| {
+            tracing::warn!(
+                key = idempotency_key,
+                error = %err,
+                "failed to record launch outcome in idempotency store"
+            );
+        };
 
-        if let Some(limit) = priority_fee.unit_limit {
-            let limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(limit);
-            instructions.push(limit_ix);
-        }
+        let transaction = match self
+            .build_launch_transaction(
+                &mint,
+                metadata,
+                amount_sol,
+                track_volume,
+                slippage_basis_points,
+                priority_fee,
+                Vec::new(),
+            )
+            .await
+        {
+            Ok(transaction) => transaction,
+            Err(err) => {
+                // Nothing was sent, so the launch can safely be retried
+                if let Err(err) = self.idempotency_store.release(idempotency_key).await {
+                    warn_unrecorded(err);
+                }
+                return Err(err);
+            }
+        };
 
-        if let Some(price) = priority_fee.unit_price {
-            let price_ix = ComputeBudgetInstruction::set_compute_unit_price(price);
-            instructions.push(price_ix);
+        // A failed send or confirmation does not mean the transaction did not land, so the
+        // key is not released and retries are rejected until it expires
+        let signature = utils::transaction::send_and_confirm_transaction(
+            &self.rpc,
+            &transaction,
+            self.send_config,
+        )
+        .await?;
+
+        if let Err(err) = self
+            .idempotency_store
+            .complete(idempotency_key, signature)
+            .await
+        {
+            warn_unrecorded(err);
         }
 
-        instructions
+        Ok(signature)
     }
 
-    /// Creates an instruction for initializing a new token
+    /// Creates a new token and buys it, together with additional instructions
     ///
-    /// Generates a Solana instruction to create a new token with a bonding curve on Pump.fun.
-    /// This instruction will initialize the token mint, metadata, and bonding curve accounts.
+    /// Behaves like [`PumpFun::create_and_buy`], but executes `extra_instructions`
+    /// atomically in the same transaction, e.g. a SOL transfer to a team wallet or a memo.
+    /// They are appended after the create and buy instructions, in order, so they see the
+    /// new mint and the payer's tokens.
+    ///
+    /// The extra instructions count towards the compute unit limit of `priority_fee`, or of
+    /// the cluster's priority fee if None. Raise `unit_limit` when adding instructions that
+    /// consume a significant amount of compute, otherwise the whole launch fails.
     ///
     /// # Arguments
     ///
     /// * `mint` - Keypair for the new token mint account that will be created
-    /// * `ipfs` - Token metadata response from IPFS upload containing name, symbol, and URI
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    /// * `amount_sol` - Amount of SOL to spend on the initial buy, in lamports, or `0` to
+    ///   only create the token
+    /// * `track_volume` - Optional flag to track this initial buy in volume stats
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    /// * `extra_instructions` - Instructions appended to the launch transaction. Additional
+    ///   signers are not supported, only the payer and the mint sign
     ///
     /// # Returns
     ///
-    /// Returns a Solana instruction for creating a new token
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils};
-    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils::CreateTokenMetadata};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, pubkey, signature::Keypair, signer::Signer};
     /// # use std::sync::Arc;
     /// #
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # async fn example(metadata: CreateTokenMetadata) -> Result<(), Box<dyn std::error::Error>> {
     /// # let payer = Arc::new(Keypair::new());
     /// # let commitment = CommitmentConfig::confirmed();
     /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
-    /// # let client = PumpFun::new(payer, cluster);
-    /// #
-    /// let mint = Keypair::new();
-    /// let metadata_response = utils::create_token_metadata(
-    ///     utils::CreateTokenMetadata {
-    ///         name: "Example Token".to_string(),
-    ///         symbol: "EXTKN".to_string(),
-    ///         description: "An example token".to_string(),
-    ///         file: "path/to/image.png".to_string(),
-    ///         twitter: None,
-    ///         telegram: None,
-    ///         website: None,
-    ///     }
-    /// ).await?;
-    ///
-    /// let create_instruction = client.get_create_instruction(&mint, metadata_response);
+    /// # let client = PumpFun::new(payer.clone(), cluster);
+    /// let team_wallet = pubkey!("TeamWa11etXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let transfer = solana_system_interface::instruction::transfer(
+    ///     &payer.pubkey(),
+    ///     &team_wallet,
+    ///     sol_to_lamports(0.05),
+    /// );
+    ///
+    /// let signature = client
+    ///     .create_and_buy_with_instructions(
+    ///         Keypair::new(),
+    ///         metadata,
+    ///         sol_to_lamports(0.1),
+    ///         None,
+    ///         Some(500),
+    ///         None,
+    ///         vec![transfer],
+    ///     )
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub fn get_create_instruction(
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_and_buy_with_instructions(
+        &self,
+        mint: Keypair,
+        metadata: utils::CreateTokenMetadata,
+        amount_sol: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+        extra_instructions: Vec<Instruction>,
+    ) -> Result<Signature, error::ClientError> {
+        let transaction = self
+            .build_launch_transaction(
+                &mint,
+                metadata,
+                amount_sol,
+                track_volume,
+                slippage_basis_points,
+                priority_fee,
+                extra_instructions,
+            )
+            .await?;
+
+        // Send and confirm transaction
+        let signature = utils::transaction::send_and_confirm_transaction(
+            &self.rpc,
+            &transaction,
+            self.send_config,
+        )
+        .await?;
+
+        Ok(signature)
+    }
+
+    /// Builds and signs the transaction of a launch, without sending it
+    ///
+    /// Shared by [`PumpFun::create_and_buy_with_instructions`] and
+    /// [`PumpFun::create_and_buy_idempotent`], which must tell failures before the
+    /// transaction is sent from failures after it
+    #[allow(clippy::too_many_arguments)]
+    async fn build_launch_transaction(
         &self,
         mint: &Keypair,
-        ipfs: utils::TokenMetadataResponse,
-    ) -> Instruction {
-        instructions::create(
-            &self.payer,
-            mint,
-            instructions::Create {
-                name: ipfs.metadata.name,
-                symbol: ipfs.metadata.symbol,
-                uri: ipfs.metadata_uri,
-                creator: self.payer.pubkey(),
-            },
+        metadata: utils::CreateTokenMetadata,
+        amount_sol: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+        extra_instructions: Vec<Instruction>,
+    ) -> Result<impl SerializableTransaction, error::ClientError> {
+        let mut instructions = self
+            .build_launch_instructions(
+                mint,
+                metadata,
+                amount_sol,
+                track_volume,
+                slippage_basis_points,
+                priority_fee,
+            )
+            .await?;
+        instructions.extend(extra_instructions);
+
+        // Create and sign transaction
+        get_transaction(
+            self.rpc.clone(),
+            self.payer.clone(),
+            &instructions,
+            Some(&[mint]),
+            #[cfg(feature = "versioned-tx")]
+            None,
         )
+        .await
     }
 
-    /// Creates a new Token 2022 token with metadata by uploading metadata to IPFS and initializing on-chain accounts
+    /// Prepares a create and buy transaction to send later with minimal latency
     ///
-    /// This method handles the complete process of creating a new Token 2022 token on Pump.fun:
-    /// 1. Uploads token metadata and image to IPFS
-    /// 2. Creates a new SPL Token 2022 token with the provided mint keypair
-    /// 3. Initializes the bonding curve that determines token pricing
-    /// 4. Supports mayhem mode functionality
+    /// Does all the slow work of [`PumpFun::create_and_buy`] ahead of time: checks the
+    /// initial buy and the payer's balance, resolves the priority fee, uploads the metadata
+    /// and builds and signs the transaction against a placeholder blockhash. Firing the
+    /// returned [`PreparedLaunch`](common::launch::PreparedLaunch) only swaps in a recent
+    /// blockhash, re-signs and sends.
+    ///
+    /// The buy is priced from the initial reserves, so it is valid whenever the launch is
+    /// fired. The priority fee is fixed when preparing.
     ///
     /// # Arguments
     ///
-    /// * `mint` - Keypair for the new token mint account that will be created
+    /// * `mint` - Keypair for the new token mint account, owned by the prepared launch
     /// * `metadata` - Token metadata including name, symbol, description and image file
-    /// * `mayhem_mode` - Whether to enable mayhem mode for this token
+    /// * `amount_sol` - Amount of SOL to spend on the initial buy, in lamports, or `0` to
+    ///   only create the token
+    /// * `track_volume` - Optional flag to track this initial buy in volume stats
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
     /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
     ///   default from the cluster configuration
     ///
     /// # Returns
     ///
-    /// Returns the transaction signature if successful, or a ClientError if the operation fails
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
-    /// - Metadata upload to IPFS fails
-    /// - Transaction creation fails
-    /// - Transaction execution on Solana fails
+    /// Returns the prepared launch, or a ClientError if any of the checks of
+    /// [`PumpFun::create_and_buy`] or the metadata upload fails
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils::CreateTokenMetadata};
-    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, signature::Keypair};
     /// # use std::sync::Arc;
     /// #
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # async fn example(metadata: CreateTokenMetadata) -> Result<(), Box<dyn std::error::Error>> {
     /// # let payer = Arc::new(Keypair::new());
     /// # let commitment = CommitmentConfig::confirmed();
     /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
     /// # let client = PumpFun::new(payer, cluster);
-    /// let mint = Keypair::new();
-    /// let metadata = CreateTokenMetadata {
-    ///     name: "My Token".to_string(),
-    ///     symbol: "MYTKN".to_string(),
-    ///     description: "A test token created with Pump.fun".to_string(),
-    ///     file: "path/to/image.png".to_string(),
-    ///     twitter: None,
-    ///     telegram: None,
-    ///     website: Some("https://example.com".to_string()),
-    /// };
+    /// let launch = client
+    ///     .prepare_launch(Keypair::new(), metadata, sol_to_lamports(0.5), None, Some(500), None)
+    ///     .await?;
     ///
-    /// let signature = client.create_v2(mint, metadata, false, None).await?;
-    /// println!("Token created! Signature: {}", signature);
+    /// // ... wait for the launch condition ...
+    ///
+    /// let signature = launch.fire().await?;
+    /// println!("Launched {}: {}", launch.mint(), signature);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn create_v2(
+    pub async fn prepare_launch(
         &self,
         mint: Keypair,
         metadata: utils::CreateTokenMetadata,
-        mayhem_mode: bool,
+        amount_sol: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
         priority_fee: Option<PriorityFee>,
-    ) -> Result<Signature, error::ClientError> {
-        // First upload metadata and image to IPFS
-        let ipfs: utils::TokenMetadataResponse = utils::create_token_metadata(metadata)
-            .await
-            .map_err(error::ClientError::UploadMetadataError)?;
-
-        // Add priority fee if provided or default to cluster priority fee
-        let priority_fee = priority_fee.unwrap_or(self.cluster.priority_fee);
-        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
-
-        // Add create_v2 token instruction
-        let create_ix = self.get_create_v2_instruction(&mint, ipfs, mayhem_mode);
-        instructions.push(create_ix);
+    ) -> Result<common::launch::PreparedLaunch, error::ClientError> {
+        let instructions = self
+            .build_launch_instructions(
+                &mint,
+                metadata,
+                amount_sol,
+                track_volume,
+                slippage_basis_points,
+                priority_fee,
+            )
+            .await?;
 
-        // Create and sign transaction
-        let transaction = get_transaction(
+        Ok(common::launch::PreparedLaunch::new(
             self.rpc.clone(),
             self.payer.clone(),
+            mint,
             &instructions,
-            Some(&[&mint]),
-            #[cfg(feature = "versioned-tx")]
-            None,
         )
-        .await?;
+        .with_send_config(self.send_config))
+    }
 
-        // Send and confirm transaction
-        let signature = self
-            .rpc
-            .send_and_confirm_transaction(&transaction)
-            .await
-            .map_err(error::ClientError::SolanaClientError)?;
+    /// Checks the payer and uploads the metadata of a launch, then builds its instructions
+    ///
+    /// Shared by [`PumpFun::create_and_buy`] and [`PumpFun::prepare_launch`]: returns the
+    /// priority fee, create and (if `amount_sol` is not `0`) buy instructions
+    async fn build_launch_instructions(
+        &self,
+        mint: &Keypair,
+        metadata: utils::CreateTokenMetadata,
+        amount_sol: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        // Reject an over-limit initial buy before uploading anything
+        self.check_initial_buy(amount_sol).await?;
 
-        Ok(signature)
+        // Add priority fee if provided or default to the strategy or cluster priority fee
+        let priority_fee = self
+            .resolve_priority_fee(priority_fee, &Self::get_priority_fee_accounts(None))
+            .await?;
+        let max_sol_cost = match amount_sol {
+            0 => 0,
+            _ => utils::calculate_with_slippage_buy(
+                amount_sol,
+                slippage_basis_points.unwrap_or(500),
+            ),
+        };
+
+        // Reject an underfunded payer before uploading anything, as the program would
+        // only fail once the transaction is executed
+        let rent = self.get_create_rent().await?;
+        let fee = Self::estimate_transaction_fee(&priority_fee, 2);
+        self.check_payer_balance(rent.saturating_add(max_sol_cost).saturating_add(fee))
+            .await?;
+
+        // Keep the mint keypair even if the launch fails past this point
+        if let Some(dir) = &self.mint_keypair_dir {
+            let path = dir.join(format!("{}.json", mint.pubkey()));
+            utils::save_keypair(mint, path).map_err(|err| {
+                error::ClientError::OtherError(format!("Failed to save mint keypair: {}", err))
+            })?;
+        }
+
+        // Upload metadata first
+        let ipfs = self.upload_metadata(&metadata).await?;
+
+        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
+
+        if amount_sol == 0 {
+            // No dev buy: the program rejects zero-amount buys, so only prepare the ATA
+            instructions.extend(self.get_create_without_buy_instructions(mint, ipfs));
+        } else {
+            // The curve does not exist yet, so the buy is priced from the initial reserves
+            let global_account = self.get_global_account().await?;
+            let buy_amount = global_account.get_initial_buy_price(amount_sol);
+
+            // Add create, ATA and buy instructions
+            instructions.extend(instructions::build_create_and_buy(
+                &self.payer.pubkey(),
+                &mint.pubkey(),
+                &global_account.fee_recipient,
+                instructions::Create {
+                    name: ipfs.metadata.name,
+                    symbol: ipfs.metadata.symbol,
+                    uri: ipfs.metadata_uri,
+                    creator: self.payer.pubkey(),
+                },
+                instructions::Buy {
+                    amount: buy_amount,
+                    max_sol_cost,
+                    track_volume,
+                },
+                None,
+            ));
+        }
+
+        Ok(instructions)
     }
 
-    /// Creates a new Token 2022 token and immediately buys an initial amount in a single atomic transaction
-    ///
-    /// This method combines Token 2022 token creation and an initial purchase into a single atomic transaction.
-    /// This is often preferred for new token launches as it:
-    /// 1. Creates the Token 2022 token and its bonding curve
-    /// 2. Makes an initial purchase to establish liquidity
-    /// 3. Guarantees that the creator becomes the first holder
-    /// 4. Supports mayhem mode functionality
+    /// Creates a new token and buys a percentage of its supply in a single atomic transaction
     ///
-    /// The entire operation is executed as a single transaction, ensuring atomicity.
+    /// Same as [`PumpFun::create_and_buy`], but the initial (dev) buy is expressed as a
+    /// percentage of the token's total supply instead of an amount of SOL. The token amount
+    /// is clamped to the maximum initial buy, and the SOL needed to buy it is solved from the
+    /// initial virtual reserves of the global account.
     ///
     /// # Arguments
     ///
     /// * `mint` - Keypair for the new token mint account that will be created
     /// * `metadata` - Token metadata including name, symbol, description and image file
-    /// * `amount_sol` - Amount of SOL to spend on the initial buy, in lamports (1 SOL = 1,000,000,000 lamports)
-    /// * `mayhem_mode` - Whether to enable mayhem mode for this token
+    /// * `supply_percent` - Percentage of the total supply to buy, e.g. `5.0` for 5%
+    /// * `track_volume` - Optional flag to track this initial buy in volume stats
     /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
     ///   If None, defaults to 500 (5%)
     /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
@@ -839,16 +1379,15 @@ impl PumpFun {
     /// # Errors
     ///
     /// Returns an error if:
-    /// - Metadata upload to IPFS fails
-    /// - Account retrieval fails
-    /// - Transaction creation fails
-    /// - Transaction execution on Solana fails
+    /// - `supply_percent` is not a positive, finite number
+    /// - The global account cannot be fetched
+    /// - Any of the errors of [`PumpFun::create_and_buy`] occurs
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils::CreateTokenMetadata};
-    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, signature::Keypair};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
     /// # use std::sync::Arc;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -864,135 +1403,65 @@ impl PumpFun {
     ///     file: "path/to/image.png".to_string(),
     ///     twitter: None,
     ///     telegram: None,
-    ///     website: Some("https://example.com".to_string()),
+    ///     website: None,
+    ///     created_on: None,
+    ///     extra: Default::default(),
     /// };
     ///
-    /// // Create Token 2022 token and buy 0.1 SOL worth with 5% slippage tolerance
-    /// let amount_sol = sol_to_lamports(0.1f64); // 0.1 SOL in lamports
-    /// let slippage_bps = Some(500); // 5%
-    /// let track_volume = Some(true); // Track this initial buy in volume stats
-    ///
-    /// let signature = client.create_v2_and_buy(mint, metadata, amount_sol, false, track_volume, slippage_bps, None).await?;
+    /// // Create token and buy 5% of the supply with 5% slippage tolerance
+    /// let signature = client
+    ///     .create_and_buy_percentage(mint, metadata, 5.0, None, Some(500), None)
+    ///     .await?;
     /// println!("Token created and bought! Signature: {}", signature);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn create_v2_and_buy(
+    pub async fn create_and_buy_percentage(
         &self,
         mint: Keypair,
         metadata: utils::CreateTokenMetadata,
-        amount_sol: u64,
-        mayhem_mode: bool,
+        supply_percent: f64,
         track_volume: Option<bool>,
         slippage_basis_points: Option<u64>,
         priority_fee: Option<PriorityFee>,
     ) -> Result<Signature, error::ClientError> {
-        // Upload metadata to IPFS first
-        let ipfs: utils::TokenMetadataResponse = utils::create_token_metadata(metadata)
-            .await
-            .map_err(error::ClientError::UploadMetadataError)?;
-
-        // Add priority fee if provided or default to cluster priority fee
-        let priority_fee = priority_fee.unwrap_or(self.cluster.priority_fee);
-        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
-
-        // Derive bonding curve PDA (needed for subsequent instructions)
-        let bonding_curve_pda = Self::get_bonding_curve_pda(&mint.pubkey())
-            .ok_or(error::ClientError::BondingCurveNotFound)?;
-
-        // Add create_v2 token instruction
-        // The program should create the associated_bonding_curve account via CPI during execution.
-        // The account is included in the instruction's account list (position 4) and marked as writable,
-        // and all necessary programs (ASSOCIATED_TOKEN_PROGRAM, TOKEN_2022_PROGRAM, SYSTEM_PROGRAM)
-        // are included, which should allow the program to create it via CPI.
-        let create_ix = self.get_create_v2_instruction(&mint, ipfs, mayhem_mode);
-        instructions.push(create_ix);
-
-        // Add extend account instruction for bonding curve
-        let extend_account_ix = instructions::extend_account(&self.payer, &bonding_curve_pda);
-        instructions.push(extend_account_ix);
-
-        // Add create associated token account instruction (idempotent) using Token 2022
-        // Pre-calculate the associated user ATA address
-        #[cfg(feature = "create-ata")]
-        {
-            let create_ata_ix = create_associated_token_account(
-                &self.payer.pubkey(),
-                &self.payer.pubkey(),
-                &mint.pubkey(),
-                &constants::accounts::TOKEN_2022_PROGRAM,
-            );
-            instructions.push(create_ata_ix);
+        if !supply_percent.is_finite() || supply_percent <= 0.0 {
+            return Err(error::ClientError::OtherError(format!(
+                "Invalid supply percentage: {}",
+                supply_percent
+            )));
         }
 
-        // Add buy instruction for v2
-        let buy_ix = self.get_buy_instructions_v2(
-            mint.pubkey(),
+        // Solve for the SOL buying the requested share of the supply
+        let global_account = self.get_global_account().await?;
+        let tokens = global_account.get_supply_percent_tokens(supply_percent);
+        let amount_sol = global_account
+            .sol_needed_for_tokens(tokens)
+            .min(global_account.get_max_initial_buy_sol());
+
+        self.create_and_buy(
+            mint,
+            metadata,
             amount_sol,
             track_volume,
-            slippage_basis_points
-        ).await?;
-        instructions.extend(buy_ix);
-
-        // Create and sign transaction
-        let transaction = get_transaction(
-            self.rpc.clone(),
-            self.payer.clone(),
-            &instructions,
-            Some(&[&mint]),
-            #[cfg(feature = "versioned-tx")]
-            None,
+            slippage_basis_points,
+            priority_fee,
         )
-        .await?;
-
-        // Send and confirm transaction
-        let signature = self
-            .rpc
-            .send_and_confirm_transaction(&transaction)
-            .await
-            .map_err(error::ClientError::SolanaClientError)?;
-
-        Ok(signature)
+        .await
     }
 
-    /// Creates an instruction for initializing a new Token 2022 token
+    /// Buys tokens from a bonding curve by spending SOL
     ///
-    /// Generates a Solana instruction to create a new Token 2022 token with a bonding curve on Pump.fun.
-    /// This instruction will initialize the token mint, bonding curve accounts, and mayhem mode accounts if enabled.
+    /// This method purchases tokens from a bonding curve by providing SOL. The amount of tokens
+    /// received is determined by the bonding curve formula for the specific token. As more tokens
+    /// are purchased, the price increases according to the curve function.
     ///
-    /// # Arguments
+    /// The method:
+    /// 1. Calculates how many tokens will be received for the given SOL amount
+    /// 2. Creates an associated token account for the buyer if needed
+    /// 3. Executes the buy transaction with slippage protection
     ///
-    /// * `mint` - Keypair for the new token mint account that will be created
-    /// * `ipfs` - Token metadata response from IPFS upload containing name, symbol, and URI
-    /// * `mayhem_mode` - Whether to enable mayhem mode for this token
-    ///
-    /// # Returns
-    ///
-    /// Returns a Solana instruction for creating a new Token 2022 token
-    pub fn get_create_v2_instruction(
-        &self,
-        mint: &Keypair,
-        ipfs: utils::TokenMetadataResponse,
-        mayhem_mode: bool,
-    ) -> Instruction {
-        instructions::create_v2(
-            &self.payer,
-            mint,
-            instructions::CreateV2 {
-                name: ipfs.metadata.name,
-                symbol: ipfs.metadata.symbol,
-                uri: ipfs.metadata_uri,
-                creator: self.payer.pubkey(),
-                is_mayhem_mode: mayhem_mode,
-            },
-        )
-    }
-
-    /// Generates instructions for buying tokens from a bonding curve
-    ///
-    /// Creates a set of Solana instructions needed to purchase tokens using SOL. These
-    /// instructions may include creating an associated token account if needed, and the actual
-    /// buy instruction with slippage protection.
+    /// A portion of the SOL is taken as a fee according to the global configuration.
     ///
     /// # Arguments
     ///
@@ -1000,23 +1469,26 @@ impl PumpFun {
     /// * `amount_sol` - Amount of SOL to spend, in lamports (1 SOL = 1,000,000,000 lamports)
     /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
     ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
     ///
     /// # Returns
     ///
-    /// Returns a vector of Solana instructions if successful, or a ClientError if the operation fails
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
     ///
     /// # Errors
     ///
     /// Returns an error if:
-    /// - The global account or bonding curve account cannot be fetched
+    /// - The bonding curve account cannot be found
     /// - The buy price calculation fails
-    /// - Token account-related operations fail
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
-    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, signature::Keypair, pubkey};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, pubkey, signature::Keypair};
     /// # use std::sync::Arc;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -1024,175 +1496,133 @@ impl PumpFun {
     /// # let commitment = CommitmentConfig::confirmed();
     /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
     /// # let client = PumpFun::new(payer, cluster);
-    /// #
-    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
-    /// let amount_sol = sol_to_lamports(0.01); // 0.01 SOL
+    /// let token_mint = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// // Buy 0.01 SOL worth of tokens with 3% max slippage
+    /// let amount_sol = sol_to_lamports(0.01f64); // 0.01 SOL in lamports
     /// let slippage_bps = Some(300); // 3%
     /// let track_volume = Some(true); // Track this buy in volume stats
     ///
-    /// let buy_instructions = client.get_buy_instructions(mint, amount_sol, track_volume, slippage_bps).await?;
+    /// let signature = client.buy(token_mint, amount_sol, track_volume, slippage_bps, None).await?;
+    /// println!("Tokens purchased! Signature: {}", signature);
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_buy_instructions(
+    pub async fn buy(
         &self,
         mint: Pubkey,
         amount_sol: u64,
         track_volume: Option<bool>,
         slippage_basis_points: Option<u64>,
-    ) -> Result<Vec<Instruction>, error::ClientError> {
-        // Get accounts and calculate buy amounts
-        let global_account = self.get_global_account().await?;
-        let mut bonding_curve_account: Option<accounts::BondingCurveAccount> = None;
-        let buy_amount = {
-            let bonding_curve_pda = Self::get_bonding_curve_pda(&mint)
-                .ok_or(error::ClientError::BondingCurveNotFound)?;
-            if self.rpc.get_account(&bonding_curve_pda).await.is_err() {
-                global_account.get_initial_buy_price(amount_sol)
-            } else {
-                bonding_curve_account = self.get_bonding_curve_account(&mint).await.ok();
-                bonding_curve_account
-                    .as_ref()
-                    .unwrap()
-                    .get_buy_price(amount_sol)
-                    .map_err(error::ClientError::BondingCurveError)?
-            }
-        };
-        let buy_amount_with_slippage =
-            utils::calculate_with_slippage_buy(amount_sol, slippage_basis_points.unwrap_or(500));
-
-        let mut instructions = Vec::new();
-
-        // Create Associated Token Account if needed
-        #[cfg(feature = "create-ata")]
-        {
-            let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), &mint);
-            if self.rpc.get_account(&ata).await.is_err() {
-                instructions.push(create_associated_token_account(
-                    &self.payer.pubkey(),
-                    &self.payer.pubkey(),
-                    &mint,
-                    &constants::accounts::TOKEN_PROGRAM,
-                ));
-            }
-        }
-
-        // Add buy instruction
-        instructions.push(instructions::buy(
-            &self.payer,
-            &mint,
-            &global_account.fee_recipient,
-            &bonding_curve_account.map_or(self.payer.pubkey(), |bc| bc.creator),
-            instructions::Buy {
-                amount: buy_amount,
-                max_sol_cost: buy_amount_with_slippage,
-                track_volume,
-            },
-        ));
-
-        Ok(instructions)
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        self.buy_with_options(
+            mint,
+            amount_sol,
+            track_volume,
+            slippage_basis_points,
+            priority_fee,
+            &TradeOptions::default(),
+        )
+        .await
     }
 
-    /// Generates instructions for buying tokens from a bonding curve using Token 2022
+    /// Buys tokens from a bonding curve using per-transaction trade options
     ///
-    /// Creates a set of Solana instructions needed to purchase Token 2022 tokens using SOL. These
-    /// instructions may include creating an associated token account if needed, and the actual
-    /// buy instruction with slippage protection.
+    /// Behaves like [`PumpFun::buy`], but lets the caller override values that are
+    /// otherwise derived from on-chain state, such as the fee recipient.
     ///
     /// # Arguments
     ///
     /// * `mint` - Public key of the token mint to buy
     /// * `amount_sol` - Amount of SOL to spend, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `track_volume` - Optional flag to track this buy in volume stats
     /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
-    ///   If None, defaults to 500 (5%)
+    ///   If None, defaults to 500 (5%). Ignored when `options.slippage` is set
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    /// * `options` - Per-transaction trade options
     ///
     /// # Returns
     ///
-    /// Returns a vector of Solana instructions if successful, or a ClientError if the operation fails
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
     ///
-    /// # Errors
+    /// # Examples
     ///
-    /// Returns an error if:
-    /// - The global account or bonding curve account cannot be fetched
-    /// - The buy price calculation fails
-    /// - Token account-related operations fail
-    pub async fn get_buy_instructions_v2(
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee, TradeOptions}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let token_mint = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let fee_recipient = pubkey!("FeeRec1p1entXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// let options = TradeOptions::default().with_fee_recipient(fee_recipient);
+    /// let signature = client
+    ///     .buy_with_options(token_mint, sol_to_lamports(0.01f64), None, None, None, &options)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn buy_with_options(
         &self,
         mint: Pubkey,
         amount_sol: u64,
         track_volume: Option<bool>,
         slippage_basis_points: Option<u64>,
-    ) -> Result<Vec<Instruction>, error::ClientError> {
-        // Get accounts and calculate buy amounts
-        let global_account = self.get_global_account().await?;
-        let mut bonding_curve_account: Option<accounts::BondingCurveAccount> = None;
-        let buy_amount = {
-            let bonding_curve_pda = Self::get_bonding_curve_pda(&mint)
-                .ok_or(error::ClientError::BondingCurveNotFound)?;
-            if self.rpc.get_account(&bonding_curve_pda).await.is_err() {
-                global_account.get_initial_buy_price(amount_sol)
-            } else {
-                bonding_curve_account = self.get_bonding_curve_account(&mint).await.ok();
-                bonding_curve_account
-                    .as_ref()
-                    .unwrap()
-                    .get_buy_price(amount_sol)
-                    .map_err(error::ClientError::BondingCurveError)?
-            }
-        };
-        let buy_amount_with_slippage =
-            utils::calculate_with_slippage_buy(amount_sol, slippage_basis_points.unwrap_or(500));
-
-        let mut instructions = Vec::new();
-
-        // Add buy instruction (using Token 2022)
-        instructions.push(instructions::buy_with_token_program(
-            &self.payer,
-            &mint,
-            &global_account.fee_recipient,
-            &bonding_curve_account.map_or(self.payer.pubkey(), |bc| bc.creator),
-            &constants::accounts::TOKEN_2022_PROGRAM,
-            instructions::Buy {
-                amount: buy_amount,
-                max_sol_cost: buy_amount_with_slippage,
-                track_volume,
-            },
-        ));
-
-        Ok(instructions)
+        priority_fee: Option<PriorityFee>,
+        options: &TradeOptions,
+    ) -> Result<Signature, error::ClientError> {
+        self.buy_with_mode(
+            mint,
+            TradeMode::ExactSolIn(amount_sol),
+            track_volume,
+            slippage_basis_points,
+            priority_fee,
+            options,
+        )
+        .await
     }
 
-    /// Generates instructions for selling tokens back to a bonding curve
+    /// Buys tokens from a bonding curve, holding either the SOL in or the tokens out exact
     ///
-    /// Creates a set of Solana instructions needed to sell tokens in exchange for SOL. These
-    /// instructions include the sell instruction with slippage protection and may include
-    /// closing the associated token account if all tokens are being sold and the feature
-    /// is enabled.
+    /// Behaves like [`PumpFun::buy_with_options`], but `mode` picks the side of the trade
+    /// the caller fixes. With [`TradeMode::ExactSolIn`] the given lamports go into the curve
+    /// and the token amount is quoted from it; with [`TradeMode::ExactTokensOut`] the given
+    /// tokens are bought and the SOL they cost is quoted instead. Either way, the slippage
+    /// tolerance is applied to the quoted SOL to bound the cost of the buy.
     ///
     /// # Arguments
     ///
-    /// * `mint` - Public key of the token mint to sell
-    /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells the entire balance
+    /// * `mint` - Public key of the token mint to buy
+    /// * `mode` - Exact SOL input or exact token output of the buy
+    /// * `track_volume` - Optional flag to track this buy in volume stats
     /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
-    ///   If None, defaults to 500 (5%)
+    ///   If None, defaults to 500 (5%). Ignored when `options.slippage` is set
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    /// * `options` - Per-transaction trade options
     ///
     /// # Returns
     ///
-    /// Returns a vector of Solana instructions if successful, or a ClientError if the operation fails
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
     ///
     /// # Errors
     ///
-    /// Returns an error if:
-    /// - The token account or token balance cannot be fetched
-    /// - The global account or bonding curve account cannot be fetched
-    /// - The sell price calculation fails
-    /// - Token account closing operations fail (when applicable)
+    /// In addition to the errors of [`PumpFun::buy_with_options`], returns
+    /// `ClientError::BondingCurveError` if the curve holds fewer tokens than an
+    /// [`TradeMode::ExactTokensOut`] buy asks for
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
-    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee, TradeMode, TradeOptions}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, pubkey, signature::Keypair};
     /// # use std::sync::Arc;
     /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
@@ -1200,165 +1630,3787 @@ impl PumpFun {
     /// # let commitment = CommitmentConfig::confirmed();
     /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
     /// # let client = PumpFun::new(payer, cluster);
-    /// #
-    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
-    /// let amount_tokens = Some(1000); // Sell 1000 tokens
-    /// let slippage_bps = Some(200); // 2%
-    ///
-    /// let sell_instructions = client.get_sell_instructions(mint, amount_tokens, slippage_bps).await?;
+    /// let token_mint = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
     ///
-    /// // Or to sell all tokens:
-    /// let sell_all_instructions = client.get_sell_instructions(mint, None, None).await?;
+    /// // Buy exactly 1,000 tokens (6 decimals), paying at most 3% over the quote
+    /// let mode = TradeMode::ExactTokensOut(1_000_000_000);
+    /// let signature = client
+    ///     .buy_with_mode(token_mint, mode, None, Some(300), None, &TradeOptions::default())
+    ///     .await?;
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_sell_instructions(
+    pub async fn buy_with_mode(
         &self,
         mint: Pubkey,
-        amount_token: Option<u64>,
+        mode: TradeMode,
+        track_volume: Option<bool>,
         slippage_basis_points: Option<u64>,
-    ) -> Result<Vec<Instruction>, error::ClientError> {
-        // Get ATA
-        let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), &mint);
+        priority_fee: Option<PriorityFee>,
+        options: &TradeOptions,
+    ) -> Result<Signature, error::ClientError> {
+        // Add priority fee if provided or default to the strategy or cluster priority fee
+        let priority_fee = self
+            .resolve_priority_fee(priority_fee, &Self::get_priority_fee_accounts(Some(&mint)))
+            .await?;
+        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
 
-        // Get token balance
-        let token_balance = if amount_token.is_none() || cfg!(feature = "close-ata") {
-            // We need the balance if amount_token is None OR if the close-ata feature is enabled
-            let balance = self.rpc.get_token_account_balance(&ata).await?;
-            Some(balance.amount.parse::<u64>().unwrap())
-        } else {
-            None
-        };
+        // Add buy instruction
+        let buy_ix = self
+            .get_buy_instructions_for(
+                &self.payer.pubkey(),
+                mint,
+                mode,
+                track_volume,
+                slippage_basis_points,
+                options,
+            )
+            .await?;
+        instructions.extend(buy_ix);
 
-        // Determine amount to sell
-        let amount = amount_token.unwrap_or_else(|| token_balance.unwrap());
+        // Create and sign transaction, with the caller's blockhash if given
+        let recent_blockhash = match options.blockhash {
+            Some(blockhash) => blockhash,
+            None => self
+                .rpc
+                .get_latest_blockhash()
+                .await
+                .map_err(error::ClientError::SolanaClientError)?,
+        };
+        let transaction = get_transaction_with_blockhash(
+            self.payer.clone(),
+            &instructions,
+            None,
+            recent_blockhash,
+            #[cfg(feature = "versioned-tx")]
+            None,
+        )?;
 
-        // Calculate min sol output
-        let global_account = self.get_global_account().await?;
-        let bonding_curve_account = self.get_bonding_curve_account(&mint).await?;
-        let min_sol_output = bonding_curve_account
-            .get_sell_price(amount, global_account.fee_basis_points)
-            .map_err(error::ClientError::BondingCurveError)?;
-        let min_sol_output = utils::calculate_with_slippage_sell(
-            min_sol_output,
-            slippage_basis_points.unwrap_or(500),
-        );
+        // Send and confirm transaction
+        let signature = utils::transaction::send_and_confirm_transaction(
+            &self.rpc,
+            &transaction,
+            options.send_config.or(self.send_config),
+        )
+        .await?;
 
-        let mut instructions = Vec::new();
+        Ok(signature)
+    }
 
-        // Add sell instruction
-        instructions.push(instructions::sell(
-            &self.payer,
-            &mint,
-            &global_account.fee_recipient,
-            &bonding_curve_account.creator,
-            instructions::Sell {
+    /// Sells tokens back to the bonding curve in exchange for SOL
+    ///
+    /// This method sells tokens back to the bonding curve, receiving SOL in return. The amount of SOL
+    /// received is determined by the bonding curve formula for the specific token. As more tokens
+    /// are sold, the price decreases according to the curve function.
+    ///
+    /// The method:
+    /// 1. Determines how many tokens to sell (all tokens or a specific amount)
+    /// 2. Calculates how much SOL will be received for the tokens
+    /// 3. Executes the sell transaction with slippage protection
+    ///
+    /// A portion of the SOL is taken as a fee according to the global configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells the entire balance
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The token account cannot be found
+    /// - The bonding curve account cannot be found
+    /// - The sell price calculation fails
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let token_mint = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// // Sell 1000 tokens with 2% max slippage
+    /// let amount_tokens = Some(1000);
+    /// let slippage_bps = Some(200); // 2%
+    ///
+    /// let signature = client.sell(token_mint, amount_tokens, slippage_bps, None).await?;
+    /// println!("Tokens sold! Signature: {}", signature);
+    ///
+    /// // Or sell all tokens with default slippage (5%)
+    /// let signature = client.sell(token_mint, None, None, None).await?;
+    /// println!("All tokens sold! Signature: {}", signature);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sell(
+        &self,
+        mint: Pubkey,
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        self.sell_with_options(
+            mint,
+            amount_token,
+            slippage_basis_points,
+            priority_fee,
+            &TradeOptions::default(),
+        )
+        .await
+    }
+
+    /// Sells tokens back to the bonding curve using per-transaction trade options
+    ///
+    /// Behaves like [`PumpFun::sell`], but lets the caller override values that are
+    /// otherwise derived from on-chain state, such as the fee recipient.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells the entire balance
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%). Ignored when `options.slippage` is set
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    /// * `options` - Per-transaction trade options
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    pub async fn sell_with_options(
+        &self,
+        mint: Pubkey,
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+        options: &TradeOptions,
+    ) -> Result<Signature, error::ClientError> {
+        // Add priority fee if provided or default to the strategy or cluster priority fee
+        let priority_fee = self
+            .resolve_priority_fee(priority_fee, &Self::get_priority_fee_accounts(Some(&mint)))
+            .await?;
+        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
+
+        // Add sell instruction
+        let sell_ix = self
+            .get_sell_instructions_with_options(mint, amount_token, slippage_basis_points, options)
+            .await?;
+        instructions.extend(sell_ix);
+
+        // Create and sign transaction, with the caller's blockhash if given
+        let recent_blockhash = match options.blockhash {
+            Some(blockhash) => blockhash,
+            None => self
+                .rpc
+                .get_latest_blockhash()
+                .await
+                .map_err(error::ClientError::SolanaClientError)?,
+        };
+        let transaction = get_transaction_with_blockhash(
+            self.payer.clone(),
+            &instructions,
+            None,
+            recent_blockhash,
+            #[cfg(feature = "versioned-tx")]
+            None,
+        )?;
+
+        // Send and confirm transaction
+        let signature = utils::transaction::send_and_confirm_transaction(
+            &self.rpc,
+            &transaction,
+            options.send_config.or(self.send_config),
+        )
+        .await?;
+
+        Ok(signature)
+    }
+
+    /// Buys tokens for an account whose key is held by a [`utils::signer::RemoteSigner`]
+    ///
+    /// Behaves like [`PumpFun::buy_with_options`], but the buyer and fee payer is
+    /// `signer` instead of the client's payer. The transaction is built locally and only its
+    /// message is sent to the signer, so the private key never has to be in the process.
+    ///
+    /// # Arguments
+    ///
+    /// * `signer` - Buyer and fee payer, e.g. a signing service
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_sol` - Amount of SOL to spend, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `track_volume` - Optional flag to track this buy in volume stats
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%). Ignored when `options.slippage` is set
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    /// * `options` - Per-transaction trade options
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee, TradeOptions}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// use pumpfun::utils::signer::LocalSigner;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// // Any `RemoteSigner`, e.g. a client for a KMS
+    /// let signer = LocalSigner::from(Keypair::new());
+    /// let token_mint = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// let signature = client
+    ///     .buy_with_signer(
+    ///         &signer,
+    ///         token_mint,
+    ///         sol_to_lamports(0.01f64),
+    ///         None,
+    ///         None,
+    ///         None,
+    ///         &TradeOptions::default(),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub async fn buy_with_signer(
+        &self,
+        signer: &dyn utils::signer::RemoteSigner,
+        mint: Pubkey,
+        amount_sol: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+        options: &TradeOptions,
+    ) -> Result<Signature, error::ClientError> {
+        // Add priority fee if provided or default to the strategy or cluster priority fee
+        let priority_fee = self
+            .resolve_priority_fee(priority_fee, &Self::get_priority_fee_accounts(Some(&mint)))
+            .await?;
+        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
+
+        // Add buy instruction
+        let buy_ix = self
+            .get_buy_instructions_for(
+                &signer.pubkey(),
+                mint,
+                TradeMode::ExactSolIn(amount_sol),
+                track_volume,
+                slippage_basis_points,
+                options,
+            )
+            .await?;
+        instructions.extend(buy_ix);
+
+        self.send_with_signer_and_options(signer, &instructions, options)
+            .await
+    }
+
+    /// Sells tokens for an account whose key is held by a [`utils::signer::RemoteSigner`]
+    ///
+    /// Behaves like [`PumpFun::sell_with_options`], but the seller and fee payer is
+    /// `signer` instead of the client's payer.
+    ///
+    /// # Arguments
+    ///
+    /// * `signer` - Seller and fee payer, e.g. a signing service
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells the entire balance
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%). Ignored when `options.slippage` is set
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    /// * `options` - Per-transaction trade options
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    pub async fn sell_with_signer(
+        &self,
+        signer: &dyn utils::signer::RemoteSigner,
+        mint: Pubkey,
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+        options: &TradeOptions,
+    ) -> Result<Signature, error::ClientError> {
+        // Add priority fee if provided or default to the strategy or cluster priority fee
+        let priority_fee = self
+            .resolve_priority_fee(priority_fee, &Self::get_priority_fee_accounts(Some(&mint)))
+            .await?;
+        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
+
+        // Add sell instruction
+        let sell_ix = self
+            .get_sell_instructions_for(
+                &signer.pubkey(),
+                mint,
+                amount_token,
+                slippage_basis_points,
+                options,
+            )
+            .await?;
+        instructions.extend(sell_ix);
+
+        self.send_with_signer_and_options(signer, &instructions, options)
+            .await
+    }
+
+    /// Signs instructions with a [`utils::signer::RemoteSigner`] as fee payer, then sends and confirms them
+    ///
+    /// # Arguments
+    ///
+    /// * `signer` - Fee payer, e.g. a signing service
+    /// * `instructions` - Instructions to send, e.g. from [`PumpFun::get_sell_instructions`]
+    ///   for tokens held by `signer`
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    pub async fn send_with_signer(
+        &self,
+        signer: &dyn utils::signer::RemoteSigner,
+        instructions: &[Instruction],
+    ) -> Result<Signature, error::ClientError> {
+        self.send_with_signer_and_options(signer, instructions, &TradeOptions::default())
+            .await
+    }
+
+    /// Signs and sends instructions like [`PumpFun::send_with_signer`], honoring the
+    /// blockhash and send options of `options`
+    async fn send_with_signer_and_options(
+        &self,
+        signer: &dyn utils::signer::RemoteSigner,
+        instructions: &[Instruction],
+        options: &TradeOptions,
+    ) -> Result<Signature, error::ClientError> {
+        // Create and sign transaction, with the caller's blockhash if given
+        let recent_blockhash = match options.blockhash {
+            Some(blockhash) => blockhash,
+            None => self
+                .rpc
+                .get_latest_blockhash()
+                .await
+                .map_err(error::ClientError::SolanaClientError)?,
+        };
+        let transaction = utils::transaction::get_transaction_with_remote_signer_and_blockhash(
+            signer,
+            instructions,
+            None,
+            recent_blockhash,
+            #[cfg(feature = "versioned-tx")]
+            None,
+        )
+        .await?;
+
+        utils::transaction::send_and_confirm_transaction(
+            &self.rpc,
+            &transaction,
+            options.send_config.or(self.send_config),
+        )
+        .await
+    }
+
+    /// Broadcasts a signed transaction to several RPC endpoints at once
+    ///
+    /// Sends `transaction` to every endpoint concurrently and returns as soon as one of them
+    /// accepts it. The remaining requests keep running in the background, so that every
+    /// endpoint still broadcasts the transaction. Endpoints reporting the transaction as
+    /// already processed are not counted as failures, since another endpoint landed it
+    /// first. The transaction is not confirmed, poll `rpc.confirm_transaction` for that.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction` - Fully signed transaction to send
+    /// * `endpoints` - HTTP URLs of the RPC endpoints to send to
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if at least one endpoint accepted the transaction or
+    /// reported it as already processed
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first failed endpoint if every endpoint failed, or
+    /// `ClientError::OtherError` if `endpoints` is empty
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, transaction::VersionedTransaction};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example(transaction: VersionedTransaction) -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let endpoints = vec![
+    ///     "https://api.mainnet-beta.solana.com".to_string(),
+    ///     "https://my-rpc-provider.example.com".to_string(),
+    /// ];
+    /// let signature = client.send_to_many(&transaction, &endpoints).await?;
+    /// println!("Accepted: {}", signature);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send_to_many(
+        &self,
+        transaction: &solana_sdk::transaction::VersionedTransaction,
+        endpoints: &[String],
+    ) -> Result<Signature, error::ClientError> {
+        let signature = *transaction.signatures.first().ok_or_else(|| {
+            error::ClientError::OtherError("Transaction is not signed".to_string())
+        })?;
+
+        let transaction = Arc::new(transaction.clone());
+        let mut requests = tokio::task::JoinSet::new();
+        for endpoint in endpoints {
+            let rpc = RpcClient::new_with_commitment(endpoint.clone(), self.cluster.commitment);
+            let transaction = transaction.clone();
+            requests.spawn(async move { rpc.send_transaction(&*transaction).await });
+        }
+
+        let mut processed = false;
+        let mut first_error = None;
+        while let Some(result) = requests.join_next().await {
+            match result {
+                Ok(Ok(_)) => {
+                    // Dropping the set would abort the requests still in flight
+                    requests.detach_all();
+                    return Ok(signature);
+                }
+                Ok(Err(err))
+                    if err.get_transaction_error()
+                        == Some(solana_sdk::transaction::TransactionError::AlreadyProcessed) =>
+                {
+                    processed = true;
+                }
+                Ok(Err(err)) => {
+                    first_error.get_or_insert(error::ClientError::SolanaClientError(err));
+                }
+                Err(err) => {
+                    first_error.get_or_insert(error::ClientError::OtherError(format!(
+                        "Send task failed: {}",
+                        err
+                    )));
+                }
+            }
+        }
+
+        if processed {
+            return Ok(signature);
+        }
+        Err(first_error.unwrap_or_else(|| {
+            error::ClientError::OtherError("No RPC endpoints to send to".to_string())
+        }))
+    }
+
+    /// Builds an unsigned transaction for a wallet to sign, e.g. in a web frontend
+    ///
+    /// Fetches a recent blockhash and serializes `instructions` into a base64-encoded v0
+    /// transaction with [`utils::transaction::get_unsigned_transaction_base64`]. The
+    /// client's payer is not involved: `payer` signs and pays for the transaction.
+    ///
+    /// # Arguments
+    ///
+    /// * `instructions` - Instructions to include, e.g. from [`PumpFun::get_buy_instructions`]
+    ///   built for the wallet
+    /// * `payer` - Wallet that will sign and pay for the transaction
+    ///
+    /// # Returns
+    ///
+    /// Returns the encoded transaction with the blockhash it uses and the block height at
+    /// which it expires, or a ClientError if the blockhash cannot be fetched or the
+    /// transaction cannot be serialized
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// # let instructions = Vec::new();
+    /// let wallet = pubkey!("Wa11etPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// let unsigned = client.build_unsigned_base64(&instructions, &wallet).await?;
+    /// println!(
+    ///     "Sign {} before block {}",
+    ///     unsigned.transaction, unsigned.last_valid_block_height
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn build_unsigned_base64(
+        &self,
+        instructions: &[Instruction],
+        payer: &Pubkey,
+    ) -> Result<common::types::UnsignedTransaction, error::ClientError> {
+        let (blockhash, last_valid_block_height) = self
+            .rpc
+            .get_latest_blockhash_with_commitment(self.rpc.commitment())
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        Ok(common::types::UnsignedTransaction {
+            transaction: utils::transaction::get_unsigned_transaction_base64(
+                payer,
+                instructions,
+                blockhash,
+            )?,
+            blockhash,
+            last_valid_block_height,
+        })
+    }
+
+    /// Buys tokens using a human-readable SOL amount
+    ///
+    /// Convenience wrapper around [`PumpFun::buy`] that converts `amount_sol` from SOL
+    /// into lamports using [`constants::decimals::SOL_DECIMALS`].
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_sol` - Amount of SOL to spend (e.g. `0.5` for half a SOL)
+    /// * `track_volume` - Optional flag to track this buy in volume stats
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let token_mint = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// // Buy 0.01 SOL worth of tokens
+    /// let signature = client.buy_ui_amount(token_mint, 0.01, None, None, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn buy_ui_amount(
+        &self,
+        mint: Pubkey,
+        amount_sol: f64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        let amount_sol = utils::ui_amount_to_base(amount_sol, constants::decimals::SOL_DECIMALS);
+        self.buy(
+            mint,
+            amount_sol,
+            track_volume,
+            slippage_basis_points,
+            priority_fee,
+        )
+        .await
+    }
+
+    /// Buys tokens with a SOL budget that includes the trading fees
+    ///
+    /// Unlike [`PumpFun::buy`], which prices `amount_sol` as if all of it reached the curve,
+    /// the token amount is quoted with [`accounts::BondingCurveAccount::get_buy_price_with_fees`],
+    /// so the expected total spend, protocol and creator fees included, is `sol_amount`.
+    /// The maximum SOL cost of the instruction adds the slippage tolerance on top, computed
+    /// with [`utils::calculate_with_slippage_buy`], unless `options.slippage` is set.
+    ///
+    /// The quoted tokens are then bought with [`PumpFun::buy_with_mode`] and
+    /// [`TradeMode::ExactTokensOut`], so the other trade options apply as for any buy.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `sol_amount` - Total amount of SOL to spend including fees, in lamports
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%). Ignored when `options.slippage` is set
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    /// * `options` - Per-transaction trade options
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The global account or bonding curve account cannot be fetched, e.g. because the
+    ///   token has not been created yet
+    /// - The bonding curve is complete (`ClientError::CurveComplete`)
+    /// - Transaction creation or execution fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee, TradeOptions}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let token_mint = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// // Spend 1 SOL including fees, with up to 1% slippage
+    /// let options = TradeOptions::default();
+    /// let signature = client
+    ///     .buy_with_sol(token_mint, sol_to_lamports(1.0), Some(100), None, &options)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn buy_with_sol(
+        &self,
+        mint: Pubkey,
+        sol_amount: u64,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+        options: &TradeOptions,
+    ) -> Result<Signature, error::ClientError> {
+        let global_account = self.get_trade_global_account(options).await?;
+        let bonding_curve_account = match &options.bonding_curve {
+            Some(bonding_curve) => bonding_curve.clone(),
+            None => self.get_bonding_curve_account(&mint).await?,
+        };
+        if !options.skip_complete_check {
+            Self::check_curve_not_complete(&mint, Some(&bonding_curve_account))?;
+        }
+
+        // Creator fees are only charged on curves with a creator
+        let mut fee_basis_points = global_account.fee_basis_points;
+        if bonding_curve_account.creator != Pubkey::default() {
+            fee_basis_points += global_account.creator_fee_basis_points;
+        }
+        let amount = bonding_curve_account
+            .get_buy_price_with_fees(sol_amount, fee_basis_points)
+            .map_err(error::ClientError::BondingCurveError)?;
+
+        // Bound the cost by the budget, fees included, rather than by the fee-less quote
+        let slippage = options.slippage.unwrap_or(SlippageMode::AbsoluteLimit(
+            utils::calculate_with_slippage_buy(sol_amount, slippage_basis_points.unwrap_or(500)),
+        ));
+        let options = options
+            .clone()
+            .with_slippage(slippage)
+            .with_global_account(global_account)
+            .with_bonding_curve(bonding_curve_account);
+
+        self.buy_with_mode(
+            mint,
+            TradeMode::ExactTokensOut(amount),
+            None,
+            slippage_basis_points,
+            priority_fee,
+            &options,
+        )
+        .await
+    }
+
+    /// Buys tokens in tranches, each only if the curve price is below its ceiling
+    ///
+    /// Tranches are executed in order. Before each one the bonding curve is fetched again
+    /// and its marginal price, in lamports per whole token of the mint, is compared with the
+    /// tranche's ceiling: the tranche is bought with [`PumpFun::buy_with_options`], quoted
+    /// against the curve just fetched, if the price does not exceed it and skipped
+    /// otherwise. Tranches are also skipped once the curve is complete. Skipped and failed
+    /// tranches do not stop the ladder, so a later tranche with a higher ceiling may still
+    /// execute.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `tranches` - Tranches as `(amount_sol, max_price)`, with the amount of SOL to spend
+    ///   in lamports and the highest acceptable price in lamports per whole token
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage of each buy in basis
+    ///   points (1 bp = 0.01%). If None, defaults to 500 (5%)
+    ///
+    /// # Returns
+    ///
+    /// Returns one result per tranche, in order: the signature of the buy, `None` if the
+    /// tranche was skipped, or the ClientError of the tranche if fetching the curve or the
+    /// buy failed. The signatures of the tranches that executed are kept alongside the
+    /// failures. Returns a ClientError, before executing any tranche, if the mint decimals
+    /// or the global account cannot be fetched
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let token_mint = pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// // Spend up to 0.3 SOL, buying less the higher the price is
+    /// let tranches = vec![
+    ///     (sol_to_lamports(0.1), 40),
+    ///     (sol_to_lamports(0.1), 35),
+    ///     (sol_to_lamports(0.1), 30),
+    /// ];
+    /// let results = client.laddered_buy(token_mint, tranches, Some(300)).await?;
+    /// for (index, result) in results.iter().enumerate() {
+    ///     match result {
+    ///         Ok(Some(signature)) => println!("Tranche {} filled: {}", index, signature),
+    ///         Ok(None) => println!("Tranche {} skipped", index),
+    ///         Err(err) => eprintln!("Tranche {} failed: {}", index, err),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn laddered_buy(
+        &self,
+        mint: Pubkey,
+        tranches: Vec<(u64, u64)>,
+        slippage_basis_points: Option<u64>,
+    ) -> Result<Vec<Result<Option<Signature>, error::ClientError>>, error::ClientError> {
+        let decimals = self.get_mint_decimals(&mint).await?;
+        let global_account = self.get_global_account().await?;
+        let mut results = Vec::with_capacity(tranches.len());
+
+        for (amount_sol, max_price) in tranches {
+            let bonding_curve_account = match self.get_bonding_curve_account(&mint).await {
+                Ok(bonding_curve_account) => bonding_curve_account,
+                Err(err) => {
+                    results.push(Err(err));
+                    continue;
+                }
+            };
+            let price = bonding_curve_account.price_per_token_with_decimals(decimals)
+                * 10f64.powi(constants::decimals::SOL_DECIMALS as i32);
+
+            if bonding_curve_account.complete || price > max_price as f64 {
+                results.push(Ok(None));
+                continue;
+            }
+
+            // Quote the buy with the accounts already fetched
+            let options = TradeOptions::default()
+                .with_global_account(global_account.clone())
+                .with_bonding_curve(bonding_curve_account);
+            let result = self
+                .buy_with_options(
+                    mint,
+                    amount_sol,
+                    None,
+                    slippage_basis_points,
+                    None,
+                    &options,
+                )
+                .await;
+            results.push(result.map(Some));
+        }
+
+        Ok(results)
+    }
+
+    /// Sells tokens using a human-readable token amount
+    ///
+    /// Convenience wrapper around [`PumpFun::sell`] that converts `amount_token` into base
+    /// units using the decimals of the mint, fetched with [`PumpFun::get_mint_decimals`].
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_token` - Optional amount of tokens to sell (e.g. `1000.0`). If None, sells the entire balance
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    pub async fn sell_ui_amount(
+        &self,
+        mint: Pubkey,
+        amount_token: Option<f64>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        let amount_token = match amount_token {
+            Some(amount) => {
+                let decimals = self.get_mint_decimals(&mint).await?;
+                Some(utils::ui_amount_to_base(amount, decimals))
+            }
+            None => None,
+        };
+        self.sell(mint, amount_token, slippage_basis_points, priority_fee)
+            .await
+    }
+
+    /// Quotes the SOL received for selling an owner's entire balance of a token
+    ///
+    /// Read-only counterpart of [`PumpFun::sell`] with no amount: fetches the balance of the
+    /// owner's associated token account and prices it against the current bonding curve,
+    /// after the protocol and creator fees. No slippage is applied.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    /// * `owner` - Public key of the token holder
+    ///
+    /// # Returns
+    ///
+    /// Returns the SOL received in lamports, `0` if the owner holds no tokens
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The token account, the bonding curve or the global account cannot be fetched
+    /// - The bonding curve is complete (`ClientError::CurveComplete`)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::lamports_to_sol, pubkey, signature::{Keypair, Signer}};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer.clone(), cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// let quote = client.quote_full_sell(&mint, &payer.pubkey()).await?;
+    /// println!("Selling everything now yields {} SOL", lamports_to_sol(quote));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn quote_full_sell(
+        &self,
+        mint: &Pubkey,
+        owner: &Pubkey,
+    ) -> Result<u64, error::ClientError> {
+        let ata: Pubkey = get_associated_token_address(owner, mint);
+        let balance = self
+            .rpc
+            .get_token_account_balance(&ata)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+        let amount: u64 = balance.amount.parse().map_err(|err| {
+            error::ClientError::OtherError(format!("Invalid token balance: {}", err))
+        })?;
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        let global_account = self.get_global_account().await?;
+        let bonding_curve_account = self.get_bonding_curve_account(mint).await?;
+        Self::check_curve_not_complete(mint, Some(&bonding_curve_account))?;
+
+        bonding_curve_account
+            .get_sell_price(amount, global_account.fee_basis_points)
+            .map_err(error::ClientError::BondingCurveError)
+    }
+
+    /// Calculates the lamports an account needs to stay rent-exempt after growing
+    ///
+    /// Compares the account's current balance with the minimum balance for rent exemption
+    /// (`getMinimumBalanceForRentExemption`) at its current size plus `additional_bytes`.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - Public key of the account to extend
+    /// * `additional_bytes` - Number of bytes the account grows by
+    ///
+    /// # Returns
+    ///
+    /// Returns the missing lamports, `0` if the account is already funded enough, or a
+    /// ClientError if the account or the rent cannot be fetched
+    pub async fn get_extend_account_rent(
+        &self,
+        account: &Pubkey,
+        additional_bytes: usize,
+    ) -> Result<u64, error::ClientError> {
+        let current = self
+            .rpc
+            .get_account(account)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        let required = self
+            .rpc
+            .get_minimum_balance_for_rent_exemption(current.data.len() + additional_bytes)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        Ok(required.saturating_sub(current.lamports))
+    }
+
+    /// Calculates the rent paid by the creator of a new token
+    ///
+    /// Sums the minimum balances for rent exemption of the accounts allocated by a create:
+    /// the mint, the bonding curve, the associated bonding curve, the metadata account and
+    /// the creator's associated token account. The sizes are taken from
+    /// [`constants::sizes`]. The rent parameters are read once from the rent sysvar and
+    /// applied locally, rather than requested for every account.
+    ///
+    /// # Returns
+    ///
+    /// Returns the rent in lamports, or a ClientError if the rent cannot be fetched
+    pub async fn get_create_rent(&self) -> Result<u64, error::ClientError> {
+        let sizes = [
+            constants::sizes::MINT_SIZE,
+            constants::sizes::BONDING_CURVE_SIZE,
+            constants::sizes::TOKEN_ACCOUNT_SIZE,
+            constants::sizes::METADATA_SIZE,
+            constants::sizes::TOKEN_ACCOUNT_SIZE,
+        ];
+
+        let rent_sysvar = self
+            .rpc
+            .get_account(&solana_sdk::sysvar::rent::id())
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+        let rent: solana_sdk::rent::Rent =
+            bincode::deserialize(&rent_sysvar.data).map_err(|err| {
+                error::ClientError::OtherError(format!("Failed to decode rent sysvar: {}", err))
+            })?;
+
+        Ok(sizes
+            .into_iter()
+            .map(|size| rent.minimum_balance(size))
+            .fold(0, u64::saturating_add))
+    }
+
+    /// Checks that the payer can cover a transaction before it is sent
+    ///
+    /// # Arguments
+    ///
+    /// * `required_lamports` - Lamports the transaction needs, including rent and fees
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::InsufficientFunds` with the payer's balance if it is lower than
+    /// `required_lamports`, or a ClientError if the balance cannot be fetched
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, error::ClientError};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let rent = client.get_create_rent().await?;
+    /// match client.check_payer_balance(rent).await {
+    ///     Err(ClientError::InsufficientFunds { have, need }) => {
+    ///         println!("Top up {} lamports before creating a token", need - have)
+    ///     }
+    ///     result => result?,
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn check_payer_balance(
+        &self,
+        required_lamports: u64,
+    ) -> Result<(), error::ClientError> {
+        let balance = self
+            .rpc
+            .get_balance(&self.payer.pubkey())
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        if balance < required_lamports {
+            return Err(error::ClientError::InsufficientFunds {
+                have: balance,
+                need: required_lamports,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Predicts the payer's SOL balance after launching a token
+    ///
+    /// Subtracts the estimated cost of [`PumpFun::create_and_buy`] from the payer's current
+    /// balance: the rent of the new accounts ([`PumpFun::get_create_rent`]), the initial
+    /// buy with its protocol and creator fees, and the network fee of the transaction with
+    /// the priority fee the client would use. Slippage is not counted, so the buy may cost
+    /// up to the slippage tolerance more.
+    ///
+    /// The prediction flags a balance below the minimum keeping the payer's own account
+    /// rent-exempt, e.g. for a UI to warn before launching; the launch would then fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `buy_amount_sol` - Amount of SOL to spend on the initial buy, in lamports, or `0`
+    ///   for a create without buy
+    ///
+    /// # Returns
+    ///
+    /// Returns the predicted balance and whether it is below the rent-exempt minimum, or a
+    /// ClientError if the balance, the rent or the global account cannot be fetched
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let prediction = client.predict_balance_after(sol_to_lamports(0.5)).await?;
+    /// if prediction.below_rent_exempt {
+    ///     println!("Top up the payer before launching");
+    /// } else {
+    ///     let left = utils::format_sol(prediction.balance_after);
+    ///     println!("You will have ~{} left after this launch", left);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn predict_balance_after(
+        &self,
+        buy_amount_sol: u64,
+    ) -> Result<BalancePrediction, error::ClientError> {
+        let balance = self
+            .rpc
+            .get_balance(&self.payer.pubkey())
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+        let rent = self.get_create_rent().await?;
+
+        // The payer is the creator of the new curve, so creator fees apply to the buy
+        let trade_fee = if buy_amount_sol == 0 {
+            0
+        } else {
+            let global_account = self.get_global_account().await?;
+            let fee_basis_points = global_account
+                .fee_basis_points
+                .saturating_add(global_account.creator_fee_basis_points);
+            (buy_amount_sol as u128 * fee_basis_points as u128).div_ceil(10_000) as u64
+        };
+
+        let priority_fee = self
+            .resolve_priority_fee(None, &Self::get_priority_fee_accounts(None))
+            .await?;
+        let network_fee = Self::estimate_transaction_fee(&priority_fee, 2);
+
+        let predicted = balance
+            .saturating_sub(rent)
+            .saturating_sub(buy_amount_sol)
+            .saturating_sub(trade_fee)
+            .saturating_sub(network_fee);
+
+        let rent_exempt_minimum = self
+            .rpc
+            .get_minimum_balance_for_rent_exemption(0)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        Ok(BalancePrediction {
+            balance_after: predicted,
+            rent_exempt_minimum,
+            below_rent_exempt: predicted < rent_exempt_minimum,
+        })
+    }
+
+    /// Extends a program-owned account, funding the rent for the extra bytes
+    ///
+    /// Computes the missing rent with [`PumpFun::get_extend_account_rent`], transfers it from
+    /// the payer to the account and extends the account in the same transaction, so the
+    /// account cannot end up underfunded.
+    ///
+    /// # Arguments
+    ///
+    /// * `account` - Public key of the account to extend, e.g. a bonding curve
+    /// * `additional_bytes` - Number of bytes the account grows by
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature and the lamports transferred for rent, or a
+    /// ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The account or the rent cannot be fetched
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let bonding_curve = PumpFun::get_bonding_curve_pda(&mint).unwrap();
+    ///
+    /// let (signature, rent) = client.extend_account_funded(&bonding_curve, 8, None).await?;
+    /// println!("Extended in {} with {} lamports of rent", signature, rent);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn extend_account_funded(
+        &self,
+        account: &Pubkey,
+        additional_bytes: usize,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<(Signature, u64), error::ClientError> {
+        let rent = self.get_extend_account_rent(account, additional_bytes).await?;
+
+        // Add priority fee if provided or default to the strategy or cluster priority fee
+        let priority_fee = self
+            .resolve_priority_fee(priority_fee, &[*account])
+            .await?;
+        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
+
+        // Fund the extra bytes before the account is extended
+        if rent > 0 {
+            instructions.push(solana_system_interface::instruction::transfer(
+                &self.payer.pubkey(),
+                account,
+                rent,
+            ));
+        }
+        instructions.push(instructions::extend_account(&self.payer, account));
+
+        // Create and sign transaction
+        let transaction = get_transaction(
+            self.rpc.clone(),
+            self.payer.clone(),
+            &instructions,
+            None,
+            #[cfg(feature = "versioned-tx")]
+            None,
+        )
+        .await?;
+
+        // Send and confirm transaction
+        let signature = utils::transaction::send_and_confirm_transaction(
+            &self.rpc,
+            &transaction,
+            self.send_config,
+        )
+        .await?;
+
+        Ok((signature, rent))
+    }
+
+    /// Subscribes to real-time events from the Pump.fun program
+    ///
+    /// This method establishes a WebSocket connection to the Solana cluster and subscribes
+    /// to program log events from the Pump.fun program. It parses the emitted events into
+    /// structured data types and delivers them through the provided callback function.
+    ///
+    /// Event types include:
+    /// - `CreateEvent`: Emitted when a new token is created
+    /// - `TradeEvent`: Emitted when tokens are bought or sold
+    /// - `CompleteEvent`: Emitted when a bonding curve operation completes
+    /// - `SetParamsEvent`: Emitted when global parameters are updated
+    ///
+    /// # Arguments
+    ///
+    /// * `mentioned` - Optional public key to filter events by mentions. If None, subscribes to all Pump.fun events
+    /// * `commitment` - Optional commitment level for the subscription. If None, uses the
+    ///   default from the cluster configuration
+    /// * `callback` - A function that will be called for each event with the following parameters:
+    ///   * `signature`: The transaction signature as a String
+    ///   * `event`: The parsed PumpFunEvent if successful, or None if parsing failed
+    ///   * `error`: Any error that occurred during parsing, or None if successful
+    ///   * `response`: The complete RPC logs response for additional context
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Subscription` object that manages the lifecycle of the subscription.
+    /// When this object is dropped, the subscription is automatically terminated. If
+    /// the subscription cannot be established, returns a ClientError.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The WebSocket connection cannot be established
+    /// - The subscription request fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// # use std::{sync::Arc, error::Error};
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// #
+    /// // Subscribe to token events
+    /// let subscription = client.subscribe(None, None, |signature, event, error, _| {
+    ///     match event {
+    ///         Some(pumpfun::common::stream::PumpFunEvent::Create(create_event)) => {
+    ///             println!("New token created: {} ({})", create_event.name, create_event.symbol);
+    ///             println!("Mint address: {}", create_event.mint);
+    ///         },
+    ///         Some(pumpfun::common::stream::PumpFunEvent::Trade(trade_event)) => {
+    ///             let action = if trade_event.is_buy { "bought" } else { "sold" };
+    ///             println!(
+    ///                 "User {} {} {} tokens for {} SOL",
+    ///                 trade_event.user,
+    ///                 action,
+    ///                 trade_event.token_amount,
+    ///                 trade_event.sol_amount as f64 / 1_000_000_000.0
+    ///             );
+    ///         },
+    ///         Some(event) => println!("Other event received: {:#?}", event),
+    ///         None => {
+    ///             if let Some(err) = error {
+    ///                 eprintln!("Error parsing event in tx {}: {}", signature, err);
+    ///             }
+    ///         }
+    ///     }
+    /// }).await?;
+    ///
+    /// // Keep the subscription active
+    /// // When no longer needed, drop the subscription to unsubscribe
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub async fn subscribe<F>(
+        &self,
+        mentioned: Option<String>,
+        commitment: Option<solana_sdk::commitment_config::CommitmentConfig>,
+        callback: F,
+    ) -> Result<common::stream::Subscription, error::ClientError>
+    where
+        F: Fn(
+                String,
+                Option<common::stream::PumpFunEvent>,
+                Option<Box<dyn std::error::Error + Send + Sync>>,
+                solana_client::rpc_response::Response<solana_client::rpc_response::RpcLogsResponse>,
+            ) + Send
+            + Sync
+            + 'static,
+    {
+        common::stream::subscribe(self.cluster.clone(), mentioned, commitment, callback).await
+    }
+
+    /// Subscribes to every token launched on Pump.fun
+    ///
+    /// Yields the `CreateEvent` of each new token program-wide, reconnecting whenever the
+    /// WebSocket connection drops. See [`common::stream::subscribe_creates`] for how
+    /// backpressure and reconnections are handled. Must be called from within a Tokio runtime.
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream of create events, at the commitment level of the cluster
+    /// configuration, that never ends on its own. Dropping it closes the subscription.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// use futures::StreamExt;
+    /// #
+    /// # async fn example() {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mut launches = client.subscribe_creates();
+    /// while let Some(event) = launches.next().await {
+    ///     println!("{} ({}) launched at {}", event.name, event.symbol, event.mint);
+    /// }
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub fn subscribe_creates(
+        &self,
+    ) -> impl futures::Stream<Item = common::stream::CreateEvent> + Send + Unpin {
+        common::stream::subscribe_creates(self.cluster.clone(), None)
+    }
+
+    /// Creates compute budget instructions for priority fees
+    ///
+    /// Generates Solana compute budget instructions based on the provided priority fee
+    /// configuration. These instructions are used to set the maximum compute units a
+    /// transaction can consume and the price per compute unit, which helps prioritize
+    /// transaction processing during network congestion.
+    ///
+    /// # Arguments
+    ///
+    /// * `priority_fee` - Priority fee configuration containing optional unit limit and unit price
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of instructions to set compute budget parameters, which can be
+    /// empty if no priority fee parameters are provided
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::PriorityFee};
+    /// # use solana_sdk::instruction::Instruction;
+    /// #
+    /// // Set both compute unit limit and price
+    /// let priority_fee = PriorityFee {
+    ///     unit_limit: Some(200_000),
+    ///     unit_price: Some(1_000), // 1000 micro-lamports per compute unit
+    /// };
+    ///
+    /// let compute_instructions: Vec<Instruction> = PumpFun::get_priority_fee_instructions(&priority_fee);
+    /// ```
+    pub fn get_priority_fee_instructions(priority_fee: &PriorityFee) -> Vec<Instruction> {
+        let mut instructions = Vec::new();
+
+        if let Some(limit) = priority_fee.unit_limit {
+            let limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(limit);
+            instructions.push(limit_ix);
+        }
+
+        if let Some(price) = priority_fee.unit_price {
+            let price_ix = ComputeBudgetInstruction::set_compute_unit_price(price);
+            instructions.push(price_ix);
+        }
+
+        instructions
+    }
+
+    /// Estimates the fee of a transaction, including its priority fee
+    ///
+    /// Charges the base fee of 5000 lamports per signature, plus the compute unit limit
+    /// times the compute unit price. Without a limit, no priority fee is counted.
+    fn estimate_transaction_fee(priority_fee: &PriorityFee, signatures: u64) -> u64 {
+        let base_fee = signatures.saturating_mul(5_000);
+        let priority = match (priority_fee.unit_limit, priority_fee.unit_price) {
+            (Some(limit), Some(price)) => {
+                (limit as u128 * price as u128).div_ceil(1_000_000) as u64
+            }
+            _ => 0,
+        };
+
+        base_fee.saturating_add(priority)
+    }
+
+    /// Uploads the metadata of a new token with the client's uploader
+    ///
+    /// Uses the configured [`utils::uploader::MetadataUploader`], or the Pump.fun IPFS API
+    /// if there is none. The symbol is normalized first, so the uploaded and returned
+    /// metadata agree. Uploaders only return the metadata URI, so the image of the
+    /// returned metadata is left empty; the create instructions only need the name, symbol
+    /// and URI.
+    async fn upload_metadata(
+        &self,
+        metadata: &utils::CreateTokenMetadata,
+    ) -> Result<utils::TokenMetadataResponse, error::ClientError> {
+        let metadata = &utils::CreateTokenMetadata {
+            symbol: self.symbol_normalization.apply(&metadata.symbol),
+            ..metadata.clone()
+        };
+
+        let metadata_uri = match &self.metadata_uploader {
+            Some(uploader) => uploader.upload(metadata).await?,
+            None => {
+                let uploader = utils::uploader::PumpFunUploader::default();
+                utils::uploader::MetadataUploader::upload(&uploader, metadata).await?
+            }
+        };
+
+        Ok(utils::TokenMetadataResponse {
+            metadata: utils::TokenMetadata {
+                name: metadata.name.clone(),
+                symbol: metadata.symbol.clone(),
+                description: metadata.description.clone(),
+                image: String::new(),
+                show_name: true,
+                created_on: metadata
+                    .created_on
+                    .clone()
+                    .unwrap_or_else(|| utils::DEFAULT_CREATED_ON.to_string()),
+                twitter: metadata.twitter.clone(),
+                telegram: metadata.telegram.clone(),
+                website: metadata.website.clone(),
+            },
+            metadata_cid: utils::pinning::cid_from_uri(&metadata_uri).map(str::to_string),
+            image_cid: None,
+            metadata_uri,
+        })
+    }
+
+    /// Resolves the priority fee of a transaction sent by the client
+    ///
+    /// An explicit `priority_fee` is used as is. Otherwise the cluster's priority fee is
+    /// used, with its compute unit price replaced by the one chosen by the client's
+    /// [`common::priority_fee::PriorityFeeStrategy`], if any.
+    async fn resolve_priority_fee(
+        &self,
+        priority_fee: Option<PriorityFee>,
+        accounts: &[Pubkey],
+    ) -> Result<PriorityFee, error::ClientError> {
+        if let Some(priority_fee) = priority_fee {
+            return Ok(priority_fee);
+        }
+
+        let mut priority_fee = self.cluster.priority_fee;
+        if let Some(strategy) = &self.priority_fee_strategy {
+            if let Some(unit_price) = strategy.unit_price(&self.rpc, accounts).await? {
+                priority_fee.unit_price = Some(unit_price);
+            }
+        }
+
+        Ok(priority_fee)
+    }
+
+    /// Gets the Pump.fun accounts whose recent fees price a transaction
+    ///
+    /// The global volume accumulator is written by every buy, which makes its fees a proxy
+    /// for Pump.fun activity. Trades of an existing token also include its bonding curve.
+    fn get_priority_fee_accounts(mint: Option<&Pubkey>) -> Vec<Pubkey> {
+        let mut accounts = vec![constants::accounts::GLOBAL_VOLUME_ACCUMULATOR];
+        if let Some(bonding_curve) = mint.and_then(Self::get_bonding_curve_pda) {
+            accounts.push(bonding_curve);
+        }
+        accounts
+    }
+
+    /// Creates an instruction for initializing a new token
+    ///
+    /// Generates a Solana instruction to create a new token with a bonding curve on Pump.fun.
+    /// This instruction will initialize the token mint, metadata, and bonding curve accounts.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Keypair for the new token mint account that will be created
+    /// * `ipfs` - Token metadata response from IPFS upload containing name, symbol, and URI
+    ///
+    /// # Returns
+    ///
+    /// Returns a Solana instruction for creating a new token
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// #
+    /// let mint = Keypair::new();
+    /// let metadata_response = utils::create_token_metadata(
+    ///     utils::CreateTokenMetadata {
+    ///         name: "Example Token".to_string(),
+    ///         symbol: "EXTKN".to_string(),
+    ///         description: "An example token".to_string(),
+    ///         file: "path/to/image.png".to_string(),
+    ///         twitter: None,
+    ///         telegram: None,
+    ///         website: None,
+    ///         created_on: None,
+    ///         extra: Default::default(),
+    ///     }
+    /// ).await?;
+    ///
+    /// let create_instruction = client.get_create_instruction(&mint, metadata_response);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_create_instruction(
+        &self,
+        mint: &Keypair,
+        ipfs: utils::TokenMetadataResponse,
+    ) -> Instruction {
+        self.get_create_instruction_for(mint, ipfs, self.payer.pubkey())
+    }
+
+    /// Creates an instruction for initializing a new token with a separate creator
+    ///
+    /// Same as [`PumpFun::get_create_instruction`], but records `creator` instead of the
+    /// payer as the token's creator. The payer and the mint still sign; the creator does not.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Keypair for the new token mint account that will be created
+    /// * `ipfs` - Token metadata response from IPFS upload containing name, symbol, and URI
+    /// * `creator` - Public key recorded as the token's creator, earning the creator fees
+    ///
+    /// # Returns
+    ///
+    /// Returns a Solana instruction for creating a new token
+    pub fn get_create_instruction_for(
+        &self,
+        mint: &Keypair,
+        ipfs: utils::TokenMetadataResponse,
+        creator: Pubkey,
+    ) -> Instruction {
+        instructions::create(
+            &self.payer,
+            mint,
+            instructions::Create {
+                name: ipfs.metadata.name,
+                symbol: ipfs.metadata.symbol,
+                uri: ipfs.metadata_uri,
+                creator,
+            },
+        )
+    }
+
+    /// Creates the instructions for launching a token without an initial buy
+    ///
+    /// Returns the create instruction followed by an idempotent instruction creating the
+    /// payer's associated token account for the new mint, so that the first buy does not
+    /// have to create it.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Keypair for the new token mint account that will be created
+    /// * `ipfs` - Token metadata response from IPFS upload containing name, symbol, and URI
+    ///
+    /// # Returns
+    ///
+    /// Returns the create and associated token account instructions, in that order
+    pub fn get_create_without_buy_instructions(
+        &self,
+        mint: &Keypair,
+        ipfs: utils::TokenMetadataResponse,
+    ) -> Vec<Instruction> {
+        vec![
+            self.get_create_instruction(mint, ipfs),
+            spl_associated_token_account::instruction::create_associated_token_account_idempotent(
+                &self.payer.pubkey(),
+                &self.payer.pubkey(),
+                &mint.pubkey(),
+                &constants::accounts::TOKEN_PROGRAM,
+            ),
+        ]
+    }
+
+    /// Checks that `creator` can receive the creator fees of a new token
+    ///
+    /// Rejects the default (all-zero) address, the mint itself, and the program and sysvar
+    /// addresses the crate knows about, all of which would strand the fees.
+    #[allow(clippy::result_large_err)]
+    fn check_creator(creator: &Pubkey, mint: &Pubkey) -> Result<(), error::ClientError> {
+        let invalid = [
+            Pubkey::default(),
+            *mint,
+            constants::accounts::PUMPFUN,
+            constants::accounts::TOKEN_PROGRAM,
+            constants::accounts::TOKEN_2022_PROGRAM,
+            constants::accounts::ASSOCIATED_TOKEN_PROGRAM,
+            constants::accounts::MPL_TOKEN_METADATA,
+            constants::accounts::RENT,
+        ];
+        if invalid.contains(creator) {
+            return Err(error::ClientError::InvalidCreator(*creator));
+        }
+
+        Ok(())
+    }
+
+    /// Creates a new Token 2022 token with metadata by uploading metadata to IPFS and initializing on-chain accounts
+    ///
+    /// This method handles the complete process of creating a new Token 2022 token on Pump.fun:
+    /// 1. Uploads token metadata and image to IPFS
+    /// 2. Creates a new SPL Token 2022 token with the provided mint keypair
+    /// 3. Initializes the bonding curve that determines token pricing
+    /// 4. Supports mayhem mode functionality
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Keypair for the new token mint account that will be created
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    /// * `mayhem_mode` - Whether to enable mayhem mode for this token
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Metadata upload to IPFS fails
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils::CreateTokenMetadata};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = Keypair::new();
+    /// let metadata = CreateTokenMetadata {
+    ///     name: "My Token".to_string(),
+    ///     symbol: "MYTKN".to_string(),
+    ///     description: "A test token created with Pump.fun".to_string(),
+    ///     file: "path/to/image.png".to_string(),
+    ///     twitter: None,
+    ///     telegram: None,
+    ///     website: Some("https://example.com".to_string()),
+    ///     created_on: None,
+    ///     extra: Default::default(),
+    /// };
+    ///
+    /// let signature = client.create_v2(mint, metadata, false, None).await?;
+    /// println!("Token created! Signature: {}", signature);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_v2(
+        &self,
+        mint: Keypair,
+        metadata: utils::CreateTokenMetadata,
+        mayhem_mode: bool,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        self.create_v2_with_accounts(mint, metadata, mayhem_mode, priority_fee)
+            .await
+            .map(|(signature, _)| signature)
+    }
+
+    /// Creates a new Token 2022 token and returns the Mayhem accounts it was created with
+    ///
+    /// Same as [`PumpFun::create_v2`], but also returns the Mayhem program accounts passed
+    /// to the instruction, so they can be fetched and verified right after launch (see
+    /// [`PumpFun::fetch_mayhem_state`]) without re-deriving them.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Keypair for the new token mint account that will be created
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    /// * `mayhem_mode` - Whether to enable mayhem mode for this token
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature and the Mayhem accounts if successful, or a
+    /// ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Metadata upload to IPFS fails
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils::CreateTokenMetadata};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, signer::Signer};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = Keypair::new();
+    /// let mint_pubkey = mint.pubkey();
+    /// let metadata = CreateTokenMetadata {
+    ///     name: "My Token".to_string(),
+    ///     symbol: "MYTKN".to_string(),
+    ///     description: "A test token created with Pump.fun".to_string(),
+    ///     file: "path/to/image.png".to_string(),
+    ///     twitter: None,
+    ///     telegram: None,
+    ///     website: None,
+    ///     created_on: None,
+    ///     extra: Default::default(),
+    /// };
+    ///
+    /// let (signature, accounts) = client.create_v2_with_accounts(mint, metadata, true, None).await?;
+    /// println!("Token created! Signature: {}", signature);
+    ///
+    /// let mayhem_state = client.fetch_mayhem_state(&mint_pubkey).await?;
+    /// assert!(mayhem_state.is_some());
+    /// println!("Mayhem state: {}", accounts.mayhem_state);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_v2_with_accounts(
+        &self,
+        mint: Keypair,
+        metadata: utils::CreateTokenMetadata,
+        mayhem_mode: bool,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<(Signature, MayhemAccounts), error::ClientError> {
+        let mayhem_accounts = Self::get_mayhem_accounts(&mint.pubkey(), mayhem_mode);
+
+        // First upload metadata and image
+        let ipfs = self.upload_metadata(&metadata).await?;
+
+        // Add priority fee if provided or default to the strategy or cluster priority fee
+        let priority_fee = self
+            .resolve_priority_fee(priority_fee, &Self::get_priority_fee_accounts(None))
+            .await?;
+        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
+
+        // Add create_v2 token instruction
+        let create_ix = self.get_create_v2_instruction(&mint, ipfs, mayhem_mode);
+        instructions.push(create_ix);
+
+        // Create and sign transaction
+        let transaction = get_transaction(
+            self.rpc.clone(),
+            self.payer.clone(),
+            &instructions,
+            Some(&[&mint]),
+            #[cfg(feature = "versioned-tx")]
+            None,
+        )
+        .await?;
+
+        // Send and confirm transaction
+        let signature = utils::transaction::send_and_confirm_transaction(
+            &self.rpc,
+            &transaction,
+            self.send_config,
+        )
+        .await?;
+
+        Ok((signature, mayhem_accounts))
+    }
+
+    /// Creates a new Token 2022 token and immediately buys an initial amount in a single atomic transaction
+    ///
+    /// This method combines Token 2022 token creation and an initial purchase into a single atomic transaction.
+    /// This is often preferred for new token launches as it:
+    /// 1. Creates the Token 2022 token and its bonding curve
+    /// 2. Makes an initial purchase to establish liquidity
+    /// 3. Guarantees that the creator becomes the first holder
+    /// 4. Supports mayhem mode functionality
+    ///
+    /// The entire operation is executed as a single transaction, ensuring atomicity.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Keypair for the new token mint account that will be created
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    /// * `amount_sol` - Amount of SOL to spend on the initial buy, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `mayhem_mode` - Whether to enable mayhem mode for this token
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `priority_fee` - Optional priority fee configuration for compute units. If None, uses the
+    ///   default from the cluster configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `amount_sol` exceeds the maximum initial buy (`ClientError::InitialBuyTooLarge`)
+    /// - Metadata upload to IPFS fails
+    /// - Account retrieval fails
+    /// - Transaction creation fails
+    /// - Transaction execution on Solana fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils::CreateTokenMetadata};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = Keypair::new();
+    /// let metadata = CreateTokenMetadata {
+    ///     name: "My Token".to_string(),
+    ///     symbol: "MYTKN".to_string(),
+    ///     description: "A test token created with Pump.fun".to_string(),
+    ///     file: "path/to/image.png".to_string(),
+    ///     twitter: None,
+    ///     telegram: None,
+    ///     website: Some("https://example.com".to_string()),
+    ///     created_on: None,
+    ///     extra: Default::default(),
+    /// };
+    ///
+    /// // Create Token 2022 token and buy 0.1 SOL worth with 5% slippage tolerance
+    /// let amount_sol = sol_to_lamports(0.1f64); // 0.1 SOL in lamports
+    /// let slippage_bps = Some(500); // 5%
+    /// let track_volume = Some(true); // Track this initial buy in volume stats
+    ///
+    /// let signature = client.create_v2_and_buy(mint, metadata, amount_sol, false, track_volume, slippage_bps, None).await?;
+    /// println!("Token created and bought! Signature: {}", signature);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_v2_and_buy(
+        &self,
+        mint: Keypair,
+        metadata: utils::CreateTokenMetadata,
+        amount_sol: u64,
+        mayhem_mode: bool,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<PriorityFee>,
+    ) -> Result<Signature, error::ClientError> {
+        // Reject an over-limit initial buy before uploading anything
+        self.check_initial_buy(amount_sol).await?;
+
+        // Upload metadata first
+        let ipfs = self.upload_metadata(&metadata).await?;
+
+        // Add priority fee if provided or default to the strategy or cluster priority fee
+        let priority_fee = self
+            .resolve_priority_fee(priority_fee, &Self::get_priority_fee_accounts(None))
+            .await?;
+        let mut instructions = Self::get_priority_fee_instructions(&priority_fee);
+
+        // Derive bonding curve PDA (needed for subsequent instructions)
+        let bonding_curve_pda = Self::get_bonding_curve_pda(&mint.pubkey())
+            .ok_or(error::ClientError::BondingCurveNotFound)?;
+
+        // Add create_v2 token instruction
+        // The program should create the associated_bonding_curve account via CPI during execution.
+        // The account is included in the instruction's account list (position 4) and marked as writable,
+        // and all necessary programs (ASSOCIATED_TOKEN_PROGRAM, TOKEN_2022_PROGRAM, SYSTEM_PROGRAM)
+        // are included, which should allow the program to create it via CPI.
+        let create_ix = self.get_create_v2_instruction(&mint, ipfs, mayhem_mode);
+        instructions.push(create_ix);
+
+        // Add extend account instruction for bonding curve
+        let extend_account_ix = instructions::extend_account(&self.payer, &bonding_curve_pda);
+        instructions.push(extend_account_ix);
+
+        // Add create associated token account instruction (idempotent) using Token 2022
+        // Pre-calculate the associated user ATA address
+        #[cfg(feature = "create-ata")]
+        {
+            let create_ata_ix = create_associated_token_account(
+                &self.payer.pubkey(),
+                &self.payer.pubkey(),
+                &mint.pubkey(),
+                &constants::accounts::TOKEN_2022_PROGRAM,
+            );
+            instructions.push(create_ata_ix);
+        }
+
+        // Add buy instruction for v2
+        let buy_ix = self.get_buy_instructions_v2(
+            mint.pubkey(),
+            amount_sol,
+            track_volume,
+            slippage_basis_points,
+            None,
+        ).await?;
+        instructions.extend(buy_ix);
+
+        // Create and sign transaction
+        let transaction = get_transaction(
+            self.rpc.clone(),
+            self.payer.clone(),
+            &instructions,
+            Some(&[&mint]),
+            #[cfg(feature = "versioned-tx")]
+            None,
+        )
+        .await?;
+
+        // Send and confirm transaction
+        let signature = utils::transaction::send_and_confirm_transaction(
+            &self.rpc,
+            &transaction,
+            self.send_config,
+        )
+        .await?;
+
+        Ok(signature)
+    }
+
+    /// Creates an instruction for initializing a new Token 2022 token
+    ///
+    /// Generates a Solana instruction to create a new Token 2022 token with a bonding curve on Pump.fun.
+    /// This instruction will initialize the token mint, bonding curve accounts, and mayhem mode accounts if enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Keypair for the new token mint account that will be created
+    /// * `ipfs` - Token metadata response from IPFS upload containing name, symbol, and URI
+    /// * `mayhem_mode` - Whether to enable mayhem mode for this token
+    ///
+    /// # Returns
+    ///
+    /// Returns a Solana instruction for creating a new Token 2022 token
+    pub fn get_create_v2_instruction(
+        &self,
+        mint: &Keypair,
+        ipfs: utils::TokenMetadataResponse,
+        mayhem_mode: bool,
+    ) -> Instruction {
+        instructions::create_v2(
+            &self.payer,
+            mint,
+            instructions::CreateV2 {
+                name: ipfs.metadata.name,
+                symbol: ipfs.metadata.symbol,
+                uri: ipfs.metadata_uri,
+                creator: self.payer.pubkey(),
+                is_mayhem_mode: mayhem_mode,
+            },
+        )
+    }
+
+    /// Checks that an initial buy on a new curve stays within the program's limit
+    ///
+    /// # Arguments
+    ///
+    /// * `amount_sol` - Amount of SOL the creator wants to spend, in lamports
+    ///
+    /// # Errors
+    ///
+    /// Returns `ClientError::InitialBuyTooLarge` with the computed cap if `amount_sol`
+    /// exceeds [`accounts::GlobalAccount::get_max_initial_buy_sol`], or an error if the
+    /// global account cannot be fetched
+    async fn check_initial_buy(&self, amount_sol: u64) -> Result<(), error::ClientError> {
+        let global_account = self.get_global_account().await?;
+        let max = global_account.get_max_initial_buy_sol();
+        if amount_sol > max {
+            return Err(error::ClientError::InitialBuyTooLarge {
+                requested: amount_sol,
+                max,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Generates instructions for buying tokens from a bonding curve
+    ///
+    /// Creates a set of Solana instructions needed to purchase tokens using SOL. These
+    /// instructions may include creating an associated token account if needed, and the actual
+    /// buy instruction with slippage protection.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_sol` - Amount of SOL to spend, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of Solana instructions if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The global account or bonding curve account cannot be fetched
+    /// - The buy price calculation fails
+    /// - Token account-related operations fail
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, signature::Keypair, pubkey};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// #
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let amount_sol = sol_to_lamports(0.01); // 0.01 SOL
+    /// let slippage_bps = Some(300); // 3%
+    /// let track_volume = Some(true); // Track this buy in volume stats
+    ///
+    /// let buy_instructions = client.get_buy_instructions(mint, amount_sol, track_volume, slippage_bps).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_buy_instructions(
+        &self,
+        mint: Pubkey,
+        amount_sol: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        self.get_buy_instructions_with_options(
+            mint,
+            amount_sol,
+            track_volume,
+            slippage_basis_points,
+            &TradeOptions::default(),
+        )
+        .await
+    }
+
+    /// Generates buy instructions using per-transaction trade options
+    ///
+    /// Same as [`PumpFun::get_buy_instructions`], except that values set in `options`
+    /// take precedence over those read from the global account.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_sol` - Amount of SOL to spend, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `track_volume` - Optional flag to track this buy in volume stats
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%). Ignored when `options.slippage` is set
+    /// * `options` - Per-transaction trade options
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of Solana instructions if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of [`PumpFun::get_buy_instructions`], returns
+    /// `ClientError::PriceImpactTooHigh` if `options.max_price_impact_bps` is set and the
+    /// buy would exceed it, and `ClientError::CurveComplete` if the bonding curve is
+    /// complete and `options.skip_complete_check` is not set
+    pub async fn get_buy_instructions_with_options(
+        &self,
+        mint: Pubkey,
+        amount_sol: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        options: &TradeOptions,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        self.get_buy_instructions_for(
+            &self.payer.pubkey(),
+            mint,
+            TradeMode::ExactSolIn(amount_sol),
+            track_volume,
+            slippage_basis_points,
+            options,
+        )
+        .await
+    }
+
+    /// Same as [`PumpFun::get_buy_instructions_with_options`], buying for `user` instead of
+    /// the payer
+    async fn get_buy_instructions_for(
+        &self,
+        user: &Pubkey,
+        mint: Pubkey,
+        mode: TradeMode,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        options: &TradeOptions,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        // Get accounts and quote the buy, against a fresh curve if the mint is not created yet
+        let global_account = self.get_trade_global_account(options).await?;
+        let mut bonding_curve_account: Option<accounts::BondingCurveAccount> = None;
+        let (buy_amount, amount_sol) = {
+            let bonding_curve_pda = Self::get_bonding_curve_pda(&mint)
+                .ok_or(error::ClientError::BondingCurveNotFound)?;
+            if let Some(bonding_curve) = &options.bonding_curve {
+                bonding_curve_account = Some(bonding_curve.clone());
+                if !options.skip_complete_check {
+                    Self::check_curve_not_complete(&mint, bonding_curve_account.as_ref())?;
+                }
+                mode.quote(bonding_curve)
+            } else if self.rpc.get_account(&bonding_curve_pda).await.is_err() {
+                mode.quote(&accounts::BondingCurveAccount::new(
+                    0,
+                    global_account.initial_virtual_token_reserves,
+                    global_account.initial_virtual_sol_reserves,
+                    global_account.initial_real_token_reserves,
+                    0,
+                    global_account.token_total_supply,
+                    false,
+                    *user,
+                ))
+            } else {
+                bonding_curve_account = self.get_bonding_curve_account(&mint).await.ok();
+                if !options.skip_complete_check {
+                    Self::check_curve_not_complete(&mint, bonding_curve_account.as_ref())?;
+                }
+                mode.quote(bonding_curve_account.as_ref().unwrap())
+            }
+            .map_err(error::ClientError::BondingCurveError)?
+        };
+
+        // Refuse trades that would move the price too far
+        if let Some(max_bps) = options.max_price_impact_bps {
+            let impact_bps = match bonding_curve_account.as_ref() {
+                Some(bonding_curve) => bonding_curve.get_price_impact_bps(amount_sol),
+                None => accounts::BondingCurveAccount::new(
+                    0,
+                    global_account.initial_virtual_token_reserves,
+                    global_account.initial_virtual_sol_reserves,
+                    global_account.initial_real_token_reserves,
+                    0,
+                    global_account.token_total_supply,
+                    false,
+                    *user,
+                )
+                .get_price_impact_bps(amount_sol),
+            }
+            .map_err(error::ClientError::BondingCurveError)?;
+
+            if impact_bps > max_bps {
+                return Err(error::ClientError::PriceImpactTooHigh {
+                    impact_bps,
+                    max_bps,
+                });
+            }
+        }
+
+        let slippage = options
+            .slippage
+            .unwrap_or(SlippageMode::Bps(slippage_basis_points.unwrap_or(500)));
+        let buy_amount_with_slippage = slippage.max_sol_cost(amount_sol);
+
+        let mut instructions = Vec::new();
+
+        // Create Associated Token Account if needed
+        #[cfg(feature = "create-ata")]
+        {
+            let create_ata = match options.ata_mode {
+                common::types::AtaMode::Auto => {
+                    let ata: Pubkey = get_associated_token_address(user, &mint);
+                    self.rpc.get_account(&ata).await.is_err()
+                }
+                common::types::AtaMode::AssumeExists => false,
+                common::types::AtaMode::AlwaysCreate => true,
+            };
+            if create_ata {
+                instructions.push(create_associated_token_account_idempotent(
+                    user,
+                    user,
+                    &mint,
+                    &constants::accounts::TOKEN_PROGRAM,
+                ));
+            }
+        }
+
+        // Add buy instruction
+        let fee_recipient = Self::get_fee_recipient(&global_account, options.fee_recipient)?;
+        instructions.push(instructions::buy_instruction(
+            user,
+            &mint,
+            &fee_recipient,
+            &bonding_curve_account.map_or(*user, |bc| bc.creator),
+            instructions::Buy {
+                amount: buy_amount,
+                max_sol_cost: buy_amount_with_slippage,
+                track_volume,
+            },
+        ));
+
+        // Tag the transaction if requested
+        if let Some(memo) = &options.memo {
+            instructions.push(utils::transaction::memo(memo));
+        }
+
+        // Bundle the caller's instructions after the trade
+        instructions.extend(options.extra_instructions.iter().cloned());
+
+        Ok(instructions)
+    }
+
+    /// Generates instructions for buying tokens from a bonding curve using Token 2022
+    ///
+    /// Creates a set of Solana instructions needed to purchase Token 2022 tokens using SOL. These
+    /// instructions may include creating an associated token account if needed, and the actual
+    /// buy instruction with slippage protection.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_sol` - Amount of SOL to spend, in lamports (1 SOL = 1,000,000,000 lamports)
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    /// * `fee_recipient` - Optional fee recipient override, validated against the global
+    ///   account. If None, uses the global account's fee recipient
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of Solana instructions if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The global account or bonding curve account cannot be fetched
+    /// - The buy price calculation fails
+    /// - Token account-related operations fail
+    /// - The fee recipient override is not accepted by the program
+    ///   (`ClientError::InvalidFeeRecipient`)
+    pub async fn get_buy_instructions_v2(
+        &self,
+        mint: Pubkey,
+        amount_sol: u64,
+        track_volume: Option<bool>,
+        slippage_basis_points: Option<u64>,
+        fee_recipient: Option<Pubkey>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        // Get accounts and calculate buy amounts
+        let global_account = self.get_global_account().await?;
+        let mut bonding_curve_account: Option<accounts::BondingCurveAccount> = None;
+        let buy_amount = {
+            let bonding_curve_pda = Self::get_bonding_curve_pda(&mint)
+                .ok_or(error::ClientError::BondingCurveNotFound)?;
+            if self.rpc.get_account(&bonding_curve_pda).await.is_err() {
+                global_account.get_initial_buy_price(amount_sol)
+            } else {
+                bonding_curve_account = self.get_bonding_curve_account(&mint).await.ok();
+                bonding_curve_account
+                    .as_ref()
+                    .unwrap()
+                    .get_buy_price(amount_sol)
+                    .map_err(error::ClientError::BondingCurveError)?
+            }
+        };
+        let buy_amount_with_slippage =
+            utils::calculate_with_slippage_buy(amount_sol, slippage_basis_points.unwrap_or(500));
+
+        // Add buy instruction (using Token 2022)
+        let fee_recipient = Self::get_fee_recipient(&global_account, fee_recipient)?;
+        let instructions = vec![instructions::buy_with_token_program(
+            &self.payer,
+            &mint,
+            &fee_recipient,
+            &bonding_curve_account.map_or(self.payer.pubkey(), |bc| bc.creator),
+            &constants::accounts::TOKEN_2022_PROGRAM,
+            instructions::Buy {
+                amount: buy_amount,
+                max_sol_cost: buy_amount_with_slippage,
+                track_volume,
+            },
+        )];
+
+        Ok(instructions)
+    }
+
+    /// Generates instructions for selling tokens back to a bonding curve
+    ///
+    /// Creates a set of Solana instructions needed to sell tokens in exchange for SOL. These
+    /// instructions include the sell instruction with slippage protection and may include
+    /// closing the associated token account if all tokens are being sold and the feature
+    /// is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells the entire balance
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%)
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of Solana instructions if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The token account or token balance cannot be fetched
+    /// - The global account or bonding curve account cannot be fetched
+    /// - The sell price calculation fails
+    /// - Token account closing operations fail (when applicable)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// #
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let amount_tokens = Some(1000); // Sell 1000 tokens
+    /// let slippage_bps = Some(200); // 2%
+    ///
+    /// let sell_instructions = client.get_sell_instructions(mint, amount_tokens, slippage_bps).await?;
+    ///
+    /// // Or to sell all tokens:
+    /// let sell_all_instructions = client.get_sell_instructions(mint, None, None).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_sell_instructions(
+        &self,
+        mint: Pubkey,
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        self.get_sell_instructions_with_options(
+            mint,
+            amount_token,
+            slippage_basis_points,
+            &TradeOptions::default(),
+        )
+        .await
+    }
+
+    /// Generates sell instructions using per-transaction trade options
+    ///
+    /// Same as [`PumpFun::get_sell_instructions`], except that values set in `options`
+    /// take precedence over those read from the global account.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells the entire balance
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%).
+    ///   If None, defaults to 500 (5%). Ignored when `options.slippage` is set
+    /// * `options` - Per-transaction trade options
+    ///
+    /// # Returns
+    ///
+    /// Returns a vector of Solana instructions if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors of [`PumpFun::get_sell_instructions`], returns
+    /// `ClientError::CurveComplete` if the bonding curve is complete and
+    /// `options.skip_complete_check` is not set
+    pub async fn get_sell_instructions_with_options(
+        &self,
+        mint: Pubkey,
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+        options: &TradeOptions,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        self.get_sell_instructions_for(
+            &self.payer.pubkey(),
+            mint,
+            amount_token,
+            slippage_basis_points,
+            options,
+        )
+        .await
+    }
+
+    /// Same as [`PumpFun::get_sell_instructions_with_options`], selling for `user` instead of
+    /// the payer
+    async fn get_sell_instructions_for(
+        &self,
+        user: &Pubkey,
+        mint: Pubkey,
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+        options: &TradeOptions,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        // Get ATA
+        let ata: Pubkey = get_associated_token_address(user, &mint);
+
+        // Get token balance
+        let token_balance = if amount_token.is_none() || cfg!(feature = "close-ata") {
+            // We need the balance if amount_token is None OR if the close-ata feature is enabled
+            let balance = self.rpc.get_token_account_balance(&ata).await?;
+            Some(balance.amount.parse::<u64>().unwrap())
+        } else {
+            None
+        };
+
+        // Determine amount to sell
+        let amount = amount_token.unwrap_or_else(|| token_balance.unwrap());
+
+        // Calculate min sol output
+        let global_account = self.get_trade_global_account(options).await?;
+        let bonding_curve_account = match &options.bonding_curve {
+            Some(bonding_curve) => bonding_curve.clone(),
+            None => self.get_bonding_curve_account(&mint).await?,
+        };
+        if !options.skip_complete_check {
+            Self::check_curve_not_complete(&mint, Some(&bonding_curve_account))?;
+        }
+        let min_sol_output = bonding_curve_account
+            .get_sell_price(amount, global_account.fee_basis_points)
+            .map_err(error::ClientError::BondingCurveError)?;
+        let slippage = options
+            .slippage
+            .unwrap_or(SlippageMode::Bps(slippage_basis_points.unwrap_or(500)));
+        let min_sol_output = slippage.min_sol_output(min_sol_output);
+
+        let mut instructions = Vec::new();
+
+        // Add sell instruction
+        let fee_recipient = Self::get_fee_recipient(&global_account, options.fee_recipient)?;
+        instructions.push(instructions::sell_instruction(
+            user,
+            &mint,
+            &fee_recipient,
+            &bonding_curve_account.creator,
+            instructions::Sell {
                 amount,
                 min_sol_output,
             },
         ));
 
-        // Close account if balance equals amount
-        #[cfg(feature = "close-ata")]
-        {
-            // Token balance should be guaranteed to be available at this point
-            // due to our fetch logic in the beginning of the function
-            if let Some(balance) = token_balance {
-                // Only close the account if we're selling all tokens
-                if balance == amount {
-                    let token_program = constants::accounts::TOKEN_PROGRAM;
+        // Close account if balance equals amount
+        #[cfg(feature = "close-ata")]
+        {
+            // Token balance should be guaranteed to be available at this point
+            // due to our fetch logic in the beginning of the function
+            if let Some(balance) = token_balance {
+                // Only close the account if we're selling all tokens
+                if balance == amount {
+                    let token_program = constants::accounts::TOKEN_PROGRAM;
+
+                    // Verify the token account exists before attempting to close it
+                    if self.rpc.get_account(&ata).await.is_ok() {
+                        // Create instruction to close the ATA
+                        let close_instruction = close_account(
+                            &token_program,
+                            &ata,
+                            user,
+                            user,
+                            &[user],
+                        )
+                        .map_err(|err| {
+                            error::ClientError::OtherError(format!(
+                                "Failed to create close account instruction: pubkey={}: {}",
+                                ata, err
+                            ))
+                        })?;
+
+                        instructions.push(close_instruction);
+                    } else {
+                        // Log warning but don't fail the transaction if account doesn't exist
+                        eprintln!(
+                            "Warning: Cannot close token account {}, it doesn't exist",
+                            ata
+                        );
+                    }
+                }
+            } else {
+                // This case should not occur due to our balance fetch logic,
+                // but handle it gracefully just in case
+                eprintln!("Warning: Token balance unavailable, not closing account");
+            }
+        }
+
+        // Tag the transaction if requested
+        if let Some(memo) = &options.memo {
+            instructions.push(utils::transaction::memo(memo));
+        }
+
+        // Bundle the caller's instructions after the trade
+        instructions.extend(options.extra_instructions.iter().cloned());
+
+        Ok(instructions)
+    }
+
+    /// Gets the Program Derived Address (PDA) for the global state account
+    ///
+    /// Derives the address of the global state account using the program ID and a
+    /// constant seed. The global state account contains program-wide configuration
+    /// such as fee settings and fee recipient.
+    ///
+    /// # Returns
+    ///
+    /// Returns the PDA public key derived from the GLOBAL_SEED
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::PumpFun;
+    /// # use solana_sdk::pubkey::Pubkey;
+    /// #
+    /// let global_pda: Pubkey = PumpFun::get_global_pda();
+    /// println!("Global state account: {}", global_pda);
+    /// ```
+    pub fn get_global_pda() -> Pubkey {
+        let seeds: &[&[u8]; 1] = &[constants::seeds::GLOBAL_SEED];
+        let program_id: &Pubkey = &constants::accounts::PUMPFUN;
+        Pubkey::find_program_address(seeds, program_id).0
+    }
+
+    /// Gets the Program Derived Address (PDA) for the mint authority
+    ///
+    /// Derives the address of the mint authority PDA using the program ID and a
+    /// constant seed. The mint authority PDA is the authority that can mint new
+    /// tokens for any token created through the Pump.fun program.
+    ///
+    /// # Returns
+    ///
+    /// Returns the PDA public key derived from the MINT_AUTHORITY_SEED
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::PumpFun;
+    /// # use solana_sdk::pubkey::Pubkey;
+    /// #
+    /// let mint_authority: Pubkey = PumpFun::get_mint_authority_pda();
+    /// println!("Mint authority account: {}", mint_authority);
+    /// ```
+    pub fn get_mint_authority_pda() -> Pubkey {
+        Self::get_mint_authority_pda_with_bump().0
+    }
+
+    /// Gets the mint authority PDA together with its canonical bump
+    ///
+    /// # Returns
+    ///
+    /// Returns the PDA public key and the bump seed found by `find_program_address`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::{constants, PumpFun};
+    /// # use solana_sdk::pubkey::Pubkey;
+    /// #
+    /// let (mint_authority, bump) = PumpFun::get_mint_authority_pda_with_bump();
+    /// let seeds: &[&[u8]] = &[constants::seeds::MINT_AUTHORITY_SEED, &[bump]];
+    /// assert_eq!(
+    ///     Pubkey::create_program_address(seeds, &constants::accounts::PUMPFUN),
+    ///     Ok(mint_authority)
+    /// );
+    /// ```
+    pub fn get_mint_authority_pda_with_bump() -> (Pubkey, u8) {
+        let seeds: &[&[u8]; 1] = &[constants::seeds::MINT_AUTHORITY_SEED];
+        let program_id: &Pubkey = &constants::accounts::PUMPFUN;
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    /// Gets the Program Derived Address (PDA) for the event authority
+    ///
+    /// Derives the address of the event authority using the program ID and a constant
+    /// seed. The program emits its events through a self-CPI signed by this PDA, which
+    /// every instruction therefore takes as an account. It is the address hardcoded in
+    /// `constants::accounts::EVENT_AUTHORITY`.
+    ///
+    /// # Returns
+    ///
+    /// Returns the PDA public key derived from the EVENT_AUTHORITY_SEED
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::{constants, PumpFun};
+    /// # use solana_sdk::pubkey::Pubkey;
+    /// #
+    /// let event_authority: Pubkey = PumpFun::get_event_authority_pda();
+    /// assert_eq!(event_authority, constants::accounts::EVENT_AUTHORITY);
+    /// ```
+    pub fn get_event_authority_pda() -> Pubkey {
+        let seeds: &[&[u8]; 1] = &[constants::seeds::EVENT_AUTHORITY_SEED];
+        let program_id: &Pubkey = &constants::accounts::PUMPFUN;
+        Pubkey::find_program_address(seeds, program_id).0
+    }
+
+    /// Gets the Program Derived Address (PDA) for a token's bonding curve account
+    ///
+    /// Derives the address of a token's bonding curve account using the program ID,
+    /// a constant seed, and the token mint address. The bonding curve account stores
+    /// the state and parameters that govern the token's price dynamics.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns Some(PDA) if derivation succeeds, or None if it fails
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::PumpFun;
+    /// # use solana_sdk::{pubkey, pubkey::Pubkey};
+    /// #
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// if let Some(bonding_curve) = PumpFun::get_bonding_curve_pda(&mint) {
+    ///     println!("Bonding curve account: {}", bonding_curve);
+    /// }
+    /// ```
+    pub fn get_bonding_curve_pda(mint: &Pubkey) -> Option<Pubkey> {
+        Self::get_bonding_curve_pda_with_bump(mint).map(|pda| pda.0)
+    }
+
+    /// Gets a token's bonding curve PDA together with its canonical bump
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns Some((PDA, bump)) if derivation succeeds, or None if it fails
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::{constants, PumpFun};
+    /// # use solana_sdk::{pubkey, pubkey::Pubkey};
+    /// #
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let (bonding_curve, bump) = PumpFun::get_bonding_curve_pda_with_bump(&mint).unwrap();
+    /// let seeds: &[&[u8]] = &[constants::seeds::BONDING_CURVE_SEED, mint.as_ref(), &[bump]];
+    /// assert_eq!(
+    ///     Pubkey::create_program_address(seeds, &constants::accounts::PUMPFUN),
+    ///     Ok(bonding_curve)
+    /// );
+    /// ```
+    pub fn get_bonding_curve_pda_with_bump(mint: &Pubkey) -> Option<(Pubkey, u8)> {
+        let seeds: &[&[u8]; 2] = &[constants::seeds::BONDING_CURVE_SEED, mint.as_ref()];
+        let program_id: &Pubkey = &constants::accounts::PUMPFUN;
+        Pubkey::try_find_program_address(seeds, program_id)
+    }
+
+    /// Gets the Program Derived Address (PDA) for a token's metadata account
+    ///
+    /// Derives the address of a token's metadata account following the Metaplex Token Metadata
+    /// standard. The metadata account stores information about the token such as name,
+    /// symbol, and URI pointing to additional metadata.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns the PDA public key for the token's metadata account
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::PumpFun;
+    /// # use solana_sdk::{pubkey, pubkey::Pubkey};
+    /// #
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let metadata_pda = PumpFun::get_metadata_pda(&mint);
+    /// println!("Token metadata account: {}", metadata_pda);
+    /// ```
+    pub fn get_metadata_pda(mint: &Pubkey) -> Pubkey {
+        Self::get_metadata_pda_with_bump(mint).0
+    }
+
+    /// Gets a token's metadata PDA together with its canonical bump
+    ///
+    /// The PDA is derived from the Metaplex Token Metadata program, so the bump is the one
+    /// expected by that program.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns the PDA public key and the bump seed found by `find_program_address`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::{constants, PumpFun};
+    /// # use solana_sdk::{pubkey, pubkey::Pubkey};
+    /// #
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let (metadata_pda, bump) = PumpFun::get_metadata_pda_with_bump(&mint);
+    /// let program_id = constants::accounts::MPL_TOKEN_METADATA;
+    /// let seeds: &[&[u8]] = &[
+    ///     constants::seeds::METADATA_SEED,
+    ///     program_id.as_ref(),
+    ///     mint.as_ref(),
+    ///     &[bump],
+    /// ];
+    /// assert_eq!(Pubkey::create_program_address(seeds, &program_id), Ok(metadata_pda));
+    /// ```
+    pub fn get_metadata_pda_with_bump(mint: &Pubkey) -> (Pubkey, u8) {
+        let seeds: &[&[u8]; 3] = &[
+            constants::seeds::METADATA_SEED,
+            constants::accounts::MPL_TOKEN_METADATA.as_ref(),
+            mint.as_ref(),
+        ];
+        let program_id: &Pubkey = &constants::accounts::MPL_TOKEN_METADATA;
+        Pubkey::find_program_address(seeds, program_id)
+    }
+
+    /// Gets the global state account data containing program-wide configuration
+    ///
+    /// Fetches and deserializes the global state account which contains program-wide
+    /// configuration parameters such as:
+    /// - Fee basis points for trading
+    /// - Fee recipient account
+    /// - Bonding curve parameters
+    /// - Other platform-wide settings
+    ///
+    /// # Returns
+    ///
+    /// Returns the deserialized GlobalAccount if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The account cannot be found on-chain
+    /// - The account data cannot be properly deserialized
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let global = client.get_global_account().await?;
+    /// println!("Fee basis points: {}", global.fee_basis_points);
+    /// println!("Fee recipient: {}", global.fee_recipient);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_global_account(&self) -> Result<accounts::GlobalAccount, error::ClientError> {
+        let global: Pubkey = Self::get_global_pda();
+
+        let account = self
+            .rpc
+            .get_account(&global)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        solana_sdk::borsh1::try_from_slice_unchecked::<accounts::GlobalAccount>(&account.data)
+            .map_err(error::ClientError::BorshError)
+    }
+
+    /// Gets the fee recipients the Pump.fun program currently accepts
+    ///
+    /// Any of them can be passed to buys and sells with
+    /// [`TradeOptions::with_fee_recipient`]; without an override the first one is used.
+    ///
+    /// # Returns
+    ///
+    /// Returns the recipients of [`accounts::GlobalAccount::valid_fee_recipients`], or a
+    /// ClientError if the global account cannot be fetched
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee, TradeOptions}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let fee_recipients = client.valid_fee_recipients().await?;
+    ///
+    /// // Any of them is accepted, e.g. to spread trades over the recipients
+    /// let fee_recipient = fee_recipients[fee_recipients.len() - 1];
+    /// let options = TradeOptions::default().with_fee_recipient(fee_recipient);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn valid_fee_recipients(&self) -> Result<Vec<Pubkey>, error::ClientError> {
+        Ok(self.get_global_account().await?.valid_fee_recipients())
+    }
+
+    /// Gets a token's bonding curve account data containing pricing parameters
+    ///
+    /// Fetches and deserializes a token's bonding curve account which contains the
+    /// state and parameters that determine the token's price dynamics, including:
+    /// - Current supply
+    /// - Reserve balance
+    /// - Bonding curve parameters
+    /// - Other token-specific configuration
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns the deserialized BondingCurveAccount if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The bonding curve PDA cannot be derived
+    /// - The account cannot be found on-chain
+    /// - The account data cannot be properly deserialized
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
+    /// # use std::sync::Arc;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let bonding_curve = client.get_bonding_curve_account(&mint).await?;
+    /// println!("Bonding Curve Account: {:#?}", bonding_curve);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_bonding_curve_account(
+        &self,
+        mint: &Pubkey,
+    ) -> Result<accounts::BondingCurveAccount, error::ClientError> {
+        let bonding_curve_pda =
+            Self::get_bonding_curve_pda(mint).ok_or(error::ClientError::BondingCurveNotFound)?;
+
+        let account = self
+            .rpc
+            .get_account(&bonding_curve_pda)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        solana_sdk::borsh1::try_from_slice_unchecked::<accounts::BondingCurveAccount>(&account.data)
+            .map_err(error::ClientError::BorshError)
+    }
+
+    /// Gets the bonding curve accounts for multiple tokens in batched RPC requests
+    ///
+    /// Fetches the bonding curve accounts for the given mints with `getMultipleAccounts`,
+    /// splitting the lookup into requests of at most 100 accounts each. Results are returned
+    /// in the same order as `mints`.
+    ///
+    /// This method has partial-success semantics: every account is decoded independently, so
+    /// a single missing or malformed account does not abort the batch. Each element is:
+    /// - `Ok(Some(curve))` if the account exists and was decoded
+    /// - `Ok(None)` if the account does not exist
+    /// - `Err(error)` if the account exists but could not be decoded
+    ///
+    /// Only a failure of an RPC request itself fails the whole call.
+    ///
+    /// # Arguments
+    ///
+    /// * `mints` - Public keys of the token mints
+    ///
+    /// # Returns
+    ///
+    /// Returns one decoding result per mint if the RPC requests succeed, or a ClientError if
+    /// any of the requests fail
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - A bonding curve PDA cannot be derived
+    /// - A `getMultipleAccounts` request fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
+    /// # use std::sync::Arc;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mints = vec![
+    ///     pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX"),
+    ///     pubkey!("SoMeTokenM1ntAddr3ssXXXXXXXXXXXXXXXXXXXXXXX"),
+    /// ];
+    /// for (mint, curve) in mints.iter().zip(client.get_bonding_curve_accounts(&mints).await?) {
+    ///     match curve {
+    ///         Ok(Some(curve)) => println!("{}: {:#?}", mint, curve),
+    ///         Ok(None) => println!("{}: no bonding curve", mint),
+    ///         Err(err) => eprintln!("{}: failed to decode bonding curve: {}", mint, err),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_bonding_curve_accounts(
+        &self,
+        mints: &[Pubkey],
+    ) -> Result<Vec<accounts::BondingCurveAccountResult>, error::ClientError> {
+        // Maximum number of accounts accepted by a single getMultipleAccounts request
+        const MAX_ACCOUNTS_PER_REQUEST: usize = 100;
+
+        let mut bonding_curve_pdas = Vec::with_capacity(mints.len());
+        for mint in mints {
+            bonding_curve_pdas.push(
+                Self::get_bonding_curve_pda(mint).ok_or(error::ClientError::BondingCurveNotFound)?,
+            );
+        }
+
+        let mut results = Vec::with_capacity(mints.len());
+        for chunk in bonding_curve_pdas.chunks(MAX_ACCOUNTS_PER_REQUEST) {
+            let accounts = self
+                .rpc
+                .get_multiple_accounts(chunk)
+                .await
+                .map_err(error::ClientError::SolanaClientError)?;
+
+            // Decode each account on its own so one bad account can't sink the batch
+            for account in accounts {
+                results.push(match account {
+                    Some(account) => solana_sdk::borsh1::try_from_slice_unchecked::<
+                        accounts::BondingCurveAccount,
+                    >(&account.data)
+                    .map(Some)
+                    .map_err(error::ClientError::BorshError),
+                    None => Ok(None),
+                });
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Lists the Pump.fun tokens held by a wallet, valued at their bonding curves
+    ///
+    /// Fetches the owner's token accounts of both the Token and Token-2022 programs with
+    /// `getTokenAccountsByOwner`, keeps the non-empty ones whose mint has a bonding curve and
+    /// computes the SOL each balance would currently sell for. This is read-heavy: besides
+    /// the two token account requests, the curves are fetched in batches of 100.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - Public key of the wallet
+    ///
+    /// # Returns
+    ///
+    /// Returns one position per non-empty token account of a Pump.fun mint, or a ClientError
+    /// if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - An RPC request fails
+    /// - A token account returned by the RPC node cannot be parsed
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::lamports_to_sol, signature::Keypair, signer::Signer};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer.clone(), cluster);
+    /// for position in client.get_positions(&payer.pubkey()).await? {
+    ///     match position.sell_value {
+    ///         Some(value) => println!("{}: {} SOL", position.mint, lamports_to_sol(value)),
+    ///         None => println!("{}: migrated to the AMM", position.mint),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_positions(
+        &self,
+        owner: &Pubkey,
+    ) -> Result<Vec<common::types::Position>, error::ClientError> {
+        // Non-empty token accounts of both token programs as (account, program, mint, amount)
+        let mut holdings = Vec::new();
+        for token_program in [
+            constants::accounts::TOKEN_PROGRAM,
+            constants::accounts::TOKEN_2022_PROGRAM,
+        ] {
+            let token_accounts = self
+                .rpc
+                .get_token_accounts_by_owner(
+                    owner,
+                    solana_client::rpc_request::TokenAccountsFilter::ProgramId(token_program),
+                )
+                .await
+                .map_err(error::ClientError::SolanaClientError)?;
+
+            for keyed_account in token_accounts {
+                // Accounts are returned jsonParsed
+                let data = serde_json::to_value(&keyed_account.account.data)
+                    .map_err(|err| error::ClientError::OtherError(err.to_string()))?;
+                let info = &data["parsed"]["info"];
+                let parsed = info["mint"]
+                    .as_str()
+                    .and_then(|mint| mint.parse::<Pubkey>().ok())
+                    .zip(
+                        info["tokenAmount"]["amount"]
+                            .as_str()
+                            .and_then(|amount| amount.parse::<u64>().ok()),
+                    )
+                    .zip(keyed_account.pubkey.parse::<Pubkey>().ok());
+                let Some(((mint, amount), token_account)) = parsed else {
+                    return Err(error::ClientError::OtherError(format!(
+                        "Failed to parse token account {}",
+                        keyed_account.pubkey
+                    )));
+                };
 
-                    // Verify the token account exists before attempting to close it
-                    if self.rpc.get_account(&ata).await.is_ok() {
-                        // Create instruction to close the ATA
-                        let close_instruction = close_account(
-                            &token_program,
-                            &ata,
-                            &self.payer.pubkey(),
-                            &self.payer.pubkey(),
-                            &[&self.payer.pubkey()],
-                        )
-                        .map_err(|err| {
-                            error::ClientError::OtherError(format!(
-                                "Failed to create close account instruction: pubkey={}: {}",
-                                ata, err
-                            ))
-                        })?;
+                if amount > 0 {
+                    holdings.push((token_account, token_program, mint, amount));
+                }
+            }
+        }
 
-                        instructions.push(close_instruction);
-                    } else {
-                        // Log warning but don't fail the transaction if account doesn't exist
-                        eprintln!(
-                            "Warning: Cannot close token account {}, it doesn't exist",
-                            ata
-                        );
+        if holdings.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        // Fetched first, so that no ClientError results are held across its request
+        let global_account = self.get_global_account().await?;
+        let mints: Vec<Pubkey> = holdings.iter().map(|(_, _, mint, _)| *mint).collect();
+        let curves = self.get_bonding_curve_accounts(&mints).await?;
+
+        // Tokens without a decodable bonding curve were not launched on Pump.fun
+        Ok(holdings
+            .into_iter()
+            .zip(curves)
+            .filter_map(|((token_account, token_program, mint, amount), curve)| {
+                let bonding_curve = curve.ok().flatten()?;
+                Some(common::types::Position {
+                    mint,
+                    token_account,
+                    token_program,
+                    amount,
+                    sell_value: bonding_curve
+                        .get_sell_price(amount, global_account.fee_basis_points)
+                        .ok(),
+                    bonding_curve,
+                })
+            })
+            .collect())
+    }
+
+    /// Waits until a bonding curve reaches a target progress towards graduation
+    ///
+    /// Polls the bonding curve account for the given mint until its progress, as computed
+    /// by [`accounts::BondingCurveAccount::get_progress`], is at or above `target`. The
+    /// initial real token reserves used for the calculation are read once from the
+    /// global account.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    /// * `target` - Progress to wait for, between `0.0` and `1.0` (e.g. `0.3` for 30%)
+    /// * `poll_interval` - Delay between successive fetches of the bonding curve
+    /// * `timeout` - Maximum time to wait before giving up
+    ///
+    /// # Returns
+    ///
+    /// Returns the bonding curve account as fetched when the target was reached
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The global account or bonding curve account cannot be fetched
+    /// - The target is not reached before `timeout` elapses (`ClientError::Timeout`)
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, pubkey, signature::Keypair};
+    /// # use std::{sync::Arc, time::Duration};
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// // Wait for the curve to cross 30% of the way to graduation
+    /// let curve = client
+    ///     .await_curve_progress(mint, 0.3, Duration::from_secs(2), Duration::from_secs(600))
+    ///     .await?;
+    /// println!("Real SOL reserves: {}", curve.real_sol_reserves);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn await_curve_progress(
+        &self,
+        mint: Pubkey,
+        target: f64,
+        poll_interval: std::time::Duration,
+        timeout: std::time::Duration,
+    ) -> Result<accounts::BondingCurveAccount, error::ClientError> {
+        let global_account = self.get_global_account().await?;
+        let started = std::time::Instant::now();
+
+        loop {
+            let bonding_curve_account = self.get_bonding_curve_account(&mint).await?;
+            if bonding_curve_account.get_progress(global_account.initial_real_token_reserves)
+                >= target
+            {
+                return Ok(bonding_curve_account);
+            }
+
+            if started.elapsed() + poll_interval > timeout {
+                return Err(error::ClientError::Timeout(timeout));
+            }
+
+            utils::sleep(poll_interval).await;
+        }
+    }
+
+    /// Estimates how long a bonding curve takes to graduate at its recent trading pace
+    ///
+    /// Walks the curve's transaction history back over `window` with
+    /// `getSignaturesForAddress`, decodes the trade events of each successful transaction
+    /// and sums the SOL bought minus the SOL sold, fees excluded. That net inflow is
+    /// extrapolated at a constant rate against the SOL still needed to complete the curve,
+    /// see [`accounts::BondingCurveAccount::time_to_graduation`].
+    ///
+    /// At most the 1,000 most recent transactions of the window are sampled, fetched a few
+    /// at a time. On busy tokens the sample then covers less than `window`, and the inflow
+    /// is extrapolated over the period it does cover; the returned estimate records how
+    /// many transactions were sampled, over which period, and whether the sample was
+    /// truncated.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    /// * `window` - How far back to sample trades, e.g. the last 10 minutes
+    ///
+    /// # Returns
+    ///
+    /// Returns the estimate with its sampling metadata. Its `time_to_graduation` is
+    /// `Some(Duration::ZERO)` if the curve is already complete, or `None` if more SOL left
+    /// the curve than went in over the sampled period
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bonding curve account cannot be fetched, an RPC request
+    /// fails, or the events of a sampled transaction cannot be decoded
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, pubkey, signature::Keypair};
+    /// # use std::{sync::Arc, time::Duration};
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// let estimate = client.estimate_time_to_graduation(mint, Duration::from_secs(600)).await?;
+    /// match estimate.time_to_graduation {
+    ///     Some(eta) => println!("At current volume, graduates in ~{} min", eta.as_secs() / 60),
+    ///     None => println!("Not on track to graduate"),
+    /// }
+    /// println!("Based on {} transactions", estimate.sampled_transactions);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub async fn estimate_time_to_graduation(
+        &self,
+        mint: Pubkey,
+        window: std::time::Duration,
+    ) -> Result<common::types::GraduationEstimate, error::ClientError> {
+        use futures::{StreamExt, TryStreamExt};
+
+        const PAGE_SIZE: usize = 1000;
+        const MAX_SAMPLED_TRANSACTIONS: usize = 1000;
+        const CONCURRENT_REQUESTS: usize = 8;
+
+        let bonding_curve_account = self.get_bonding_curve_account(&mint).await?;
+        if bonding_curve_account.complete {
+            return Ok(common::types::GraduationEstimate {
+                time_to_graduation: Some(std::time::Duration::ZERO),
+                net_sol_inflow: 0,
+                sampled_transactions: 0,
+                sampled_window: window,
+                truncated: false,
+            });
+        }
+
+        // Transactions cannot be fetched below `confirmed`
+        let commitment = if self.cluster.commitment.is_at_least_confirmed() {
+            self.cluster.commitment
+        } else {
+            solana_sdk::commitment_config::CommitmentConfig::confirmed()
+        };
+
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs() as i64);
+        let since = now.saturating_sub(window.as_secs() as i64);
+
+        // Collect the successful transactions of the window, newest first
+        let bonding_curve_pda =
+            Self::get_bonding_curve_pda(&mint).ok_or(error::ClientError::BondingCurveNotFound)?;
+        let mut signatures: Vec<Signature> = Vec::new();
+        let mut oldest_block_time = now;
+        let mut truncated = false;
+        let mut before = None;
+
+        'pages: loop {
+            let page = self
+                .rpc
+                .get_signatures_for_address_with_config(
+                    &bonding_curve_pda,
+                    solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config {
+                        before,
+                        until: None,
+                        limit: Some(PAGE_SIZE),
+                        commitment: Some(commitment),
+                    },
+                )
+                .await
+                .map_err(error::ClientError::SolanaClientError)?;
+
+            let Some(last) = page.last() else {
+                break;
+            };
+            before = Some(last.signature.parse().map_err(|err| {
+                error::ClientError::OtherError(format!("Invalid signature from RPC: {}", err))
+            })?);
+
+            for status in &page {
+                // Signatures come newest first, so the window ends at the first older one
+                if status
+                    .block_time
+                    .is_some_and(|block_time| block_time < since)
+                {
+                    break 'pages;
+                }
+                if status.err.is_some() {
+                    continue;
+                }
+                if signatures.len() == MAX_SAMPLED_TRANSACTIONS {
+                    truncated = true;
+                    break 'pages;
+                }
+                if let Some(block_time) = status.block_time {
+                    oldest_block_time = oldest_block_time.min(block_time);
+                }
+                signatures.push(status.signature.parse().map_err(|err| {
+                    error::ClientError::OtherError(format!("Invalid signature from RPC: {}", err))
+                })?);
+            }
+
+            if page.len() < PAGE_SIZE {
+                break;
+            }
+        }
+
+        // A truncated sample only covers the period back to its oldest transaction
+        let sampled_window = if truncated {
+            std::time::Duration::from_secs(now.saturating_sub(oldest_block_time) as u64)
+        } else {
+            window
+        };
+
+        let sampled_transactions = signatures.len();
+        let transactions: Vec<_> = futures::stream::iter(signatures)
+            .map(|signature| async move {
+                let transaction = self
+                    .rpc
+                    .get_transaction_with_config(
+                        &signature,
+                        solana_client::rpc_config::RpcTransactionConfig {
+                            encoding: Some(
+                                solana_transaction_status_client_types::UiTransactionEncoding::Base64,
+                            ),
+                            commitment: Some(commitment),
+                            max_supported_transaction_version: Some(0),
+                        },
+                    )
+                    .await
+                    .map_err(error::ClientError::SolanaClientError)?;
+                Ok::<_, error::ClientError>((signature, transaction))
+            })
+            .buffered(CONCURRENT_REQUESTS)
+            .try_collect()
+            .await?;
+
+        let mut net_sol_inflow: i128 = 0;
+        for (signature, transaction) in &transactions {
+            for event in Self::transaction_events(signature, transaction)? {
+                match event {
+                    common::stream::PumpFunEvent::Trade(trade) if trade.mint == mint => {
+                        if trade.is_buy {
+                            net_sol_inflow += trade.sol_amount as i128;
+                        } else {
+                            net_sol_inflow -= trade.sol_amount as i128;
+                        }
                     }
+                    _ => {}
                 }
-            } else {
-                // This case should not occur due to our balance fetch logic,
-                // but handle it gracefully just in case
-                eprintln!("Warning: Token balance unavailable, not closing account");
             }
         }
 
-        Ok(instructions)
+        Ok(common::types::GraduationEstimate {
+            time_to_graduation: bonding_curve_account
+                .time_to_graduation(net_sol_inflow, sampled_window),
+            net_sol_inflow,
+            sampled_transactions,
+            sampled_window,
+            truncated,
+        })
+    }
+
+    /// Gets the fee paid by a confirmed transaction
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - Signature of the transaction
+    ///
+    /// # Returns
+    ///
+    /// Returns the network fee (base fee plus priority fee) in lamports
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The transaction is not confirmed yet (`ClientError::NotYetConfirmed`)
+    /// - The transaction cannot be fetched or has no status metadata
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let signature = client.buy(mint, sol_to_lamports(0.01), None, None, None).await?;
+    ///
+    /// let fee = client.get_transaction_fee(&signature).await?;
+    /// println!("Paid {} lamports in fees", fee);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_transaction_fee(
+        &self,
+        signature: &Signature,
+    ) -> Result<u64, error::ClientError> {
+        Ok(self.get_transaction_fee_details(signature).await?.fee)
+    }
+
+    /// Gets the fee and the fee payer's balance change of a confirmed transaction
+    ///
+    /// The lookup uses the cluster commitment, raised to `confirmed` if it is lower since
+    /// unconfirmed transactions cannot be fetched.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - Signature of the transaction
+    ///
+    /// # Returns
+    ///
+    /// Returns the fee and the fee payer's SOL balance change, both in lamports
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The transaction is not confirmed yet (`ClientError::NotYetConfirmed`)
+    /// - The transaction cannot be fetched or has no status metadata
+    pub async fn get_transaction_fee_details(
+        &self,
+        signature: &Signature,
+    ) -> Result<TransactionFee, error::ClientError> {
+        let transaction = self.fetch_confirmed_transaction(signature).await?;
+
+        let meta = transaction.transaction.meta.ok_or_else(|| {
+            error::ClientError::OtherError(format!(
+                "Transaction {} has no status metadata",
+                signature
+            ))
+        })?;
+
+        // The fee payer is always the first account of the transaction
+        let payer_balance_change = match (meta.pre_balances.first(), meta.post_balances.first()) {
+            (Some(pre), Some(post)) => *post as i64 - *pre as i64,
+            _ => 0,
+        };
+
+        Ok(TransactionFee {
+            fee: meta.fee,
+            payer_balance_change,
+        })
+    }
+
+    /// Computes the all-in price paid by a confirmed buy
+    ///
+    /// Reads the `TradeEvent`s of the transaction and divides the SOL it spent by the tokens
+    /// it received. The SOL spent is the curve cost, the protocol and creator fees of every
+    /// buy, and the network fee of the transaction (base fee plus priority fee). Rent paid
+    /// for a new token account is not included, since it is refunded when the account is
+    /// closed. All buys of the transaction must be of the same mint.
+    ///
+    /// # Arguments
+    ///
+    /// * `signature` - Signature of the buy transaction
+    ///
+    /// # Returns
+    ///
+    /// Returns the effective price in SOL per whole token
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The transaction is not confirmed yet (`ClientError::NotYetConfirmed`)
+    /// - The transaction cannot be fetched or has no status metadata
+    /// - The Pump.fun events of the transaction cannot be decoded
+    /// - The transaction contains no buy, buys of several mints, or received no tokens
+    /// - The decimals of the mint cannot be fetched
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, native_token::sol_to_lamports, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let signature = client.buy(mint, sol_to_lamports(0.01), None, None, None).await?;
+    ///
+    /// let price = client.effective_buy_price(&signature).await?;
+    /// println!("Paid {} SOL per token, fees included", price);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    pub async fn effective_buy_price(
+        &self,
+        signature: &Signature,
+    ) -> Result<f64, error::ClientError> {
+        let transaction = self.fetch_confirmed_transaction(signature).await?;
+        let network_fee = transaction
+            .transaction
+            .meta
+            .as_ref()
+            .map(|meta| meta.fee)
+            .ok_or_else(|| {
+                error::ClientError::OtherError(format!(
+                    "Transaction {} has no status metadata",
+                    signature
+                ))
+            })?;
+
+        let events = Self::transaction_events(signature, &transaction)?;
+        let buys: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                common::stream::PumpFunEvent::Trade(trade) if trade.is_buy => Some(trade),
+                _ => None,
+            })
+            .collect();
+
+        let Some(mint) = buys.first().map(|trade| trade.mint) else {
+            return Err(error::ClientError::OtherError(format!(
+                "Transaction {} contains no Pump.fun buy",
+                signature
+            )));
+        };
+        if buys.iter().any(|trade| trade.mint != mint) {
+            return Err(error::ClientError::OtherError(format!(
+                "Transaction {} buys several mints",
+                signature
+            )));
+        }
+
+        let tokens: u64 = buys.iter().map(|trade| trade.token_amount).sum();
+        if tokens == 0 {
+            return Err(error::ClientError::OtherError(format!(
+                "Transaction {} received no tokens",
+                signature
+            )));
+        }
+        let lamports = buys.iter().fold(network_fee, |total, trade| {
+            total
+                .saturating_add(trade.sol_amount)
+                .saturating_add(trade.fee)
+                .saturating_add(trade.creator_fee)
+        });
+
+        let decimals = self.get_mint_decimals(&mint).await?;
+        let sol = lamports as f64 / solana_sdk::native_token::LAMPORTS_PER_SOL as f64;
+        Ok(sol / utils::base_amount_to_ui(tokens, decimals))
+    }
+
+    /// Decodes the Pump.fun events of a fetched transaction
+    ///
+    /// Unlike [`common::stream::parse_events_from_transaction`], fails if the transaction has
+    /// no log messages or a Pump.fun payload does not decode, rather than reporting no events.
+    #[cfg(feature = "stream")]
+    #[allow(clippy::result_large_err)]
+    fn transaction_events(
+        signature: &Signature,
+        transaction: &solana_transaction_status_client_types::EncodedConfirmedTransactionWithStatusMeta,
+    ) -> Result<Vec<common::stream::PumpFunEvent>, error::ClientError> {
+        let logs: Option<&Vec<String>> = transaction
+            .transaction
+            .meta
+            .as_ref()
+            .and_then(|meta| Option::from(meta.log_messages.as_ref()));
+        let logs = logs.ok_or_else(|| {
+            error::ClientError::OtherError(format!("Transaction {} has no log messages", signature))
+        })?;
+
+        common::stream::try_events_from_logs(&signature.to_string(), logs).map_err(|err| {
+            error::ClientError::OtherError(format!(
+                "Failed to decode events of transaction {}: {}",
+                signature, err
+            ))
+        })
+    }
+
+    /// Fetches a confirmed transaction with its status metadata
+    ///
+    /// Uses the cluster commitment, raised to `confirmed` if it is lower since unconfirmed
+    /// transactions cannot be fetched. Returns `ClientError::NotYetConfirmed` for unknown
+    /// signatures, for which `getTransaction` returns null.
+    async fn fetch_confirmed_transaction(
+        &self,
+        signature: &Signature,
+    ) -> Result<
+        solana_transaction_status_client_types::EncodedConfirmedTransactionWithStatusMeta,
+        error::ClientError,
+    > {
+        let commitment = if self.cluster.commitment.is_at_least_confirmed() {
+            self.cluster.commitment
+        } else {
+            solana_sdk::commitment_config::CommitmentConfig::confirmed()
+        };
+
+        // `getTransaction` returns null for unknown signatures, so check the status first
+        let status = self
+            .rpc
+            .get_signature_status_with_commitment(signature, commitment)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+        if status.is_none() {
+            return Err(error::ClientError::NotYetConfirmed(*signature));
+        }
+
+        self.rpc
+            .get_transaction_with_config(
+                signature,
+                solana_client::rpc_config::RpcTransactionConfig {
+                    encoding: Some(
+                        solana_transaction_status_client_types::UiTransactionEncoding::Base64,
+                    ),
+                    commitment: Some(commitment),
+                    max_supported_transaction_version: Some(0),
+                },
+            )
+            .await
+            .map_err(error::ClientError::SolanaClientError)
     }
 
-    /// Gets the Program Derived Address (PDA) for the global state account
+    /// Fetches and decodes the Metaplex token metadata of a mint
     ///
-    /// Derives the address of the global state account using the program ID and a
-    /// constant seed. The global state account contains program-wide configuration
-    /// such as fee settings and fee recipient.
+    /// Useful to verify after launch that the on-chain name, symbol and URI match what was
+    /// intended. The null padding of the fixed-length string fields is removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
     ///
     /// # Returns
     ///
-    /// Returns the PDA public key derived from the GLOBAL_SEED
+    /// Returns the decoded metadata account if successful, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The metadata account cannot be fetched
+    /// - The account data is not a valid metadata account
     ///
     /// # Examples
     ///
-    /// ```
-    /// # use pumpfun::PumpFun;
-    /// # use solana_sdk::pubkey::Pubkey;
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
     /// #
-    /// let global_pda: Pubkey = PumpFun::get_global_pda();
-    /// println!("Global state account: {}", global_pda);
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// let metadata = client.fetch_token_metadata(&mint).await?;
+    /// assert_eq!(metadata.symbol, "MYTKN");
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get_global_pda() -> Pubkey {
-        let seeds: &[&[u8]; 1] = &[constants::seeds::GLOBAL_SEED];
-        let program_id: &Pubkey = &constants::accounts::PUMPFUN;
-        Pubkey::find_program_address(seeds, program_id).0
+    pub async fn fetch_token_metadata(
+        &self,
+        mint: &Pubkey,
+    ) -> Result<accounts::OnChainMetadata, error::ClientError> {
+        let metadata_pda = Self::get_metadata_pda(mint);
+
+        let account = self
+            .rpc
+            .get_account(&metadata_pda)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        accounts::OnChainMetadata::from_account_data(&account.data)
+            .map_err(error::ClientError::BorshError)
     }
 
-    /// Gets the Program Derived Address (PDA) for the mint authority
+    /// Fetches the number of decimals of a mint
     ///
-    /// Derives the address of the mint authority PDA using the program ID and a
-    /// constant seed. The mint authority PDA is the authority that can mint new
-    /// tokens for any token created through the Pump.fun program.
+    /// Pump.fun tokens normally have [`constants::decimals::TOKEN_DECIMALS`] decimals, but
+    /// Token-2022 mints created with `create_v2` may be configured differently. Use the
+    /// result for UI/base amount conversions instead of assuming the default.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
     ///
     /// # Returns
     ///
-    /// Returns the PDA public key derived from the MINT_AUTHORITY_SEED
+    /// Returns the decimals of the mint, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The mint account cannot be fetched
+    /// - The account is not owned by the Token or Token-2022 program, or is not a mint
     ///
     /// # Examples
     ///
-    /// ```
-    /// # use pumpfun::PumpFun;
-    /// # use solana_sdk::pubkey::Pubkey;
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}, utils};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
     /// #
-    /// let mint_authority: Pubkey = PumpFun::get_mint_authority_pda();
-    /// println!("Mint authority account: {}", mint_authority);
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// let decimals = client.get_mint_decimals(&mint).await?;
+    /// println!("1 token = {} base units", utils::ui_amount_to_base(1.0, decimals));
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get_mint_authority_pda() -> Pubkey {
-        let seeds: &[&[u8]; 1] = &[constants::seeds::MINT_AUTHORITY_SEED];
-        let program_id: &Pubkey = &constants::accounts::PUMPFUN;
-        Pubkey::find_program_address(seeds, program_id).0
+    pub async fn get_mint_decimals(&self, mint: &Pubkey) -> Result<u8, error::ClientError> {
+        let account = self
+            .rpc
+            .get_account(mint)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        if account.owner != constants::accounts::TOKEN_PROGRAM
+            && account.owner != constants::accounts::TOKEN_2022_PROGRAM
+        {
+            return Err(error::ClientError::OtherError(format!(
+                "Account {} is not owned by a token program",
+                mint
+            )));
+        }
+
+        utils::decode_mint_decimals(&account.data).ok_or_else(|| {
+            error::ClientError::OtherError(format!("Account {} is not a mint", mint))
+        })
     }
 
-    /// Gets the Program Derived Address (PDA) for a token's bonding curve account
+    /// Detects whether a mint was launched with `create` or `create_v2`
     ///
-    /// Derives the address of a token's bonding curve account using the program ID,
-    /// a constant seed, and the token mint address. The bonding curve account stores
-    /// the state and parameters that govern the token's price dynamics.
+    /// Fetches the mint and its bonding curve in one request. The mint's owning program tells
+    /// the variants apart: classic tokens are SPL Token mints and `create_v2` tokens are
+    /// Token-2022 mints. Use [`PumpFun::fetch_mayhem_state`] to check whether mayhem mode is
+    /// active for a `create_v2` token.
     ///
     /// # Arguments
     ///
@@ -1366,31 +5418,76 @@ impl PumpFun {
     ///
     /// # Returns
     ///
-    /// Returns Some(PDA) if derivation succeeds, or None if it fails
+    /// Returns the token variant of the mint, or a ClientError if the operation fails
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The RPC request fails
+    /// - The mint has no bonding curve owned by the Pump.fun program, or is not owned by the
+    ///   Token or Token-2022 program (`ClientError::NotPumpFunToken`)
     ///
     /// # Examples
     ///
-    /// ```
-    /// # use pumpfun::PumpFun;
-    /// # use solana_sdk::{pubkey, pubkey::Pubkey};
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee, TokenVariant}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
     /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
     /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
-    /// if let Some(bonding_curve) = PumpFun::get_bonding_curve_pda(&mint) {
-    ///     println!("Bonding curve account: {}", bonding_curve);
+    ///
+    /// match client.detect_token_variant(&mint).await? {
+    ///     TokenVariant::Classic => println!("Launched with create"),
+    ///     TokenVariant::MayhemV2 => println!("Launched with create_v2"),
     /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get_bonding_curve_pda(mint: &Pubkey) -> Option<Pubkey> {
-        let seeds: &[&[u8]; 2] = &[constants::seeds::BONDING_CURVE_SEED, mint.as_ref()];
-        let program_id: &Pubkey = &constants::accounts::PUMPFUN;
-        let pda: Option<(Pubkey, u8)> = Pubkey::try_find_program_address(seeds, program_id);
-        pda.map(|pubkey| pubkey.0)
+    pub async fn detect_token_variant(
+        &self,
+        mint: &Pubkey,
+    ) -> Result<TokenVariant, error::ClientError> {
+        let bonding_curve =
+            Self::get_bonding_curve_pda(mint).ok_or(error::ClientError::BondingCurveNotFound)?;
+
+        let accounts = self
+            .rpc
+            .get_multiple_accounts(&[*mint, bonding_curve])
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        let (Some(mint_account), Some(curve_account)) = (&accounts[0], &accounts[1]) else {
+            return Err(error::ClientError::NotPumpFunToken(*mint));
+        };
+
+        if curve_account.owner != constants::accounts::PUMPFUN {
+            return Err(error::ClientError::NotPumpFunToken(*mint));
+        }
+
+        if mint_account.owner == constants::accounts::TOKEN_PROGRAM {
+            Ok(TokenVariant::Classic)
+        } else if mint_account.owner == constants::accounts::TOKEN_2022_PROGRAM {
+            Ok(TokenVariant::MayhemV2)
+        } else {
+            Err(error::ClientError::NotPumpFunToken(*mint))
+        }
     }
 
-    /// Gets the Program Derived Address (PDA) for a token's metadata account
+    /// Checks whether a mint was launched on Pump.fun, explaining why if it was not
     ///
-    /// Derives the address of a token's metadata account following the Metaplex Token Metadata
-    /// standard. The metadata account stores information about the token such as name,
-    /// symbol, and URI pointing to additional metadata.
+    /// Fetches the mint and its bonding curve in one request and checks that:
+    /// - the mint is a Token or Token-2022 mint;
+    /// - its mint authority is the Pump.fun mint authority PDA or has been revoked, which
+    ///   the program does once the supply is minted, so nobody else can mint more;
+    /// - the bonding curve PDA of the mint exists and is owned by the Pump.fun program.
+    ///
+    /// Lookalike tokens can copy the name, symbol and image of a Pump.fun token, but not a
+    /// bonding curve at the PDA of their own mint.
     ///
     /// # Arguments
     ///
@@ -1398,46 +5495,205 @@ impl PumpFun {
     ///
     /// # Returns
     ///
-    /// Returns the PDA public key for the token's metadata account
+    /// Returns `TokenVerification::Verified`, the first check the mint failed, or a
+    /// ClientError if the RPC request fails
     ///
     /// # Examples
     ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee, TokenVerification}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    ///
+    /// match client.verify_pumpfun_token(&mint).await? {
+    ///     TokenVerification::Verified => println!("Verified Pump.fun token"),
+    ///     TokenVerification::WrongMintAuthority(authority) => {
+    ///         println!("Mintable by {}", authority)
+    ///     }
+    ///     reason => println!("Not a Pump.fun token: {:?}", reason),
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    /// # use pumpfun::PumpFun;
-    /// # use solana_sdk::{pubkey, pubkey::Pubkey};
+    pub async fn verify_pumpfun_token(
+        &self,
+        mint: &Pubkey,
+    ) -> Result<TokenVerification, error::ClientError> {
+        let bonding_curve =
+            Self::get_bonding_curve_pda(mint).ok_or(error::ClientError::BondingCurveNotFound)?;
+
+        let accounts = self
+            .rpc
+            .get_multiple_accounts(&[*mint, bonding_curve])
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        let Some(mint_account) = &accounts[0] else {
+            return Ok(TokenVerification::MintNotFound);
+        };
+        if mint_account.owner != constants::accounts::TOKEN_PROGRAM
+            && mint_account.owner != constants::accounts::TOKEN_2022_PROGRAM
+        {
+            return Ok(TokenVerification::NotAMint);
+        }
+        match utils::decode_mint_authority(&mint_account.data) {
+            None => return Ok(TokenVerification::NotAMint),
+            Some(Some(authority)) if authority != Self::get_mint_authority_pda() => {
+                return Ok(TokenVerification::WrongMintAuthority(authority));
+            }
+            Some(_) => {}
+        }
+
+        match &accounts[1] {
+            None => Ok(TokenVerification::MissingBondingCurve),
+            Some(curve) if curve.owner != constants::accounts::PUMPFUN => {
+                Ok(TokenVerification::WrongCurveOwner(curve.owner))
+            }
+            Some(_) => Ok(TokenVerification::Verified),
+        }
+    }
+
+    /// Checks whether a mint was launched on Pump.fun
+    ///
+    /// Shortcut for [`PumpFun::verify_pumpfun_token`] when the reason a mint is not
+    /// recognized does not matter.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the mint passed every check, or a ClientError if the RPC request
+    /// fails
+    pub async fn is_pumpfun_token(&self, mint: &Pubkey) -> Result<bool, error::ClientError> {
+        Ok(self.verify_pumpfun_token(mint).await?.is_verified())
+    }
+
+    /// Fetches the Mayhem state account of a mint
+    ///
+    /// The account only exists for tokens created with mayhem mode enabled, which makes
+    /// this a quick post-launch check. Its data layout is owned by the Mayhem program and is
+    /// returned undecoded.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(account)` if the Mayhem state account exists, `None` if it does not, or
+    /// a ClientError if the RPC request fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, pubkey, signature::Keypair};
+    /// # use std::sync::Arc;
     /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
     /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
-    /// let metadata_pda = PumpFun::get_metadata_pda(&mint);
-    /// println!("Token metadata account: {}", metadata_pda);
+    ///
+    /// match client.fetch_mayhem_state(&mint).await? {
+    ///     Some(account) => println!("Mayhem active, {} bytes of state", account.data.len()),
+    ///     None => println!("Mayhem not active"),
+    /// }
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn get_metadata_pda(mint: &Pubkey) -> Pubkey {
-        let seeds: &[&[u8]; 3] = &[
-            constants::seeds::METADATA_SEED,
-            constants::accounts::MPL_TOKEN_METADATA.as_ref(),
-            mint.as_ref(),
-        ];
-        let program_id: &Pubkey = &constants::accounts::MPL_TOKEN_METADATA;
-        Pubkey::find_program_address(seeds, program_id).0
+    pub async fn fetch_mayhem_state(
+        &self,
+        mint: &Pubkey,
+    ) -> Result<Option<solana_sdk::account::Account>, error::ClientError> {
+        let mayhem_state = Self::get_mayhem_state_pda(mint);
+
+        let response = self
+            .rpc
+            .get_account_with_commitment(&mayhem_state, self.rpc.commitment())
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        Ok(response.value)
     }
 
-    /// Gets the global state account data containing program-wide configuration
+    /// Fetches the Mayhem global params account passed to `create_v2`
+    ///
+    /// Like the Mayhem state, its data layout is owned by the Mayhem program and is returned
+    /// undecoded. None of its fields influence the accounts of a `create_v2` instruction:
+    /// the global params, SOL vault, Mayhem state and token vault addresses of
+    /// [`PumpFun::get_mayhem_accounts`] are all derived from fixed seeds and the mint, and
+    /// the Mayhem program reads the params itself when mayhem mode is enabled.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(account)` if the global params account exists, `None` if it does not
+    /// (e.g. on a cluster without the Mayhem program), or a ClientError if the RPC request
+    /// fails
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let payer = Arc::new(Keypair::new());
+    /// # let commitment = CommitmentConfig::confirmed();
+    /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
+    /// # let client = PumpFun::new(payer, cluster);
+    /// match client.fetch_global_params().await? {
+    ///     Some(account) => println!("Global params: {} bytes", account.data.len()),
+    ///     None => println!("Mayhem program not initialized on this cluster"),
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn fetch_global_params(
+        &self,
+    ) -> Result<Option<solana_sdk::account::Account>, error::ClientError> {
+        let global_params = Self::get_global_params_pda();
+
+        let response = self
+            .rpc
+            .get_account_with_commitment(&global_params, self.rpc.commitment())
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+
+        Ok(response.value)
+    }
+
+    /// Lists the most recent token launches by scanning Pump.fun program transactions
     ///
-    /// Fetches and deserializes the global state account which contains program-wide
-    /// configuration parameters such as:
-    /// - Fee basis points for trading
-    /// - Fee recipient account
-    /// - Bonding curve parameters
-    /// - Other platform-wide settings
+    /// Walks the program's transaction history from newest to oldest with
+    /// `getSignaturesForAddress`, fetches each successful transaction and decodes the
+    /// `CreateEvent`s found in its logs. Signatures are requested page by page and
+    /// transactions are fetched one at a time with a short pause in between to stay within
+    /// public RPC rate limits. Events are deduplicated by mint.
     ///
-    /// # Returns
+    /// This is read-heavy: most program transactions are trades, so many transactions may
+    /// have to be fetched to collect `limit` launches. A dedicated RPC endpoint is recommended.
     ///
-    /// Returns the deserialized GlobalAccount if successful, or a ClientError if the operation fails
+    /// # Arguments
     ///
-    /// # Errors
+    /// * `limit` - Maximum number of create events to return
     ///
-    /// Returns an error if:
-    /// - The account cannot be found on-chain
-    /// - The account data cannot be properly deserialized
+    /// # Returns
+    ///
+    /// Returns up to `limit` create events, newest first, or a ClientError if an RPC request
+    /// fails. Fewer events are returned if the available history runs out.
     ///
     /// # Examples
     ///
@@ -1445,86 +5701,157 @@ impl PumpFun {
     /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
     /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
     /// # use std::sync::Arc;
+    /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let payer = Arc::new(Keypair::new());
     /// # let commitment = CommitmentConfig::confirmed();
     /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
     /// # let client = PumpFun::new(payer, cluster);
-    /// let global = client.get_global_account().await?;
-    /// println!("Fee basis points: {}", global.fee_basis_points);
-    /// println!("Fee recipient: {}", global.fee_recipient);
+    /// for event in client.recent_creates(10).await? {
+    ///     println!("{} ({}) launched at {}", event.name, event.symbol, event.mint);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_global_account(&self) -> Result<accounts::GlobalAccount, error::ClientError> {
-        let global: Pubkey = Self::get_global_pda();
+    #[cfg(feature = "stream")]
+    pub async fn recent_creates(
+        &self,
+        limit: usize,
+    ) -> Result<Vec<common::stream::CreateEvent>, error::ClientError> {
+        const PAGE_SIZE: usize = 1000;
+        const REQUEST_DELAY: std::time::Duration = std::time::Duration::from_millis(100);
 
-        let account = self
-            .rpc
-            .get_account(&global)
-            .await
-            .map_err(error::ClientError::SolanaClientError)?;
+        // Transactions cannot be fetched below `confirmed`
+        let commitment = if self.cluster.commitment.is_at_least_confirmed() {
+            self.cluster.commitment
+        } else {
+            solana_sdk::commitment_config::CommitmentConfig::confirmed()
+        };
 
-        solana_sdk::borsh1::try_from_slice_unchecked::<accounts::GlobalAccount>(&account.data)
-            .map_err(error::ClientError::BorshError)
+        let mut events = Vec::new();
+        let mut seen_mints = std::collections::HashSet::new();
+        let mut before = None;
+
+        while events.len() < limit {
+            let page = self
+                .rpc
+                .get_signatures_for_address_with_config(
+                    &constants::accounts::PUMPFUN,
+                    solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config {
+                        before,
+                        until: None,
+                        limit: Some(PAGE_SIZE),
+                        commitment: Some(commitment),
+                    },
+                )
+                .await
+                .map_err(error::ClientError::SolanaClientError)?;
+
+            let Some(last) = page.last() else {
+                break;
+            };
+            before = Some(last.signature.parse().map_err(|err| {
+                error::ClientError::OtherError(format!("Invalid signature from RPC: {}", err))
+            })?);
+
+            for status in &page {
+                if status.err.is_some() {
+                    continue;
+                }
+                let signature: Signature = status.signature.parse().map_err(|err| {
+                    error::ClientError::OtherError(format!("Invalid signature from RPC: {}", err))
+                })?;
+
+                utils::sleep(REQUEST_DELAY).await;
+                let transaction = self
+                    .rpc
+                    .get_transaction_with_config(
+                        &signature,
+                        solana_client::rpc_config::RpcTransactionConfig {
+                            encoding: Some(
+                                solana_transaction_status_client_types::UiTransactionEncoding::Base64,
+                            ),
+                            commitment: Some(commitment),
+                            max_supported_transaction_version: Some(0),
+                        },
+                    )
+                    .await
+                    .map_err(error::ClientError::SolanaClientError)?;
+
+                let logs: Option<Vec<String>> = transaction
+                    .transaction
+                    .meta
+                    .and_then(|meta| meta.log_messages.into());
+                let logs = logs.unwrap_or_default();
+
+                for event in common::stream::create_events_from_logs(&status.signature, &logs) {
+                    if seen_mints.insert(event.mint) {
+                        events.push(event);
+                        if events.len() == limit {
+                            return Ok(events);
+                        }
+                    }
+                }
+            }
+
+            if page.len() < PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(events)
     }
 
-    /// Gets a token's bonding curve account data containing pricing parameters
+    /// Finds existing tokens sharing the name or symbol of a token about to be launched
     ///
-    /// Fetches and deserializes a token's bonding curve account which contains the
-    /// state and parameters that determine the token's price dynamics, including:
-    /// - Current supply
-    /// - Reserve balance
-    /// - Bonding curve parameters
-    /// - Other token-specific configuration
+    /// Launching under the name or symbol of a well-known token is a common way to
+    /// impersonate it, so platforms can warn creators about the collisions found here. Names
+    /// and symbols are compared as described in
+    /// [`common::token_index::is_similar_token`], unless the index matches differently.
     ///
     /// # Arguments
     ///
-    /// * `mint` - Public key of the token mint
+    /// * `name` - Name of the new token
+    /// * `symbol` - Symbol of the new token
     ///
     /// # Returns
     ///
-    /// Returns the deserialized BondingCurveAccount if successful, or a ClientError if the operation fails
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if:
-    /// - The bonding curve PDA cannot be derived
-    /// - The account cannot be found on-chain
-    /// - The account data cannot be properly deserialized
+    /// Returns the mints of the matching tokens, without duplicates, or a ClientError if the
+    /// index cannot be queried. Returns an empty vector if no index is configured with
+    /// [`PumpFun::with_token_index`]
     ///
     /// # Examples
     ///
     /// ```no_run
     /// # use pumpfun::{PumpFun, common::types::{Cluster, PriorityFee}};
-    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair, pubkey};
+    /// # use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
     /// # use std::sync::Arc;
+    /// #
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// # let payer = Arc::new(Keypair::new());
     /// # let commitment = CommitmentConfig::confirmed();
     /// # let cluster = Cluster::devnet(commitment, PriorityFee::default());
     /// # let client = PumpFun::new(payer, cluster);
-    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
-    /// let bonding_curve = client.get_bonding_curve_account(&mint).await?;
-    /// println!("Bonding Curve Account: {:#?}", bonding_curve);
+    /// let similar = client.find_similar_tokens("My Token", "MYTKN").await?;
+    /// if !similar.is_empty() {
+    ///     println!("Warning: {} existing tokens use this name or symbol", similar.len());
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub async fn get_bonding_curve_account(
+    pub async fn find_similar_tokens(
         &self,
-        mint: &Pubkey,
-    ) -> Result<accounts::BondingCurveAccount, error::ClientError> {
-        let bonding_curve_pda =
-            Self::get_bonding_curve_pda(mint).ok_or(error::ClientError::BondingCurveNotFound)?;
-
-        let account = self
-            .rpc
-            .get_account(&bonding_curve_pda)
-            .await
-            .map_err(error::ClientError::SolanaClientError)?;
+        name: &str,
+        symbol: &str,
+    ) -> Result<Vec<Pubkey>, error::ClientError> {
+        let Some(index) = &self.token_index else {
+            return Ok(Vec::new());
+        };
 
-        solana_sdk::borsh1::try_from_slice_unchecked::<accounts::BondingCurveAccount>(&account.data)
-            .map_err(error::ClientError::BorshError)
+        let mut mints = index.find_similar(self, name, symbol).await?;
+        let mut seen = std::collections::HashSet::new();
+        mints.retain(|mint| seen.insert(*mint));
+        Ok(mints)
     }
 
     /// Gets the creator vault address (for claiming pump creator fees)
@@ -1552,12 +5879,138 @@ impl PumpFun {
     /// }
     /// ```
     pub fn get_creator_vault_pda(creator: &Pubkey) -> Option<Pubkey> {
+        Self::get_creator_vault_pda_with_bump(creator).map(|pda| pda.0)
+    }
+
+    /// Gets a creator's vault PDA together with its canonical bump
+    ///
+    /// # Arguments
+    ///
+    /// * `creator` - Public key of the token's creator
+    ///
+    /// # Returns
+    ///
+    /// Returns Some((PDA, bump)) if derivation succeeds, or None if it fails
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::{constants, PumpFun};
+    /// # use solana_sdk::{pubkey, pubkey::Pubkey};
+    /// #
+    /// let creator = pubkey!("Amya8kr2bzEY9kyXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let (vault, bump) = PumpFun::get_creator_vault_pda_with_bump(&creator).unwrap();
+    /// let seeds: &[&[u8]] = &[constants::seeds::CREATOR_VAULT_SEED, creator.as_ref(), &[bump]];
+    /// assert_eq!(
+    ///     Pubkey::create_program_address(seeds, &constants::accounts::PUMPFUN),
+    ///     Ok(vault)
+    /// );
+    /// ```
+    pub fn get_creator_vault_pda_with_bump(creator: &Pubkey) -> Option<(Pubkey, u8)> {
         let seeds: &[&[u8]; 2] = &[constants::seeds::CREATOR_VAULT_SEED, creator.as_ref()];
         let program_id: &Pubkey = &constants::accounts::PUMPFUN;
+        Pubkey::try_find_program_address(seeds, program_id)
+    }
+
+    /// Gets the Program Derived Address (PDA) that owns the AMM pool of a migrated token
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns Some(PDA) if derivation succeeds, or None if it fails
+    pub fn get_pool_authority_pda(mint: &Pubkey) -> Option<Pubkey> {
+        let seeds: &[&[u8]; 2] = &[constants::seeds::POOL_AUTHORITY_SEED, mint.as_ref()];
+        let program_id: &Pubkey = &constants::accounts::PUMPFUN;
+        let pda: Option<(Pubkey, u8)> = Pubkey::try_find_program_address(seeds, program_id);
+        pda.map(|pubkey| pubkey.0)
+    }
+
+    /// Gets the PumpSwap AMM pool a token migrates to once its bonding curve completes
+    ///
+    /// Derives the canonical pool (index 0) created by the migration, which pairs the token
+    /// with wrapped SOL and is owned by the token's pool authority PDA.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns Some(PDA) if derivation succeeds, or None if it fails
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::PumpFun;
+    /// # use solana_sdk::pubkey::Pubkey;
+    /// #
+    /// let mint = Pubkey::new_unique();
+    /// if let Some(pool) = PumpFun::get_amm_pool_pda(&mint) {
+    ///     println!("AMM pool: {}", pool);
+    /// }
+    /// ```
+    pub fn get_amm_pool_pda(mint: &Pubkey) -> Option<Pubkey> {
+        let pool_authority = Self::get_pool_authority_pda(mint)?;
+        let index: u16 = 0;
+        let index_bytes = index.to_le_bytes();
+        let seeds: &[&[u8]; 5] = &[
+            constants::seeds::POOL_SEED,
+            &index_bytes,
+            pool_authority.as_ref(),
+            mint.as_ref(),
+            constants::accounts::WSOL_MINT.as_ref(),
+        ];
+        let program_id: &Pubkey = &constants::accounts::PUMP_AMM;
         let pda: Option<(Pubkey, u8)> = Pubkey::try_find_program_address(seeds, program_id);
         pda.map(|pubkey| pubkey.0)
     }
 
+    /// Returns the global account of a trade, unless pre-fetched in `options`
+    async fn get_trade_global_account(
+        &self,
+        options: &TradeOptions,
+    ) -> Result<accounts::GlobalAccount, error::ClientError> {
+        match &options.global_account {
+            Some(global_account) => Ok(global_account.clone()),
+            None => self.get_global_account().await,
+        }
+    }
+
+    /// Rejects trades against a completed bonding curve, which would fail on-chain
+    #[allow(clippy::result_large_err)]
+    fn check_curve_not_complete(
+        mint: &Pubkey,
+        bonding_curve: Option<&accounts::BondingCurveAccount>,
+    ) -> Result<(), error::ClientError> {
+        match bonding_curve {
+            Some(bonding_curve) if bonding_curve.complete => {
+                Err(error::ClientError::CurveComplete {
+                    mint: *mint,
+                    pool: Self::get_amm_pool_pda(mint),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Picks the fee recipient of a trade, rejecting an override the program would not accept
+    #[allow(clippy::result_large_err)]
+    fn get_fee_recipient(
+        global: &accounts::GlobalAccount,
+        fee_recipient: Option<Pubkey>,
+    ) -> Result<Pubkey, error::ClientError> {
+        match fee_recipient {
+            Some(fee_recipient) if !global.is_valid_fee_recipient(&fee_recipient) => {
+                Err(error::ClientError::InvalidFeeRecipient(fee_recipient))
+            }
+            Some(fee_recipient) => Ok(fee_recipient),
+            None => Ok(global.fee_recipient),
+        }
+    }
+
     /// Returns the PDA of a user volume accumulator account.
     ///
     /// # Arguments
@@ -1642,6 +6095,146 @@ impl PumpFun {
         get_associated_token_address(&sol_vault, mint)
     }
 
+    /// Gets the Mayhem program accounts that `create_v2` passes for a mint
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    /// * `mayhem_mode` - Whether mayhem mode is enabled for the token
+    ///
+    /// # Returns
+    ///
+    /// Returns the Mayhem state, SOL vault, global params and token vault addresses
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::PumpFun;
+    /// # use solana_sdk::pubkey::Pubkey;
+    /// #
+    /// let mint = Pubkey::new_unique();
+    /// let accounts = PumpFun::get_mayhem_accounts(&mint, true);
+    /// assert_eq!(accounts.mayhem_state, PumpFun::get_mayhem_state_pda(&mint));
+    /// assert_eq!(accounts.token_vault, PumpFun::get_token_vault_pda(&mint));
+    /// ```
+    pub fn get_mayhem_accounts(mint: &Pubkey, mayhem_mode: bool) -> MayhemAccounts {
+        MayhemAccounts {
+            is_mayhem_mode: mayhem_mode,
+            mayhem_state: Self::get_mayhem_state_pda(mint),
+            sol_vault: Self::get_sol_vault_pda(),
+            global_params: Self::get_global_params_pda(),
+            token_vault: Self::get_token_vault_pda(mint),
+        }
+    }
+
+    /// Suggests the accounts of a transaction worth storing in an address lookup table
+    ///
+    /// Returns the accounts of `instructions` that stay the same from one launch or trade to
+    /// the next, so that a single lookup table can be reused by every versioned transaction
+    /// built with the `versioned-tx` feature:
+    /// - programs passed as accounts, e.g. the Token and System programs;
+    /// - Pump.fun and Mayhem accounts with fixed addresses, such as the global account, the
+    ///   event authority and the fee configuration;
+    /// - the fee recipient of Pump.fun buys and sells;
+    /// - the creator vault and user volume accumulator of the signers, which are shared by all
+    ///   launches of the same wallet.
+    ///
+    /// Signers and invoked program IDs are left out, as a v0 message must list them
+    /// statically, and so are the mint and its PDAs (bonding curve, token accounts, ...).
+    ///
+    /// # Arguments
+    ///
+    /// * `instructions` - Instructions of a representative transaction, e.g. a create and buy
+    ///
+    /// # Returns
+    ///
+    /// The suggested accounts without duplicates, in order of first appearance
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pumpfun::{constants, instructions::{build_create_and_buy, Buy, Create}, PumpFun};
+    /// use solana_sdk::pubkey::Pubkey;
+    ///
+    /// let payer = Pubkey::new_unique();
+    /// let mint = Pubkey::new_unique();
+    /// let fee_recipient = Pubkey::new_unique();
+    /// let instructions = build_create_and_buy(
+    ///     &payer,
+    ///     &mint,
+    ///     &fee_recipient,
+    ///     Create {
+    ///         name: "My Token".to_string(),
+    ///         symbol: "MYTKN".to_string(),
+    ///         uri: "https://ipfs.io/ipfs/QmMetadata".to_string(),
+    ///         creator: payer,
+    ///     },
+    ///     Buy { amount: 1_000_000, max_sol_cost: 10_000_000, track_volume: Some(true) },
+    ///     Some((200_000, 1_000)),
+    /// );
+    ///
+    /// let accounts = PumpFun::suggest_lookup_table_accounts(&instructions);
+    /// assert!(accounts.contains(&PumpFun::get_global_pda()));
+    /// assert!(accounts.contains(&constants::accounts::EVENT_AUTHORITY));
+    /// assert!(accounts.contains(&fee_recipient));
+    /// assert!(accounts.contains(&PumpFun::get_creator_vault_pda(&payer).unwrap()));
+    /// assert!(!accounts.contains(&mint));
+    /// assert!(!accounts.contains(&payer));
+    /// assert!(!accounts.contains(&PumpFun::get_bonding_curve_pda(&mint).unwrap()));
+    /// ```
+    pub fn suggest_lookup_table_accounts(instructions: &[Instruction]) -> Vec<Pubkey> {
+        let invoked: Vec<Pubkey> = instructions.iter().map(|ix| ix.program_id).collect();
+
+        let mut shared = vec![
+            constants::accounts::PUMPFUN,
+            constants::accounts::MPL_TOKEN_METADATA,
+            constants::accounts::EVENT_AUTHORITY,
+            constants::accounts::SYSTEM_PROGRAM,
+            constants::accounts::TOKEN_PROGRAM,
+            constants::accounts::TOKEN_2022_PROGRAM,
+            constants::accounts::MAYHEM_PROGRAM,
+            constants::accounts::GLOBAL_VOLUME_ACCUMULATOR,
+            constants::accounts::FEE_CONFIG,
+            constants::accounts::FEE_CONFIG_PROGRAM,
+            constants::accounts::ASSOCIATED_TOKEN_PROGRAM,
+            constants::accounts::RENT,
+            constants::accounts::PUMP_AMM,
+            constants::accounts::WSOL_MINT,
+            Self::get_global_pda(),
+            Self::get_mint_authority_pda(),
+            Self::get_global_params_pda(),
+            Self::get_sol_vault_pda(),
+        ];
+        for meta in instructions.iter().flat_map(|ix| &ix.accounts) {
+            if meta.is_signer {
+                shared.push(Self::get_user_volume_accumulator_pda(&meta.pubkey));
+                shared.extend(Self::get_creator_vault_pda(&meta.pubkey));
+            }
+        }
+
+        let discriminators = instructions::Discriminators::current();
+        let mut accounts: Vec<Pubkey> = Vec::new();
+        for ix in instructions {
+            // Buys and sells take the fee recipient right after the global account
+            let is_trade = ix.program_id == constants::accounts::PUMPFUN
+                && (ix.data.starts_with(&discriminators.buy)
+                    || ix.data.starts_with(&discriminators.sell));
+            let fee_recipient = ix.accounts.get(1).filter(|_| is_trade).map(|meta| meta.pubkey);
+
+            for meta in &ix.accounts {
+                let pubkey = meta.pubkey;
+                if meta.is_signer || invoked.contains(&pubkey) || accounts.contains(&pubkey) {
+                    continue;
+                }
+                if shared.contains(&pubkey) || fee_recipient == Some(pubkey) {
+                    accounts.push(pubkey);
+                }
+            }
+        }
+
+        accounts
+    }
+
     /// Gets the associated token address PDA for a given owner, mint, and token program
     ///
     /// This manually derives the associated token account PDA using the same seeds as
@@ -1667,4 +6260,203 @@ impl PumpFun {
         );
         ata
     }
+
+    /// Gets the associated bonding curve token account for a given token program
+    ///
+    /// Derives the token account owned by the bonding curve PDA that holds the curve's
+    /// token reserves. Tokens created with `create` are owned by the Token program, while
+    /// tokens created with `create_v2` are owned by the Token 2022 program, so the program
+    /// passed here must match the mint's owner or the derived address will be wrong.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    /// * `token_program` - The token program ID (TOKEN_PROGRAM or TOKEN_2022_PROGRAM)
+    ///
+    /// # Returns
+    ///
+    /// Returns the associated bonding curve token account address
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pumpfun::{constants, PumpFun};
+    /// # use solana_sdk::pubkey;
+    /// #
+    /// let mint = pubkey!("TokenM1ntPubk3yXXXXXXXXXXXXXXXXXXXXXXXXXXXX");
+    /// let v1 = PumpFun::get_associated_bonding_curve(&mint, &constants::accounts::TOKEN_PROGRAM);
+    /// let v2 = PumpFun::get_associated_bonding_curve(&mint, &constants::accounts::TOKEN_2022_PROGRAM);
+    ///
+    /// assert_eq!(v1, PumpFun::get_associated_bonding_curve_v1(&mint));
+    /// assert_eq!(v2, PumpFun::get_associated_bonding_curve_v2(&mint));
+    /// assert_ne!(v1, v2);
+    /// ```
+    pub fn get_associated_bonding_curve(mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+        let bonding_curve: Pubkey = Self::get_bonding_curve_pda(mint).unwrap();
+        Self::get_associated_token_address_with_program(&bonding_curve, mint, token_program)
+    }
+
+    /// Gets the associated bonding curve token account for a token created with `create`
+    ///
+    /// Shortcut for [`PumpFun::get_associated_bonding_curve`] using the Token program.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns the associated bonding curve token account address
+    pub fn get_associated_bonding_curve_v1(mint: &Pubkey) -> Pubkey {
+        Self::get_associated_bonding_curve(mint, &constants::accounts::TOKEN_PROGRAM)
+    }
+
+    /// Gets the associated bonding curve token account for a token created with `create_v2`
+    ///
+    /// Shortcut for [`PumpFun::get_associated_bonding_curve`] using the Token 2022 program.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns the associated bonding curve token account address
+    pub fn get_associated_bonding_curve_v2(mint: &Pubkey) -> Pubkey {
+        Self::get_associated_bonding_curve(mint, &constants::accounts::TOKEN_2022_PROGRAM)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::types::AtaMode;
+    use solana_sdk::commitment_config::CommitmentConfig;
+
+    #[test]
+    fn test_create_without_buy_instructions() {
+        let payer = Arc::new(Keypair::new());
+        let client = PumpFun::new(
+            payer.clone(),
+            Cluster::devnet(CommitmentConfig::confirmed(), PriorityFee::default()),
+        );
+        let mint = Keypair::new();
+        let ipfs = utils::TokenMetadataResponse {
+            metadata: utils::TokenMetadata {
+                name: "Cat On Horse".to_string(),
+                symbol: "COH".to_string(),
+                description: "Lorem ipsum dolor, sit amet consectetur adipisicing elit."
+                    .to_string(),
+                image: "https://ipfs.io/ipfs/QmImage".to_string(),
+                show_name: true,
+                created_on: "https://pump.fun".to_string(),
+                twitter: None,
+                telegram: None,
+                website: None,
+            },
+            metadata_uri: "https://ipfs.io/ipfs/QmMetadata".to_string(),
+            metadata_cid: Some("QmMetadata".to_string()),
+            image_cid: Some("QmImage".to_string()),
+        };
+
+        let instructions = client.get_create_without_buy_instructions(&mint, ipfs);
+
+        // Exactly create + ATA, no zero-amount buy
+        assert_eq!(instructions.len(), 2);
+        assert_eq!(instructions[0].program_id, constants::accounts::PUMPFUN);
+        assert_eq!(
+            instructions[1].program_id,
+            constants::accounts::ASSOCIATED_TOKEN_PROGRAM
+        );
+        assert_eq!(
+            instructions[1].accounts[1].pubkey,
+            get_associated_token_address(&payer.pubkey(), &mint.pubkey())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_trade_instructions_with_cached_accounts() {
+        // Nothing listens on this port, so any RPC request fails
+        let cluster = Cluster::new(
+            "http://127.0.0.1:1".to_string(),
+            "ws://127.0.0.1:1".to_string(),
+            CommitmentConfig::confirmed(),
+            PriorityFee::default(),
+        );
+        let client = PumpFun::new(Arc::new(Keypair::new()), cluster);
+        let mint = Pubkey::new_unique();
+        let global_account = accounts::GlobalAccount::new(
+            1,
+            true,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1_073_000_000_000_000,
+            30_000_000_000,
+            793_100_000_000_000,
+            1_000_000_000_000_000,
+            95,
+            Pubkey::new_unique(),
+            true,
+            0,
+            5,
+            [Pubkey::new_unique(); 7],
+            Pubkey::new_unique(),
+        );
+        let bonding_curve = accounts::BondingCurveAccount::new(
+            1,
+            1_073_000_000_000_000,
+            30_000_000_000,
+            793_100_000_000_000,
+            0,
+            1_000_000_000_000_000,
+            false,
+            Pubkey::new_unique(),
+        );
+        let options = TradeOptions::default()
+            .with_global_account(global_account.clone())
+            .with_bonding_curve(bonding_curve)
+            .with_ata_mode(AtaMode::AssumeExists);
+        let priority_fee = PriorityFee {
+            unit_limit: Some(100_000),
+            unit_price: Some(1_000),
+        };
+
+        // Buys need no RPC request at all
+        let priority_fee = client
+            .resolve_priority_fee(
+                Some(priority_fee),
+                &PumpFun::get_priority_fee_accounts(Some(&mint)),
+            )
+            .await
+            .unwrap();
+        let mut instructions = PumpFun::get_priority_fee_instructions(&priority_fee);
+        instructions.extend(
+            client
+                .get_buy_instructions_with_options(mint, 1_000_000, None, None, &options)
+                .await
+                .unwrap(),
+        );
+        assert_eq!(instructions.len(), 3);
+        assert_eq!(instructions[2].program_id, constants::accounts::PUMPFUN);
+        assert!(instructions[2]
+            .accounts
+            .iter()
+            .any(|account| account.pubkey == global_account.fee_recipient));
+
+        // Sells of a given amount only fetch the token balance, to decide whether to close
+        // the token account
+        let result = client
+            .get_sell_instructions_with_options(mint, Some(1_000_000), None, &options)
+            .await;
+        if cfg!(feature = "close-ata") {
+            assert!(matches!(
+                result,
+                Err(error::ClientError::SolanaClientError(_))
+            ));
+        } else {
+            let instructions = result.unwrap();
+            assert_eq!(instructions.len(), 1);
+            assert_eq!(instructions[0].program_id, constants::accounts::PUMPFUN);
+        }
+    }
 }
@@ -0,0 +1,442 @@
+//! Decoding of the Pump.fun instructions submitted in a transaction
+//!
+//! Where `common::stream` reads the events a transaction emitted, this module reads the
+//! instructions it submitted: [`parse_actions_from_transaction`] matches every instruction
+//! of the Pump.fun program, including those invoked through CPIs, against the configured
+//! [`Discriminators`] and decodes its arguments into a [`PumpFunAction`].
+
+use crate::{
+    constants,
+    instructions::{self, Discriminators},
+};
+use borsh::BorshDeserialize;
+use solana_sdk::{bs58, pubkey::Pubkey};
+use solana_transaction_status_client_types::{
+    EncodedConfirmedTransactionWithStatusMeta, EncodedTransaction, UiCompiledInstruction,
+    UiInstruction, UiLoadedAddresses, UiMessage, UiParsedInstruction,
+};
+
+/// High-level action performed by a Pump.fun instruction
+///
+/// Amounts are the ones submitted, e.g. the maximum SOL cost of a buy, not the amounts
+/// actually traded, which are only known from the emitted events.
+#[derive(Debug, Clone)]
+pub enum PumpFunAction {
+    /// Token created with the `create` instruction
+    Create {
+        mint: Pubkey,
+        user: Pubkey,
+        args: instructions::Create,
+    },
+    /// Token created with the `create_v2` instruction
+    CreateV2 {
+        mint: Pubkey,
+        user: Pubkey,
+        args: instructions::CreateV2,
+    },
+    /// Tokens bought from a bonding curve
+    Buy {
+        mint: Pubkey,
+        user: Pubkey,
+        args: instructions::Buy,
+    },
+    /// Tokens sold to a bonding curve
+    Sell {
+        mint: Pubkey,
+        user: Pubkey,
+        args: instructions::Sell,
+    },
+    /// Program-owned account extended with `extend_account`
+    ExtendAccount { account: Pubkey, user: Pubkey },
+    /// Reserves of a completed bonding curve withdrawn by the withdraw authority
+    #[cfg(feature = "admin")]
+    Withdraw { mint: Pubkey, authority: Pubkey },
+    /// Pump.fun instruction with an unknown discriminator or undecodable data, with its
+    /// raw instruction data
+    Unknown(Vec<u8>),
+}
+
+/// Instruction with its program and accounts resolved to addresses
+struct ResolvedInstruction {
+    program_id: Pubkey,
+    accounts: Vec<Pubkey>,
+    data: Vec<u8>,
+}
+
+/// Extracts the Pump.fun actions of a fetched transaction
+///
+/// Top-level instructions and the inner instructions recorded in the transaction status
+/// are visited in execution order, so an action invoked through a CPI follows the
+/// instruction that invoked it. Instructions of other programs are skipped, and Pump.fun
+/// instructions that cannot be decoded are returned as [`PumpFunAction::Unknown`].
+///
+/// # Arguments
+///
+/// * `transaction` - Transaction as returned by `getTransaction`, in the `json`,
+///   `jsonParsed`, `base58` or `base64` encoding. Transactions of versioned messages must
+///   include their loaded addresses
+///
+/// # Returns
+///
+/// Returns the actions in execution order, or an empty vector if the transaction has no
+/// Pump.fun instruction or is in the `accounts` encoding, which has no instructions
+pub fn parse_actions_from_transaction(
+    transaction: &EncodedConfirmedTransactionWithStatusMeta,
+) -> Vec<PumpFunAction> {
+    let meta = transaction.transaction.meta.as_ref();
+    // Addresses loaded from lookup tables follow the static keys, writable ones first
+    let loaded_addresses = || -> Vec<Pubkey> {
+        let loaded: Option<&UiLoadedAddresses> =
+            meta.and_then(|meta| Option::from(meta.loaded_addresses.as_ref()));
+        loaded
+            .map(|loaded| loaded.writable.iter().chain(&loaded.readonly))
+            .into_iter()
+            .flatten()
+            .filter_map(|key| key.parse().ok())
+            .collect()
+    };
+
+    let (account_keys, instructions): (Vec<Pubkey>, Vec<UiInstruction>) =
+        match &transaction.transaction.transaction {
+            EncodedTransaction::Json(ui) => match &ui.message {
+                UiMessage::Raw(raw) => {
+                    let keys = raw.account_keys.iter().filter_map(|key| key.parse().ok());
+                    let instructions = raw
+                        .instructions
+                        .iter()
+                        .cloned()
+                        .map(UiInstruction::Compiled);
+                    (
+                        keys.chain(loaded_addresses()).collect(),
+                        instructions.collect(),
+                    )
+                }
+                // Parsed messages already list the addresses loaded from lookup tables
+                UiMessage::Parsed(parsed) => {
+                    let keys = parsed
+                        .account_keys
+                        .iter()
+                        .filter_map(|key| key.pubkey.parse().ok());
+                    (keys.collect(), parsed.instructions.clone())
+                }
+            },
+            EncodedTransaction::Accounts(_) => return Vec::new(),
+            encoded => {
+                let Some(decoded) = encoded.decode() else {
+                    return Vec::new();
+                };
+                let keys = decoded.message.static_account_keys().iter().copied();
+                let instructions = decoded.message.instructions().iter().map(|instruction| {
+                    UiInstruction::Compiled(UiCompiledInstruction::from(instruction, None))
+                });
+                (
+                    keys.chain(loaded_addresses()).collect(),
+                    instructions.collect(),
+                )
+            }
+        };
+
+    let inner_instructions: &[_] = meta
+        .and_then(|meta| Option::from(meta.inner_instructions.as_ref()))
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    let discriminators = Discriminators::current();
+    let mut actions = Vec::new();
+    for (index, instruction) in instructions.iter().enumerate() {
+        let inner = inner_instructions
+            .iter()
+            .filter(|inner| usize::from(inner.index) == index)
+            .flat_map(|inner| &inner.instructions);
+
+        for instruction in std::iter::once(instruction).chain(inner) {
+            if let Some(action) = resolve_instruction(instruction, &account_keys)
+                .and_then(|instruction| decode_action(&instruction, &discriminators))
+            {
+                actions.push(action);
+            }
+        }
+    }
+
+    actions
+}
+
+/// Resolves the program and accounts of an instruction against the transaction's keys
+fn resolve_instruction(
+    instruction: &UiInstruction,
+    account_keys: &[Pubkey],
+) -> Option<ResolvedInstruction> {
+    match instruction {
+        UiInstruction::Compiled(compiled) => Some(ResolvedInstruction {
+            program_id: *account_keys.get(usize::from(compiled.program_id_index))?,
+            accounts: compiled
+                .accounts
+                .iter()
+                .map(|index| account_keys.get(usize::from(*index)).copied())
+                .collect::<Option<_>>()?,
+            data: bs58::decode(&compiled.data).into_vec().ok()?,
+        }),
+        UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(decoded)) => {
+            Some(ResolvedInstruction {
+                program_id: decoded.program_id.parse().ok()?,
+                accounts: decoded
+                    .accounts
+                    .iter()
+                    .map(|key| key.parse().ok())
+                    .collect::<Option<_>>()?,
+                data: bs58::decode(&decoded.data).into_vec().ok()?,
+            })
+        }
+        // Only programs known to the RPC node are fully parsed, which Pump.fun is not
+        UiInstruction::Parsed(UiParsedInstruction::Parsed(_)) => None,
+    }
+}
+
+/// Decodes a Pump.fun instruction, or returns `None` for other programs
+///
+/// Trailing bytes after the known arguments are ignored, so instructions of newer program
+/// versions that append arguments still decode.
+fn decode_action(
+    instruction: &ResolvedInstruction,
+    discriminators: &Discriminators,
+) -> Option<PumpFunAction> {
+    if instruction.program_id != constants::accounts::PUMPFUN {
+        return None;
+    }
+
+    let data = &instruction.data;
+    let account = |index: usize| instruction.accounts.get(index).copied();
+    let unknown = || PumpFunAction::Unknown(data.clone());
+    let Some((discriminator, mut args)) = data.split_first_chunk::<8>() else {
+        return Some(unknown());
+    };
+
+    let action = if *discriminator == discriminators.create {
+        instructions::Create::deserialize(&mut args)
+            .ok()
+            .zip(account(0).zip(account(7)))
+            .map(|(args, (mint, user))| PumpFunAction::Create { mint, user, args })
+    } else if *discriminator == discriminators.create_v2 {
+        instructions::CreateV2::deserialize(&mut args)
+            .ok()
+            .zip(account(0).zip(account(5)))
+            .map(|(args, (mint, user))| PumpFunAction::CreateV2 { mint, user, args })
+    } else if *discriminator == discriminators.buy {
+        // Buys sent before `track_volume` was added end after `max_sol_cost`
+        let mut padded = args.to_vec();
+        if padded.len() == 16 {
+            padded.push(0);
+        }
+        instructions::Buy::deserialize(&mut padded.as_slice())
+            .ok()
+            .zip(account(2).zip(account(6)))
+            .map(|(args, (mint, user))| PumpFunAction::Buy { mint, user, args })
+    } else if *discriminator == discriminators.sell {
+        instructions::Sell::deserialize(&mut args)
+            .ok()
+            .zip(account(2).zip(account(6)))
+            .map(|(args, (mint, user))| PumpFunAction::Sell { mint, user, args })
+    } else if *discriminator == discriminators.extend_account {
+        account(0)
+            .zip(account(1))
+            .map(|(account, user)| PumpFunAction::ExtendAccount { account, user })
+    } else {
+        #[cfg(feature = "admin")]
+        if *discriminator == discriminators.withdraw {
+            return Some(
+                account(2)
+                    .zip(account(6))
+                    .map(|(mint, authority)| PumpFunAction::Withdraw { mint, authority })
+                    .unwrap_or_else(unknown),
+            );
+        }
+        None
+    };
+
+    Some(action.unwrap_or_else(unknown))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::Engine;
+    use solana_sdk::{
+        instruction::Instruction, message::Message, transaction::VersionedTransaction,
+    };
+
+    /// Builds a fetched transaction in `base64` encoding with the given inner instructions
+    fn encoded_transaction(
+        instructions: &[Instruction],
+        payer: &Pubkey,
+        inner_instructions: serde_json::Value,
+    ) -> (EncodedConfirmedTransactionWithStatusMeta, Vec<Pubkey>) {
+        let message = Message::new(instructions, Some(payer));
+        let account_keys = message.account_keys.clone();
+        let transaction = VersionedTransaction {
+            signatures: vec![Default::default()],
+            message: solana_sdk::message::VersionedMessage::Legacy(message),
+        };
+        let encoded = base64::engine::general_purpose::STANDARD
+            .encode(bincode::serialize(&transaction).unwrap());
+
+        let transaction = serde_json::from_value(serde_json::json!({
+            "slot": 1,
+            "blockTime": null,
+            "transaction": [encoded, "base64"],
+            "meta": {
+                "err": null,
+                "status": { "Ok": null },
+                "fee": 5000,
+                "preBalances": [],
+                "postBalances": [],
+                "innerInstructions": inner_instructions
+            }
+        }))
+        .unwrap();
+        (transaction, account_keys)
+    }
+
+    #[test]
+    fn test_parse_actions_from_transaction() {
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let fee_recipient = Pubkey::new_unique();
+        let router = Pubkey::new_unique();
+
+        let buy = instructions::buy_instruction(
+            &payer,
+            &mint,
+            &fee_recipient,
+            &payer,
+            instructions::Buy::tokens(1_000).max_sol(2_000),
+        );
+        let unknown = Instruction::new_with_bytes(
+            constants::accounts::PUMPFUN,
+            &[9, 9, 9, 9, 9, 9, 9, 9],
+            vec![],
+        );
+        // A router instruction whose CPI sells through Pump.fun
+        let sell = instructions::sell_instruction(
+            &payer,
+            &mint,
+            &fee_recipient,
+            &payer,
+            instructions::Sell::tokens(500).min_sol(100),
+        );
+        let mut routed = sell.clone();
+        routed.program_id = router;
+
+        let (transaction, account_keys) =
+            encoded_transaction(&[buy, routed, unknown], &payer, serde_json::Value::Null);
+        let index = |key: &Pubkey| account_keys.iter().position(|k| k == key).unwrap() as u8;
+        let inner = serde_json::json!([{
+            "index": 1,
+            "instructions": [{
+                "programIdIndex": index(&constants::accounts::PUMPFUN),
+                "accounts": sell.accounts.iter().map(|meta| index(&meta.pubkey)).collect::<Vec<_>>(),
+                "data": bs58::encode(&sell.data).into_string(),
+                "stackHeight": 2
+            }]
+        }]);
+        let mut transaction = transaction;
+        let (with_inner, _) = encoded_transaction(&[], &payer, inner);
+        transaction.transaction.meta = with_inner.transaction.meta;
+
+        let actions = parse_actions_from_transaction(&transaction);
+        assert_eq!(actions.len(), 3);
+        let PumpFunAction::Buy {
+            mint: buy_mint,
+            user,
+            args,
+        } = &actions[0]
+        else {
+            panic!("expected a buy, got {:?}", actions[0]);
+        };
+        assert_eq!((*buy_mint, *user), (mint, payer));
+        assert_eq!((args.amount, args.max_sol_cost), (1_000, 2_000));
+        let PumpFunAction::Sell { args, .. } = &actions[1] else {
+            panic!("expected a sell, got {:?}", actions[1]);
+        };
+        assert_eq!((args.amount, args.min_sol_output), (500, 100));
+        assert!(matches!(&actions[2], PumpFunAction::Unknown(data) if data[0] == 9));
+    }
+
+    #[cfg(feature = "admin")]
+    #[test]
+    fn test_parse_withdraw_action() {
+        let authority = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let withdraw = instructions::withdraw_instruction(&authority, &mint);
+
+        let (transaction, _) =
+            encoded_transaction(&[withdraw], &authority, serde_json::Value::Null);
+        let actions = parse_actions_from_transaction(&transaction);
+        assert!(matches!(
+            actions.as_slice(),
+            [PumpFunAction::Withdraw { mint: withdrawn, authority: signer }]
+                if (*withdrawn, *signer) == (mint, authority)
+        ));
+    }
+
+    #[test]
+    fn test_parse_actions_from_json_parsed_transaction() {
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let create = instructions::create_instruction(
+            &payer,
+            &mint,
+            instructions::Create {
+                name: "Example".to_string(),
+                symbol: "EXM".to_string(),
+                uri: "https://example.com/metadata.json".to_string(),
+                creator: payer,
+            },
+        );
+
+        let transaction: EncodedConfirmedTransactionWithStatusMeta =
+            serde_json::from_value(serde_json::json!({
+                "slot": 1,
+                "blockTime": null,
+                "transaction": {
+                    "signatures": ["1111111111111111111111111111111111111111111111111111111111111111"],
+                    "message": {
+                        "accountKeys": [],
+                        "recentBlockhash": "11111111111111111111111111111111",
+                        "instructions": [
+                            {
+                                "program": "system",
+                                "programId": "11111111111111111111111111111111",
+                                "parsed": { "type": "transfer" },
+                                "stackHeight": null
+                            },
+                            {
+                                "programId": constants::accounts::PUMPFUN.to_string(),
+                                "accounts": create
+                                    .accounts
+                                    .iter()
+                                    .map(|meta| meta.pubkey.to_string())
+                                    .collect::<Vec<_>>(),
+                                "data": bs58::encode(&create.data).into_string(),
+                                "stackHeight": null
+                            }
+                        ]
+                    }
+                },
+                "meta": null
+            }))
+            .unwrap();
+
+        let actions = parse_actions_from_transaction(&transaction);
+        assert_eq!(actions.len(), 1);
+        let PumpFunAction::Create {
+            mint: created,
+            user,
+            args,
+        } = &actions[0]
+        else {
+            panic!("expected a create, got {:?}", actions[0]);
+        };
+        assert_eq!((*created, *user), (mint, payer));
+        assert_eq!(args.symbol, "EXM");
+    }
+}
@@ -0,0 +1,42 @@
+//! Commonly used types, re-exported for convenience
+//!
+//! `use pumpfun::prelude::*;` brings the client, its configuration types, the account and
+//! instruction structs, the metadata upload types and the slippage helpers into scope. The
+//! original module paths remain available.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! use pumpfun::prelude::*;
+//! use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+//! use std::sync::Arc;
+//!
+//! let payer = Arc::new(Keypair::new());
+//! let cluster = Cluster::devnet(CommitmentConfig::confirmed(), PriorityFee::default());
+//! let client = PumpFun::new(payer, cluster);
+//!
+//! let max_cost = calculate_with_slippage_buy(1_000_000_000, 500);
+//! ```
+
+pub use crate::{
+    accounts::{BondingCurveAccount, BreakEven, GlobalAccount, OnChainMetadata},
+    common::types::{
+        AtaMode, BalancePrediction, Cluster, GraduationEstimate, MayhemAccounts, Position,
+        PriorityFee, SlippageMode, TokenVariant, TokenVerification, TradeMode, TradeOptions,
+        TransactionFee, UnsignedTransaction,
+    },
+    error::ClientError,
+    instructions::{Buy, Create, CreateV2, Discriminators, Sell},
+    utils::{
+        buy_quote, calculate_with_slippage_buy, calculate_with_slippage_buy_pct,
+        calculate_with_slippage_sell, calculate_with_slippage_sell_pct, create_token_metadata,
+        create_token_metadata_with_options, percent_to_basis_points, sell_quote,
+        uploader::{MetadataUploader, PumpFunUploader},
+        BuyQuote, CreateTokenMetadata, SellQuote, TokenMetadata, TokenMetadataResponse,
+        UploadOptions,
+    },
+    PumpFun,
+};
+
+#[cfg(feature = "stream")]
+pub use crate::common::stream::{PumpFunEvent, Subscription};
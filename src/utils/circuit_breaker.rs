@@ -0,0 +1,190 @@
+//! Circuit breaker for repeated metadata upload failures
+//!
+//! When the Pump.fun IPFS API fails, retrying every upload of a batch launch only adds load
+//! to a service that is already down. A [`CircuitBreaker`] shared between uploads counts
+//! consecutive failures and, once too many happen within a window, rejects uploads with a
+//! [`CircuitOpen`] error for a cooldown period instead of sending them. After the cooldown
+//! the next upload is let through: a success closes the circuit, a failure reopens it.
+
+use std::{
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Error returned when an upload is rejected by an open [`CircuitBreaker`]
+///
+/// # Fields
+///
+/// * `retry_after` - Time left until the breaker lets an upload through again
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CircuitOpen {
+    pub retry_after: Duration,
+}
+
+impl std::fmt::Display for CircuitOpen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Upload circuit open after repeated failures, retry in {:?}",
+            self.retry_after
+        )
+    }
+}
+
+impl std::error::Error for CircuitOpen {}
+
+#[derive(Debug, Default)]
+struct State {
+    /// Number of consecutive failures in the current streak
+    failures: u32,
+    /// Time of the first failure of the current streak
+    first_failure: Option<Instant>,
+    /// End of the cooldown while the circuit is open
+    open_until: Option<Instant>,
+}
+
+/// Breaker rejecting uploads after repeated consecutive failures
+///
+/// # Examples
+///
+/// ```rust
+/// use pumpfun::utils::{circuit_breaker::CircuitBreaker, UploadOptions};
+/// use std::{sync::Arc, time::Duration};
+///
+/// // Stop uploading for 30 seconds after 3 failures in a row within a minute
+/// let breaker = Arc::new(CircuitBreaker::new(
+///     3,
+///     Duration::from_secs(60),
+///     Duration::from_secs(30),
+/// ));
+/// let options = UploadOptions::default().with_circuit_breaker(breaker.clone());
+/// assert!(breaker.check().is_ok());
+/// ```
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    window: Duration,
+    cooldown: Duration,
+    state: Mutex<State>,
+}
+
+impl CircuitBreaker {
+    /// Creates a closed circuit breaker
+    ///
+    /// # Arguments
+    ///
+    /// * `failure_threshold` - Consecutive failures opening the circuit, at least 1
+    /// * `window` - Time within which the failures must happen; a failure after the window
+    ///   starts a new streak
+    /// * `cooldown` - Time the circuit stays open before letting an upload through
+    pub fn new(failure_threshold: u32, window: Duration, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            window,
+            cooldown,
+            state: Mutex::new(State::default()),
+        }
+    }
+
+    /// Checks whether an upload may be sent
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(())` if the circuit is closed or its cooldown has elapsed, or a
+    /// [`CircuitOpen`] error with the remaining cooldown
+    pub fn check(&self) -> Result<(), CircuitOpen> {
+        self.check_at(Instant::now())
+    }
+
+    /// Records a successful upload, closing the circuit
+    pub fn record_success(&self) {
+        *self.lock() = State::default();
+    }
+
+    /// Records a failed upload, opening the circuit once the threshold is reached
+    pub fn record_failure(&self) {
+        self.record_failure_at(Instant::now())
+    }
+
+    /// Returns whether uploads are currently rejected
+    pub fn is_open(&self) -> bool {
+        self.check().is_err()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, State> {
+        // The state is always left consistent, so a poisoned lock can be reused
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn check_at(&self, now: Instant) -> Result<(), CircuitOpen> {
+        match self.lock().open_until {
+            Some(open_until) if now < open_until => Err(CircuitOpen {
+                retry_after: open_until - now,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    fn record_failure_at(&self, now: Instant) {
+        let mut state = self.lock();
+        let in_window = state
+            .first_failure
+            .is_some_and(|first| now.duration_since(first) <= self.window);
+        if in_window || state.open_until.is_some() {
+            state.failures = state.failures.saturating_add(1);
+        } else {
+            state.failures = 1;
+            state.first_failure = Some(now);
+        }
+
+        if state.failures >= self.failure_threshold {
+            state.open_until = Some(now + self.cooldown);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circuit_breaker() {
+        let breaker = CircuitBreaker::new(3, Duration::from_secs(60), Duration::from_secs(30));
+        let start = Instant::now();
+
+        // Failures spread beyond the window never open the circuit
+        breaker.record_failure_at(start);
+        breaker.record_failure_at(start + Duration::from_secs(10));
+        breaker.record_failure_at(start + Duration::from_secs(90));
+        assert!(breaker.check_at(start + Duration::from_secs(90)).is_ok());
+
+        // A success resets the streak
+        breaker.record_success();
+        breaker.record_failure_at(start);
+        breaker.record_failure_at(start);
+        breaker.record_success();
+        breaker.record_failure_at(start);
+        assert!(breaker.check_at(start).is_ok());
+
+        // The third consecutive failure opens the circuit for the cooldown
+        breaker.record_failure_at(start + Duration::from_secs(1));
+        breaker.record_failure_at(start + Duration::from_secs(2));
+        assert_eq!(
+            breaker.check_at(start + Duration::from_secs(12)),
+            Err(CircuitOpen {
+                retry_after: Duration::from_secs(20)
+            })
+        );
+
+        // After the cooldown one upload goes through; failing it reopens the circuit
+        let after = start + Duration::from_secs(32);
+        assert!(breaker.check_at(after).is_ok());
+        breaker.record_failure_at(after);
+        assert!(breaker.check_at(after).is_err());
+
+        // A success after the next cooldown closes it
+        breaker.record_success();
+        assert!(breaker.check_at(after).is_ok());
+        assert!(!breaker.is_open());
+    }
+}
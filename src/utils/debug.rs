@@ -0,0 +1,210 @@
+//! Debugging helpers for inspecting built instructions
+//!
+//! Account-ordering mistakes (a PDA derived with the wrong token program, two accounts
+//! swapped, ...) only surface as opaque on-chain errors. [`debug_accounts`] renders the
+//! accounts of an instruction as a table with a best-effort label for each of them, so
+//! a misplaced account stands out.
+
+use crate::{constants, PumpFun};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey};
+
+/// Well-known addresses that do not depend on any other account
+fn known_accounts() -> Vec<(Pubkey, &'static str)> {
+    vec![
+        (constants::accounts::PUMPFUN, "Pump.fun program"),
+        (
+            constants::accounts::MPL_TOKEN_METADATA,
+            "token metadata program",
+        ),
+        (constants::accounts::EVENT_AUTHORITY, "event authority"),
+        (constants::accounts::SYSTEM_PROGRAM, "system program"),
+        (constants::accounts::TOKEN_PROGRAM, "token program"),
+        (
+            constants::accounts::TOKEN_2022_PROGRAM,
+            "token 2022 program",
+        ),
+        (constants::accounts::MAYHEM_PROGRAM, "mayhem program"),
+        (
+            constants::accounts::GLOBAL_VOLUME_ACCUMULATOR,
+            "global volume accumulator",
+        ),
+        (constants::accounts::FEE_CONFIG, "fee config"),
+        (
+            constants::accounts::FEE_CONFIG_PROGRAM,
+            "fee config program",
+        ),
+        (
+            constants::accounts::ASSOCIATED_TOKEN_PROGRAM,
+            "associated token program",
+        ),
+        (constants::accounts::RENT, "rent sysvar"),
+        (constants::accounts::PUMP_AMM, "PumpSwap AMM program"),
+        (constants::accounts::MEMO_PROGRAM, "memo program"),
+        (constants::accounts::WSOL_MINT, "wrapped SOL mint"),
+        (PumpFun::get_global_pda(), "global"),
+        (PumpFun::get_mint_authority_pda(), "mint authority"),
+        (PumpFun::get_global_params_pda(), "global params"),
+        (PumpFun::get_sol_vault_pda(), "mayhem SOL vault"),
+    ]
+}
+
+/// Finds a label for `pubkey`, trying the other accounts of the instruction as seeds
+fn label_account(pubkey: &Pubkey, accounts: &[Pubkey]) -> Option<String> {
+    if let Some((_, label)) = known_accounts().iter().find(|(key, _)| key == pubkey) {
+        return Some(label.to_string());
+    }
+
+    let token_programs = [
+        (constants::accounts::TOKEN_PROGRAM, "token program"),
+        (
+            constants::accounts::TOKEN_2022_PROGRAM,
+            "token 2022 program",
+        ),
+    ];
+
+    for (index, seed) in accounts.iter().enumerate() {
+        if PumpFun::get_bonding_curve_pda(seed).as_ref() == Some(pubkey) {
+            return Some(format!("bonding curve of #{}", index));
+        }
+        if PumpFun::get_creator_vault_pda(seed).as_ref() == Some(pubkey) {
+            return Some(format!("creator vault of #{}", index));
+        }
+        if &PumpFun::get_metadata_pda(seed) == pubkey {
+            return Some(format!("metadata of #{}", index));
+        }
+        if &PumpFun::get_user_volume_accumulator_pda(seed) == pubkey {
+            return Some(format!("user volume accumulator of #{}", index));
+        }
+        if &PumpFun::get_mayhem_state_pda(seed) == pubkey {
+            return Some(format!("mayhem state of #{}", index));
+        }
+        for (token_program, program_name) in &token_programs {
+            if &PumpFun::get_associated_bonding_curve(seed, token_program) == pubkey {
+                return Some(format!(
+                    "associated bonding curve of #{} ({})",
+                    index, program_name
+                ));
+            }
+        }
+    }
+
+    for (owner_index, owner) in accounts.iter().enumerate() {
+        for (mint_index, mint) in accounts.iter().enumerate() {
+            for (token_program, program_name) in &token_programs {
+                let ata =
+                    PumpFun::get_associated_token_address_with_program(owner, mint, token_program);
+                if &ata == pubkey {
+                    return Some(format!(
+                        "token account of #{} for mint #{} ({})",
+                        owner_index, mint_index, program_name
+                    ));
+                }
+            }
+        }
+    }
+
+    None
+}
+
+/// Renders the accounts of an instruction as a readable table
+///
+/// Each row shows the account's index, public key, signer and writable flags, and a
+/// best-effort label. Labels come from the crate's known program addresses and PDAs, and
+/// from PDAs derived with the other accounts of the instruction as seeds (e.g. "bonding
+/// curve of #2" when account 2 is the mint). Derived labels name the token program they
+/// were derived with, which exposes accounts derived for the wrong program.
+///
+/// Labelling derives many PDAs and is meant for debugging, not hot paths.
+///
+/// # Arguments
+///
+/// * `ix` - Instruction to describe
+///
+/// # Returns
+///
+/// Returns a multi-line table describing the instruction's accounts
+///
+/// # Examples
+///
+/// ```
+/// use pumpfun::{instructions, utils::debug::debug_accounts};
+/// use solana_sdk::pubkey::Pubkey;
+///
+/// let payer = Pubkey::new_unique();
+/// let mint = Pubkey::new_unique();
+/// let ix = instructions::sell_instruction(
+///     &payer,
+///     &mint,
+///     &Pubkey::new_unique(),
+///     &Pubkey::new_unique(),
+///     instructions::Sell { amount: 1, min_sol_output: 0 },
+/// );
+///
+/// let table = debug_accounts(&ix);
+/// assert!(table.contains("bonding curve of #2"));
+/// println!("{}", table);
+/// ```
+pub fn debug_accounts(ix: &Instruction) -> String {
+    let pubkeys: Vec<Pubkey> = ix.accounts.iter().map(|meta| meta.pubkey).collect();
+    let program_label = label_account(&ix.program_id, &[]).unwrap_or_else(|| "unknown".into());
+
+    let mut table = format!(
+        "program: {} ({}), {} accounts, {} data bytes\n",
+        ix.program_id,
+        program_label,
+        ix.accounts.len(),
+        ix.data.len()
+    );
+    table.push_str(&format!(
+        "{:>3}  {:<44}  {:<6}  {:<8}  {}\n",
+        "#", "pubkey", "signer", "writable", "label"
+    ));
+
+    for (index, meta) in ix.accounts.iter().enumerate() {
+        let label = label_account(&meta.pubkey, &pubkeys).unwrap_or_else(|| "-".into());
+        table.push_str(&format!(
+            "{:>3}  {:<44}  {:<6}  {:<8}  {}\n",
+            index,
+            meta.pubkey.to_string(),
+            if meta.is_signer { "yes" } else { "no" },
+            if meta.is_writable { "yes" } else { "no" },
+            label
+        ));
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::instructions;
+
+    #[test]
+    fn test_debug_accounts() {
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let ix = instructions::sell_instruction(
+            &payer,
+            &mint,
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            instructions::Sell {
+                amount: 1,
+                min_sol_output: 0,
+            },
+        );
+
+        let table = debug_accounts(&ix);
+        let rows: Vec<&str> = table.lines().collect();
+        assert_eq!(rows.len(), ix.accounts.len() + 2);
+        assert!(rows[0].contains("Pump.fun program"));
+        assert!(rows[2].ends_with("global"));
+        assert!(rows[3].ends_with("-"));
+        assert!(rows[5].ends_with("bonding curve of #2"));
+        assert!(rows[6].ends_with("associated bonding curve of #2 (token program)"));
+        assert!(rows[7].ends_with("token account of #6 for mint #2 (token program)"));
+        assert!(rows[8].contains(&payer.to_string()));
+        assert!(rows[8].contains("yes     yes"));
+    }
+}
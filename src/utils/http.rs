@@ -0,0 +1,461 @@
+//! Pluggable HTTP client used for metadata uploads and checks
+//!
+//! The upload helpers talk to the network through the [`HttpClient`] trait so that
+//! applications can reuse their own HTTP stack (and connection pool) and tests can
+//! substitute a fake client. [`IsahcHttpClient`] is the default implementation.
+
+use async_trait::async_trait;
+use flate2::read::{GzDecoder, ZlibDecoder};
+use isahc::{config::Configurable, AsyncReadResponseExt};
+use std::{
+    borrow::Cow,
+    io::Read,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+/// Error type returned by [`HttpClient`] implementations
+pub type HttpError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Largest response body accepted by default, in bytes
+///
+/// Upload responses are small JSON documents, so a few megabytes leave ample room while
+/// protecting against endpoints streaming unbounded bodies.
+pub const DEFAULT_MAX_RESPONSE_SIZE: usize = 4 * 1024 * 1024;
+
+/// Error returned when a response body, or its decompressed content, exceeds the size limit
+///
+/// # Fields
+///
+/// * `limit` - Largest accepted body size, in bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResponseTooLarge {
+    pub limit: usize,
+}
+
+impl std::fmt::Display for ResponseTooLarge {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Response body exceeds the limit of {} bytes", self.limit)
+    }
+}
+
+impl std::error::Error for ResponseTooLarge {}
+
+/// Callback reporting upload progress as `(bytes_sent, total_bytes)`
+///
+/// `total_bytes` is `None` when the size of the body is not known up front.
+pub type ProgressCallback = Arc<dyn Fn(u64, Option<u64>) + Send + Sync>;
+
+/// Response returned by an [`HttpClient`]
+///
+/// # Fields
+///
+/// * `status` - HTTP status code
+/// * `body` - Raw response body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Returns the response body, decompressed if it is gzip or zlib (deflate) encoded
+    ///
+    /// Clients normally decode compressed responses based on their `Content-Encoding`
+    /// header, but some proxies compress bodies without announcing it. The encoding is
+    /// therefore detected from the body itself; bodies that are not compressed, or fail to
+    /// decompress, are returned unchanged.
+    pub fn decoded_body(&self) -> Cow<'_, [u8]> {
+        self.decode(u64::MAX)
+    }
+
+    /// Decodes the body, reading at most `limit` decompressed bytes
+    fn decode(&self, limit: u64) -> Cow<'_, [u8]> {
+        let mut decoded = Vec::new();
+        let result = match self.body.as_slice() {
+            [0x1f, 0x8b, ..] => GzDecoder::new(self.body.as_slice())
+                .take(limit)
+                .read_to_end(&mut decoded),
+            // zlib header: deflate compression method and a valid header checksum
+            [cmf, flg, ..] if cmf & 0x0f == 8 && u16::from_be_bytes([*cmf, *flg]) % 31 == 0 => {
+                ZlibDecoder::new(self.body.as_slice())
+                    .take(limit)
+                    .read_to_end(&mut decoded)
+            }
+            _ => return Cow::Borrowed(&self.body),
+        };
+
+        match result {
+            Ok(_) => Cow::Owned(decoded),
+            Err(_) => Cow::Borrowed(&self.body),
+        }
+    }
+
+    /// Returns the decoded response body as text, replacing invalid UTF-8 sequences
+    ///
+    /// Compressed bodies are decompressed first, see [`HttpResponse::decoded_body`].
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.decoded_body()).into_owned()
+    }
+
+    /// Returns the decoded response body as text, failing if it exceeds `limit` bytes
+    ///
+    /// Same as [`HttpResponse::text`], but both the raw body and the decompressed body are
+    /// checked against the limit, so a small compressed body cannot expand without bound.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Largest accepted body size, in bytes
+    ///
+    /// # Returns
+    ///
+    /// Returns the body text, or [`ResponseTooLarge`] if the body exceeds the limit
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use pumpfun::utils::http::HttpResponse;
+    ///
+    /// let response = HttpResponse { status: 200, body: b"{}".to_vec() };
+    /// assert_eq!(response.text_within(2).unwrap(), "{}");
+    /// assert!(response.text_within(1).is_err());
+    /// ```
+    pub fn text_within(&self, limit: usize) -> Result<String, ResponseTooLarge> {
+        let too_large = ResponseTooLarge { limit };
+        if self.body.len() > limit {
+            return Err(too_large);
+        }
+
+        // Read one byte past the limit to tell a body of exactly `limit` bytes from a larger one
+        let decoded = self.decode((limit as u64).saturating_add(1));
+        if decoded.len() > limit {
+            return Err(too_large);
+        }
+
+        Ok(String::from_utf8_lossy(&decoded).into_owned())
+    }
+}
+
+/// Minimal HTTP client interface needed by the upload helpers
+///
+/// # Examples
+///
+/// ```rust
+/// use pumpfun::utils::http::{HttpClient, HttpError, HttpResponse};
+///
+/// struct FakeClient;
+///
+/// #[async_trait::async_trait]
+/// impl HttpClient for FakeClient {
+///     async fn post_multipart(
+///         &self,
+///         _url: &str,
+///         _body: Vec<u8>,
+///         _headers: &[(String, String)],
+///     ) -> Result<HttpResponse, HttpError> {
+///         Ok(HttpResponse { status: 200, body: b"{}".to_vec() })
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait HttpClient: Send + Sync {
+    /// Sends a `POST` request with an already encoded multipart body
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Destination URL
+    /// * `body` - Encoded multipart/form-data body
+    /// * `headers` - Request headers, including the multipart `Content-Type`
+    ///
+    /// # Returns
+    ///
+    /// Returns the response status and body, or an error if the request could not be sent
+    async fn post_multipart(
+        &self,
+        url: &str,
+        body: Vec<u8>,
+        headers: &[(String, String)],
+    ) -> Result<HttpResponse, HttpError>;
+
+    /// Sends a `POST` request with an arbitrary, already encoded body
+    ///
+    /// The default implementation forwards to [`HttpClient::post_multipart`], which sends
+    /// the body and headers unchanged; override it if the two need to differ.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Destination URL
+    /// * `body` - Encoded request body
+    /// * `headers` - Request headers, including the `Content-Type`
+    ///
+    /// # Returns
+    ///
+    /// Returns the response status and body, or an error if the request could not be sent
+    async fn post(
+        &self,
+        url: &str,
+        body: Vec<u8>,
+        headers: &[(String, String)],
+    ) -> Result<HttpResponse, HttpError> {
+        self.post_multipart(url, body, headers).await
+    }
+
+    /// Sends a `POST` request with an encoded multipart body, reporting upload progress
+    ///
+    /// The default implementation sends the body with [`HttpClient::post_multipart`] and
+    /// reports the whole body as sent once the request completes; override it to report
+    /// progress while the body is streamed.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Destination URL
+    /// * `body` - Encoded multipart/form-data body
+    /// * `headers` - Request headers, including the multipart `Content-Type`
+    /// * `on_progress` - Callback invoked with the number of bytes sent so far
+    ///
+    /// # Returns
+    ///
+    /// Returns the response status and body, or an error if the request could not be sent
+    async fn post_multipart_with_progress(
+        &self,
+        url: &str,
+        body: Vec<u8>,
+        headers: &[(String, String)],
+        on_progress: ProgressCallback,
+    ) -> Result<HttpResponse, HttpError> {
+        let total = body.len() as u64;
+        let response = self.post_multipart(url, body, headers).await?;
+        on_progress(total, Some(total));
+        Ok(response)
+    }
+
+    /// Sends a `GET` request
+    ///
+    /// The default implementation fails, since uploads only need `POST`; override it to
+    /// use the client with [`super::verify_metadata_reachable_with_options`].
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Destination URL
+    /// * `headers` - Request headers
+    ///
+    /// # Returns
+    ///
+    /// Returns the response status and body, or an error if the request could not be sent
+    async fn get(
+        &self,
+        url: &str,
+        headers: &[(String, String)],
+    ) -> Result<HttpResponse, HttpError> {
+        let _ = headers;
+        Err(format!("GET {} is not supported by this HTTP client", url).into())
+    }
+}
+
+/// Default [`HttpClient`] backed by isahc
+///
+/// Requests advertise the supported encodings through `Accept-Encoding` and compressed
+/// responses are decompressed transparently. Response bodies are read up to
+/// [`DEFAULT_MAX_RESPONSE_SIZE`] bytes unless configured otherwise; reading stops with a
+/// [`ResponseTooLarge`] error as soon as a body exceeds the limit.
+#[derive(Clone)]
+pub struct IsahcHttpClient {
+    client: isahc::HttpClient,
+    max_response_size: usize,
+}
+
+impl IsahcHttpClient {
+    /// Creates a new isahc-backed client
+    ///
+    /// # Returns
+    ///
+    /// Returns the client, or an error if isahc fails to initialize
+    pub fn new() -> Result<Self, HttpError> {
+        Ok(Self::with_client(isahc::HttpClient::new()?))
+    }
+
+    /// Wraps an existing isahc client
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - Preconfigured isahc client
+    pub fn with_client(client: isahc::HttpClient) -> Self {
+        Self {
+            client,
+            max_response_size: DEFAULT_MAX_RESPONSE_SIZE,
+        }
+    }
+
+    /// Sets the largest response body read, in bytes
+    ///
+    /// # Arguments
+    ///
+    /// * `max_response_size` - Largest accepted body size, in bytes
+    pub fn with_max_response_size(mut self, max_response_size: usize) -> Self {
+        self.max_response_size = max_response_size;
+        self
+    }
+
+    /// Sends a request and reads the response body up to the size limit
+    async fn send(
+        &self,
+        request: isahc::Request<isahc::AsyncBody>,
+    ) -> Result<HttpResponse, HttpError> {
+        let mut response = self.client.send_async(request).await?;
+        let status = response.status().as_u16();
+
+        let mut body = LimitedBuffer {
+            data: Vec::new(),
+            limit: self.max_response_size,
+        };
+        if let Err(err) = response.copy_to(&mut body).await {
+            if body.data.len() > body.limit {
+                return Err(Box::new(ResponseTooLarge { limit: body.limit }));
+            }
+            return Err(Box::new(err));
+        }
+
+        Ok(HttpResponse {
+            status,
+            body: body.data,
+        })
+    }
+}
+
+/// Response body buffer refusing writes past its limit
+///
+/// Keeps the first byte past the limit, so that the reader can tell an exceeded limit
+/// from other I/O errors.
+struct LimitedBuffer {
+    data: Vec<u8>,
+    limit: usize,
+}
+
+impl futures_io::AsyncWrite for LimitedBuffer {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        let remaining = this.limit.saturating_sub(this.data.len());
+        if buf.len() > remaining {
+            this.data.extend_from_slice(&buf[..remaining + 1]);
+            return Poll::Ready(Err(std::io::Error::other(ResponseTooLarge {
+                limit: this.limit,
+            })));
+        }
+
+        this.data.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+#[async_trait]
+impl HttpClient for IsahcHttpClient {
+    async fn post_multipart(
+        &self,
+        url: &str,
+        body: Vec<u8>,
+        headers: &[(String, String)],
+    ) -> Result<HttpResponse, HttpError> {
+        let mut request = isahc::Request::builder()
+            .method("POST")
+            .uri(url)
+            .automatic_decompression(true);
+        for (name, value) in headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        let request = request
+            .header("Content-Length", body.len() as u64)
+            .body(isahc::AsyncBody::from(body))?;
+
+        self.send(request).await
+    }
+
+    async fn post_multipart_with_progress(
+        &self,
+        url: &str,
+        body: Vec<u8>,
+        headers: &[(String, String)],
+        on_progress: ProgressCallback,
+    ) -> Result<HttpResponse, HttpError> {
+        let mut request = isahc::Request::builder()
+            .method("POST")
+            .uri(url)
+            .automatic_decompression(true);
+        for (name, value) in headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        let length = body.len() as u64;
+        let reader = ProgressReader::new(body, on_progress);
+        let request = request
+            .header("Content-Length", length)
+            .body(isahc::AsyncBody::from_reader_sized(reader, length))?;
+
+        self.send(request).await
+    }
+
+    async fn get(
+        &self,
+        url: &str,
+        headers: &[(String, String)],
+    ) -> Result<HttpResponse, HttpError> {
+        let mut request = isahc::Request::builder()
+            .method("GET")
+            .uri(url)
+            .automatic_decompression(true);
+        for (name, value) in headers {
+            request = request.header(name.as_str(), value.as_str());
+        }
+        let request = request.body(isahc::AsyncBody::empty())?;
+
+        self.send(request).await
+    }
+}
+
+/// Request body reader that reports how many bytes have been read so far
+pub(crate) struct ProgressReader {
+    body: Vec<u8>,
+    position: usize,
+    on_progress: ProgressCallback,
+}
+
+impl ProgressReader {
+    pub(crate) fn new(body: Vec<u8>, on_progress: ProgressCallback) -> Self {
+        Self {
+            body,
+            position: 0,
+            on_progress,
+        }
+    }
+}
+
+impl futures_io::AsyncRead for ProgressReader {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        let remaining = &this.body[this.position..];
+        let read = remaining.len().min(buf.len());
+        buf[..read].copy_from_slice(&remaining[..read]);
+        this.position += read;
+
+        if read > 0 {
+            (this.on_progress)(this.position as u64, Some(this.body.len() as u64));
+        }
+
+        Poll::Ready(Ok(read))
+    }
+}
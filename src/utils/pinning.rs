@@ -0,0 +1,214 @@
+//! Redundant pinning of uploaded metadata to additional IPFS providers
+//!
+//! Pump.fun pins uploaded images and metadata on its own IPFS node. A [`Pinner`] can
+//! additionally pin the same CIDs with another provider so they stay available if Pump.fun
+//! ever unpins them. [`NoopPinner`] is the default and does nothing; [`PinataPinner`] pins
+//! by CID through Pinata's API.
+
+use super::http::{HttpClient, HttpError, IsahcHttpClient};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+/// Result of pinning a CID with a provider
+///
+/// # Fields
+///
+/// * `provider` - Name of the pinning provider
+/// * `cid` - Pinned content identifier
+/// * `response` - Raw response body returned by the provider
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PinResult {
+    pub provider: String,
+    pub cid: String,
+    pub response: String,
+}
+
+/// Service able to pin existing IPFS content by CID
+#[async_trait]
+pub trait Pinner: Send + Sync {
+    /// Pins the given CID
+    ///
+    /// # Arguments
+    ///
+    /// * `cid` - Content identifier to pin
+    /// * `name` - Human-readable name to attach to the pin
+    ///
+    /// # Returns
+    ///
+    /// Returns `Ok(Some(_))` when the content was pinned, `Ok(None)` when the pinner
+    /// intentionally did nothing, or an error if the provider rejected the request
+    async fn pin(&self, cid: &str, name: &str) -> Result<Option<PinResult>, HttpError>;
+}
+
+/// Pinner that does nothing, used when redundant pinning is not configured
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoopPinner;
+
+#[async_trait]
+impl Pinner for NoopPinner {
+    async fn pin(&self, _cid: &str, _name: &str) -> Result<Option<PinResult>, HttpError> {
+        Ok(None)
+    }
+}
+
+/// Pinner backed by Pinata's pin-by-hash API
+#[derive(Clone)]
+pub struct PinataPinner {
+    jwt: String,
+    http_client: Arc<dyn HttpClient>,
+}
+
+impl PinataPinner {
+    /// Pin-by-hash endpoint of the Pinata API
+    pub const PIN_BY_HASH_URL: &'static str = "https://api.pinata.cloud/pinning/pinByHash";
+
+    /// Creates a Pinata pinner using the default HTTP client
+    ///
+    /// # Arguments
+    ///
+    /// * `jwt` - Pinata API JWT
+    ///
+    /// # Returns
+    ///
+    /// Returns the pinner, or an error if the HTTP client fails to initialize
+    pub fn new(jwt: impl Into<String>) -> Result<Self, HttpError> {
+        Ok(Self::with_http_client(
+            jwt,
+            Arc::new(IsahcHttpClient::new()?),
+        ))
+    }
+
+    /// Creates a Pinata pinner sending requests through the given HTTP client
+    ///
+    /// # Arguments
+    ///
+    /// * `jwt` - Pinata API JWT
+    /// * `http_client` - HTTP client used to reach the Pinata API
+    pub fn with_http_client(jwt: impl Into<String>, http_client: Arc<dyn HttpClient>) -> Self {
+        Self {
+            jwt: jwt.into(),
+            http_client,
+        }
+    }
+}
+
+#[async_trait]
+impl Pinner for PinataPinner {
+    async fn pin(&self, cid: &str, name: &str) -> Result<Option<PinResult>, HttpError> {
+        let body = serde_json::to_vec(&serde_json::json!({
+            "hashToPin": cid,
+            "pinataMetadata": { "name": name },
+        }))?;
+        let headers = [
+            ("Content-Type".to_string(), "application/json".to_string()),
+            ("Authorization".to_string(), format!("Bearer {}", self.jwt)),
+        ];
+
+        let response = self
+            .http_client
+            .post(Self::PIN_BY_HASH_URL, body, &headers)
+            .await?;
+        if !(200..300).contains(&response.status) {
+            return Err(format!(
+                "Pinata returned status {}: {}",
+                response.status,
+                response.text()
+            )
+            .into());
+        }
+
+        Ok(Some(PinResult {
+            provider: "pinata".to_string(),
+            cid: cid.to_string(),
+            response: response.text(),
+        }))
+    }
+}
+
+/// Gateway used to resolve `ipfs://` URIs unless another one is configured
+pub const DEFAULT_IPFS_GATEWAY: &str = "https://ipfs.io";
+
+/// Rewrites an `ipfs://` URI to an HTTP URL on the given gateway
+///
+/// Other URIs, e.g. links already pointing to a gateway, are returned unchanged.
+///
+/// # Arguments
+///
+/// * `uri` - URI to resolve, e.g. a metadata URI returned by an upload
+/// * `gateway` - Base URL of the gateway, e.g. [`DEFAULT_IPFS_GATEWAY`]
+///
+/// # Examples
+///
+/// ```
+/// use pumpfun::utils::pinning::{gateway_url, DEFAULT_IPFS_GATEWAY};
+///
+/// assert_eq!(
+///     gateway_url("ipfs://QmExample/metadata.json", DEFAULT_IPFS_GATEWAY),
+///     "https://ipfs.io/ipfs/QmExample/metadata.json"
+/// );
+/// assert_eq!(
+///     gateway_url("https://example.com/metadata.json", DEFAULT_IPFS_GATEWAY),
+///     "https://example.com/metadata.json"
+/// );
+/// ```
+pub fn gateway_url(uri: &str, gateway: &str) -> String {
+    match uri.trim().strip_prefix("ipfs://") {
+        Some(path) => format!(
+            "{}/ipfs/{}",
+            gateway.trim_end_matches('/'),
+            path.strip_prefix("ipfs/").unwrap_or(path)
+        ),
+        None => uri.trim().to_string(),
+    }
+}
+
+/// Extracts the CID from an IPFS URI
+///
+/// Supports `ipfs://<cid>` as well as gateway URLs of the form `https://<host>/ipfs/<cid>`.
+pub(crate) fn cid_from_uri(uri: &str) -> Option<&str> {
+    let rest = match uri.strip_prefix("ipfs://") {
+        Some(rest) => rest,
+        None => uri.split_once("/ipfs/")?.1,
+    };
+    let cid = rest.split(['/', '?', '#']).next()?;
+    (!cid.is_empty()).then_some(cid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cid_from_uri() {
+        assert_eq!(cid_from_uri("ipfs://QmExample"), Some("QmExample"));
+        assert_eq!(
+            cid_from_uri("https://ipfs.io/ipfs/QmExample/image.png"),
+            Some("QmExample")
+        );
+        assert_eq!(
+            cid_from_uri("https://cf-ipfs.com/ipfs/bafyExample?filename=x"),
+            Some("bafyExample")
+        );
+        assert_eq!(cid_from_uri("https://example.com/image.png"), None);
+        assert_eq!(cid_from_uri("https://ipfs.io/ipfs/"), None);
+    }
+
+    #[test]
+    fn test_gateway_url() {
+        assert_eq!(
+            gateway_url("ipfs://QmExample", "https://gateway.example/"),
+            "https://gateway.example/ipfs/QmExample"
+        );
+        assert_eq!(
+            gateway_url("ipfs://ipfs/QmExample", "https://gateway.example"),
+            "https://gateway.example/ipfs/QmExample"
+        );
+        assert_eq!(
+            gateway_url(
+                " https://ipfs.io/ipfs/QmExample ",
+                "https://gateway.example"
+            ),
+            "https://ipfs.io/ipfs/QmExample"
+        );
+    }
+}
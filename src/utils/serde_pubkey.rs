@@ -0,0 +1,86 @@
+//! Serde helpers for (de)serializing public keys as base58 strings
+//!
+//! `Pubkey` serializes as a raw 32-byte array by default, which is awkward in JSON APIs.
+//! Use this module with `#[serde(with = "pumpfun::utils::serde_pubkey")]` on a `Pubkey`
+//! field to emit the familiar base58 address instead, or with
+//! `#[serde(with = "pumpfun::utils::serde_pubkey::array")]` on a fixed-size array of them.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use pumpfun::utils::serde_pubkey;
+//! use serde::{Deserialize, Serialize};
+//! use solana_sdk::pubkey::Pubkey;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Holder {
+//!     #[serde(with = "serde_pubkey")]
+//!     owner: Pubkey,
+//! }
+//!
+//! let holder = Holder { owner: Pubkey::default() };
+//! let json = serde_json::to_string(&holder).unwrap();
+//! assert_eq!(json, r#"{"owner":"11111111111111111111111111111111"}"#);
+//! ```
+
+use serde::{de::Error, Deserialize, Deserializer, Serializer};
+use solana_sdk::pubkey::Pubkey;
+use std::str::FromStr;
+
+/// Serializes a public key as a base58 string
+pub fn serialize<S>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.collect_str(pubkey)
+}
+
+/// Deserializes a public key from a base58 string
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Pubkey, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    Pubkey::from_str(&value).map_err(|err| D::Error::custom(format!("{}: {}", err, value)))
+}
+
+/// Serde helpers for fixed-size arrays of public keys
+pub mod array {
+    use super::*;
+    use serde::ser::SerializeSeq;
+
+    /// Serializes an array of public keys as a sequence of base58 strings
+    pub fn serialize<S, const N: usize>(
+        pubkeys: &[Pubkey; N],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(N))?;
+        for pubkey in pubkeys {
+            seq.serialize_element(&pubkey.to_string())?;
+        }
+        seq.end()
+    }
+
+    /// Deserializes an array of public keys from a sequence of base58 strings
+    pub fn deserialize<'de, D, const N: usize>(deserializer: D) -> Result<[Pubkey; N], D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let values = Vec::<String>::deserialize(deserializer)?;
+        let len = values.len();
+        let pubkeys = values
+            .iter()
+            .map(|value| {
+                Pubkey::from_str(value)
+                    .map_err(|err| D::Error::custom(format!("{}: {}", err, value)))
+            })
+            .collect::<Result<Vec<Pubkey>, D::Error>>()?;
+
+        pubkeys
+            .try_into()
+            .map_err(|_| D::Error::invalid_length(len, &format!("{} public keys", N).as_str()))
+    }
+}
@@ -0,0 +1,179 @@
+//! Asynchronous signing for keys held outside the process
+//!
+//! A [`RemoteSigner`] signs serialized transaction messages on behalf of an account whose
+//! private key the SDK never sees, e.g. a key held by an HTTP KMS or a signing service. The
+//! SDK builds the transaction, requests the fee payer's signature from the signer and
+//! assembles the signed transaction. [`LocalSigner`] wraps an in-process [`Keypair`], so the
+//! same code paths work with local keys.
+
+use crate::error;
+use async_trait::async_trait;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::Signer,
+};
+use std::sync::Arc;
+
+/// Signer that may sign asynchronously, e.g. through a remote service
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_trait::async_trait;
+/// use pumpfun::{error::ClientError, utils::signer::RemoteSigner};
+/// use solana_sdk::{pubkey::Pubkey, signature::Signature};
+///
+/// struct KmsSigner {
+///     pubkey: Pubkey,
+///     key_id: String,
+/// }
+///
+/// #[async_trait]
+/// impl RemoteSigner for KmsSigner {
+///     fn pubkey(&self) -> Pubkey {
+///         self.pubkey
+///     }
+///
+///     async fn sign_message(&self, message: &[u8]) -> Result<Signature, ClientError> {
+///         // Send `message` to the KMS and decode the returned 64-byte ed25519 signature
+///         # let signature: Vec<u8> = vec![0; 64];
+///         Signature::try_from(signature.as_slice())
+///             .map_err(|err| ClientError::OtherError(format!("Invalid signature: {}", err)))
+///     }
+/// }
+/// ```
+#[async_trait]
+pub trait RemoteSigner: Send + Sync {
+    /// Returns the public key of the signing account
+    fn pubkey(&self) -> Pubkey;
+
+    /// Signs a serialized transaction message
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Serialized transaction message to sign
+    ///
+    /// # Returns
+    ///
+    /// Returns the ed25519 signature of the message, or a ClientError if signing fails.
+    /// Implementations should map service failures to `ClientError::OtherError`
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, error::ClientError>;
+}
+
+/// [`RemoteSigner`] backed by a keypair held in the process
+///
+/// Lets code written against [`RemoteSigner`] run with a local key, e.g. in tests or when
+/// only some accounts are held by a signing service. Wrapping the keypair instead of
+/// implementing the trait on [`Keypair`] keeps `pubkey()` unambiguous for code that also
+/// uses [`Signer`].
+///
+/// # Examples
+///
+/// ```
+/// use pumpfun::utils::signer::{LocalSigner, RemoteSigner};
+/// use solana_sdk::{signature::Keypair, signer::Signer};
+/// use std::sync::Arc;
+///
+/// let keypair = Arc::new(Keypair::new());
+/// let signer = LocalSigner::from(keypair.clone());
+/// assert_eq!(RemoteSigner::pubkey(&signer), keypair.pubkey());
+/// ```
+#[derive(Debug, Clone)]
+pub struct LocalSigner(pub Arc<Keypair>);
+
+impl From<Keypair> for LocalSigner {
+    fn from(keypair: Keypair) -> Self {
+        Self(Arc::new(keypair))
+    }
+}
+
+impl From<Arc<Keypair>> for LocalSigner {
+    fn from(keypair: Arc<Keypair>) -> Self {
+        Self(keypair)
+    }
+}
+
+#[async_trait]
+impl RemoteSigner for LocalSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.0.pubkey()
+    }
+
+    async fn sign_message(&self, message: &[u8]) -> Result<Signature, error::ClientError> {
+        self.0
+            .try_sign_message(message)
+            .map_err(|err| error::ClientError::OtherError(format!("Failed to sign: {}", err)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::transaction::{
+        get_transaction_with_blockhash, get_transaction_with_remote_signer_and_blockhash,
+    };
+    use solana_sdk::hash::Hash;
+
+    /// Signer returning a signature that does not match the message
+    struct BrokenSigner(Pubkey);
+
+    #[async_trait]
+    impl RemoteSigner for BrokenSigner {
+        fn pubkey(&self) -> Pubkey {
+            self.0
+        }
+
+        async fn sign_message(&self, _message: &[u8]) -> Result<Signature, error::ClientError> {
+            Ok(Signature::default())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_remote_signed_transaction() {
+        let payer = Arc::new(Keypair::new());
+        let owner = Keypair::new();
+        let blockhash = Hash::new_unique();
+        let instructions = [solana_system_interface::instruction::transfer(
+            &owner.pubkey(),
+            &payer.pubkey(),
+            1,
+        )];
+
+        // ed25519 signatures are deterministic, so both paths produce the same transaction
+        let local = get_transaction_with_blockhash(
+            payer.clone(),
+            &instructions,
+            Some(&[&owner]),
+            blockhash,
+            #[cfg(feature = "versioned-tx")]
+            None,
+        )
+        .unwrap();
+        let remote = get_transaction_with_remote_signer_and_blockhash(
+            &LocalSigner::from(payer.clone()),
+            &instructions,
+            Some(&[&owner]),
+            blockhash,
+            #[cfg(feature = "versioned-tx")]
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(
+            serde_json::to_vec(&local).unwrap(),
+            serde_json::to_vec(&remote).unwrap()
+        );
+
+        let result = get_transaction_with_remote_signer_and_blockhash(
+            &BrokenSigner(payer.pubkey()),
+            &instructions,
+            Some(&[&owner]),
+            blockhash,
+            #[cfg(feature = "versioned-tx")]
+            None,
+        )
+        .await;
+        assert!(matches!(result, Err(error::ClientError::OtherError(_))));
+    }
+}
@@ -1,16 +1,27 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use solana_client::{nonblocking::rpc_client::RpcClient, rpc_client::SerializableTransaction};
+use base64::Engine;
+use solana_client::{
+    nonblocking::rpc_client::RpcClient, rpc_client::SerializableTransaction,
+    rpc_config::RpcSendTransactionConfig,
+};
 #[cfg(not(feature = "versioned-tx"))]
 use solana_sdk::transaction::Transaction;
-use solana_sdk::{instruction::Instruction, signature::Keypair, signer::Signer};
+use solana_sdk::{
+    commitment_config::CommitmentConfig,
+    hash::Hash,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature},
+    signer::Signer,
+};
 #[cfg(feature = "versioned-tx")]
 use solana_sdk::{
     message::{v0, AddressLookupTableAccount, VersionedMessage},
     transaction::VersionedTransaction,
 };
 
-use crate::error;
+use crate::{constants, error};
 
 /// Constructs a signed transaction from a set of instructions and signers
 ///
@@ -119,6 +130,52 @@ pub async fn get_transaction(
         .await
         .map_err(error::ClientError::SolanaClientError)?;
 
+    get_transaction_with_blockhash(
+        payer,
+        instructions,
+        additional_signers,
+        recent_blockhash,
+        #[cfg(feature = "versioned-tx")]
+        address_lookup_table_accounts,
+    )
+}
+
+/// Constructs a signed transaction using the given blockhash
+///
+/// Same as [`get_transaction`], but does not contact the network: the caller provides the
+/// blockhash. This is needed for durable nonce transactions, where the "blockhash" is the
+/// value stored in the nonce account, and for building transactions offline.
+///
+/// # Arguments
+///
+/// * `payer` - The primary account that will pay for the transaction fees
+/// * `instructions` - Slice of Solana instructions to include in the transaction
+/// * `additional_signers` - Optional slice of additional keypair signers that should sign the transaction,
+///   in addition to the payer
+/// * `recent_blockhash` - Blockhash (or durable nonce value) the transaction is built against
+/// * `address_lookup_table_accounts` - Optional slice of Address Lookup Table accounts to include
+///   (only available with "versioned-tx" feature)
+///
+/// # Returns
+///
+/// Returns a signed Transaction (or VersionedTransaction when the "versioned-tx" feature is enabled)
+/// if successful, or a ClientError if the operation fails
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Transaction message compilation fails (for versioned transactions)
+/// - Transaction signing fails
+#[allow(clippy::result_large_err)]
+pub fn get_transaction_with_blockhash(
+    payer: Arc<Keypair>,
+    instructions: &[Instruction],
+    additional_signers: Option<&[&Keypair]>,
+    recent_blockhash: Hash,
+    #[cfg(feature = "versioned-tx")] address_lookup_table_accounts: Option<
+        &[AddressLookupTableAccount],
+    >,
+) -> Result<impl SerializableTransaction, error::ClientError> {
     // Create a combined signers array with payer and additional signers
     let mut all_signers =
         Vec::with_capacity(1 + additional_signers.map_or(0, |signers| signers.len()));
@@ -168,3 +225,359 @@ pub async fn get_transaction(
 
     Ok(transaction)
 }
+
+/// Constructs a transaction whose fee payer signs through a remote signer
+///
+/// Fetches a recent blockhash, compiles the message with `signer`, a
+/// [`RemoteSigner`](super::signer::RemoteSigner), as the fee payer, signs it
+/// with the local `additional_signers` and requests the fee payer's signature from `signer`.
+/// The returned signature is verified against the message before the transaction is
+/// assembled, so a misconfigured signing service fails here rather than at the RPC node.
+///
+/// # Arguments
+///
+/// * `rpc` - An Arc-wrapped RpcClient used to fetch the recent blockhash
+/// * `signer` - Fee payer, e.g. a signing service or a local keypair
+/// * `instructions` - Slice of Solana instructions to include in the transaction
+/// * `additional_signers` - Optional slice of additional keypair signers that should sign the transaction,
+///   in addition to the fee payer
+/// * `address_lookup_table_accounts` - Optional slice of Address Lookup Table accounts to include
+///   (only available with "versioned-tx" feature)
+///
+/// # Returns
+///
+/// Returns a signed Transaction (or VersionedTransaction when the "versioned-tx" feature is enabled)
+/// if successful, or a ClientError if the operation fails
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Failed to retrieve the recent blockhash from the network
+/// - Transaction message compilation fails (for versioned transactions)
+/// - Signing with an additional signer fails, or it is not a signer of the message
+/// - The remote signer fails or returns an invalid signature
+pub async fn get_transaction_with_remote_signer(
+    rpc: Arc<RpcClient>,
+    signer: &dyn super::signer::RemoteSigner,
+    instructions: &[Instruction],
+    additional_signers: Option<&[&Keypair]>,
+    #[cfg(feature = "versioned-tx")] address_lookup_table_accounts: Option<
+        &[AddressLookupTableAccount],
+    >,
+) -> Result<impl SerializableTransaction, error::ClientError> {
+    let recent_blockhash = rpc
+        .get_latest_blockhash()
+        .await
+        .map_err(error::ClientError::SolanaClientError)?;
+
+    get_transaction_with_remote_signer_and_blockhash(
+        signer,
+        instructions,
+        additional_signers,
+        recent_blockhash,
+        #[cfg(feature = "versioned-tx")]
+        address_lookup_table_accounts,
+    )
+    .await
+}
+
+/// Constructs a transaction whose fee payer signs through a remote signer, using the given
+/// blockhash
+///
+/// Same as [`get_transaction_with_remote_signer`], but does not contact the network: the
+/// caller provides the blockhash.
+///
+/// # Arguments
+///
+/// * `signer` - Fee payer, e.g. a signing service or a local keypair
+/// * `instructions` - Slice of Solana instructions to include in the transaction
+/// * `additional_signers` - Optional slice of additional keypair signers that should sign the transaction,
+///   in addition to the fee payer
+/// * `recent_blockhash` - Blockhash (or durable nonce value) the transaction is built against
+/// * `address_lookup_table_accounts` - Optional slice of Address Lookup Table accounts to include
+///   (only available with "versioned-tx" feature)
+///
+/// # Returns
+///
+/// Returns a signed Transaction (or VersionedTransaction when the "versioned-tx" feature is enabled)
+/// if successful, or a ClientError if the operation fails
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - Transaction message compilation fails (for versioned transactions)
+/// - Signing with an additional signer fails, or it is not a signer of the message
+/// - The remote signer fails or returns an invalid signature
+pub async fn get_transaction_with_remote_signer_and_blockhash(
+    signer: &dyn super::signer::RemoteSigner,
+    instructions: &[Instruction],
+    additional_signers: Option<&[&Keypair]>,
+    recent_blockhash: Hash,
+    #[cfg(feature = "versioned-tx")] address_lookup_table_accounts: Option<
+        &[AddressLookupTableAccount],
+    >,
+) -> Result<impl SerializableTransaction, error::ClientError> {
+    let payer = signer.pubkey();
+    let additional_signers = additional_signers.unwrap_or(&[]);
+
+    // Build the message and sign it with the local signers
+    #[cfg(not(feature = "versioned-tx"))]
+    let (mut transaction, message_data) = {
+        let mut transaction = Transaction::new_with_payer(instructions, Some(&payer));
+        transaction
+            .try_partial_sign(additional_signers, recent_blockhash)
+            .map_err(|err| {
+                error::ClientError::OtherError(format!("Failed to sign transaction: {}", err))
+            })?;
+        let message_data = transaction.message_data();
+        (transaction, message_data)
+    };
+
+    #[cfg(feature = "versioned-tx")]
+    let (mut transaction, message_data) = {
+        let message = VersionedMessage::V0(
+            v0::Message::try_compile(
+                &payer,
+                instructions,
+                address_lookup_table_accounts.unwrap_or(&[]),
+                recent_blockhash,
+            )
+            .map_err(|err| {
+                error::ClientError::OtherError(format!(
+                    "Failed to compile transaction message: {}",
+                    err
+                ))
+            })?,
+        );
+        let message_data = message.serialize();
+        let signer_keys =
+            &message.static_account_keys()[..message.header().num_required_signatures as usize];
+        let mut signatures = vec![solana_sdk::signature::Signature::default(); signer_keys.len()];
+        for keypair in additional_signers {
+            let index = signer_keys
+                .iter()
+                .position(|key| *key == keypair.pubkey())
+                .ok_or_else(|| {
+                    error::ClientError::OtherError(format!(
+                        "Failed to sign transaction: {} is not a signer",
+                        keypair.pubkey()
+                    ))
+                })?;
+            signatures[index] = keypair.sign_message(&message_data);
+        }
+        (
+            VersionedTransaction {
+                signatures,
+                message,
+            },
+            message_data,
+        )
+    };
+
+    // The fee payer is always the first signer
+    let signature = signer.sign_message(&message_data).await?;
+    if !signature.verify(payer.as_ref(), &message_data) {
+        return Err(error::ClientError::OtherError(format!(
+            "Remote signer returned an invalid signature for {}",
+            payer
+        )));
+    }
+    transaction.signatures[0] = signature;
+
+    Ok(transaction)
+}
+
+/// Sends a transaction and waits until it is confirmed
+///
+/// Without `config`, this is `RpcClient::send_and_confirm_transaction`: the transaction is
+/// simulated (preflight) at the client's commitment before being sent. With `config`, it is
+/// sent with the given RPC options, e.g. `skip_preflight` to save the simulation round trip
+/// of time-critical trades, or `max_retries` to control rebroadcasting by the RPC node.
+///
+/// # Arguments
+///
+/// * `rpc` - RpcClient used to send the transaction and poll its status, at its commitment
+/// * `transaction` - Signed transaction to send
+/// * `config` - Optional send options, see `RpcSendTransactionConfig`
+///
+/// # Returns
+///
+/// Returns the transaction signature once confirmed, or a ClientError if sending fails, the
+/// transaction fails or its blockhash expires before it is confirmed
+///
+/// # Examples
+///
+/// ```no_run
+/// # use pumpfun::utils::transaction::send_and_confirm_transaction;
+/// # use solana_client::{nonblocking::rpc_client::RpcClient, rpc_config::RpcSendTransactionConfig};
+/// # use solana_sdk::transaction::Transaction;
+/// #
+/// # async fn example(rpc: RpcClient, transaction: Transaction) -> Result<(), Box<dyn std::error::Error>> {
+/// let config = RpcSendTransactionConfig {
+///     skip_preflight: true,
+///     max_retries: Some(0),
+///     ..Default::default()
+/// };
+/// let signature = send_and_confirm_transaction(&rpc, &transaction, Some(config)).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn send_and_confirm_transaction(
+    rpc: &RpcClient,
+    transaction: &impl SerializableTransaction,
+    config: Option<RpcSendTransactionConfig>,
+) -> Result<Signature, error::ClientError> {
+    let Some(config) = config else {
+        return rpc
+            .send_and_confirm_transaction(transaction)
+            .await
+            .map_err(error::ClientError::SolanaClientError);
+    };
+
+    let signature = rpc
+        .send_transaction_with_config(transaction, config)
+        .await
+        .map_err(error::ClientError::SolanaClientError)?;
+
+    // Fetched after sending to keep it off the critical path; a transaction can only land
+    // while this blockhash is valid, as its own is at most as recent
+    let latest_blockhash = rpc
+        .get_latest_blockhash()
+        .await
+        .map_err(error::ClientError::SolanaClientError)?;
+
+    loop {
+        // Bound first, so that no error temporary is held across the awaits below
+        let status = rpc
+            .get_signature_status(&signature)
+            .await
+            .map_err(error::ClientError::SolanaClientError)?;
+        match status {
+            Some(Ok(())) => return Ok(signature),
+            Some(Err(err)) => return Err(error::ClientError::SolanaClientError(err.into())),
+            None => {
+                let valid = rpc
+                    .is_blockhash_valid(&latest_blockhash, CommitmentConfig::processed())
+                    .await
+                    .map_err(error::ClientError::SolanaClientError)?;
+                if !valid {
+                    return Err(error::ClientError::OtherError(format!(
+                        "Transaction {} expired before it was confirmed",
+                        signature
+                    )));
+                }
+                crate::utils::sleep(Duration::from_millis(500)).await;
+            }
+        }
+    }
+}
+
+/// Serializes instructions into an unsigned v0 transaction encoded as base64
+///
+/// The transaction holds an empty signature for each required signer, which is the format
+/// wallet adapters expect from `VersionedTransaction.deserialize`. Nothing is signed, so
+/// the payer's key is not needed.
+///
+/// # Arguments
+///
+/// * `payer` - Fee payer of the transaction, usually the wallet that will sign it
+/// * `instructions` - Instructions to include in the transaction
+/// * `blockhash` - Recent blockhash to build the transaction with
+///
+/// # Returns
+///
+/// Returns the base64-encoded transaction, or a ClientError if the message cannot be
+/// compiled or serialized
+///
+/// # Examples
+///
+/// ```
+/// use pumpfun::utils::transaction::{get_unsigned_transaction_base64, memo};
+/// use solana_sdk::{hash::Hash, pubkey::Pubkey};
+///
+/// let payer = Pubkey::new_unique();
+/// let encoded = get_unsigned_transaction_base64(&payer, &[memo("hello")], Hash::default())
+///     .unwrap();
+/// assert!(!encoded.is_empty());
+/// ```
+#[allow(clippy::result_large_err)]
+pub fn get_unsigned_transaction_base64(
+    payer: &Pubkey,
+    instructions: &[Instruction],
+    blockhash: Hash,
+) -> Result<String, error::ClientError> {
+    let message =
+        solana_sdk::message::v0::Message::try_compile(payer, instructions, &[], blockhash)
+            .map_err(|err| {
+                error::ClientError::OtherError(format!("Failed to compile message: {}", err))
+            })?;
+    let message = solana_sdk::message::VersionedMessage::V0(message);
+    let transaction = solana_sdk::transaction::VersionedTransaction {
+        signatures: vec![Signature::default(); message.header().num_required_signatures as usize],
+        message,
+    };
+
+    let bytes = bincode::serialize(&transaction).map_err(|err| {
+        error::ClientError::OtherError(format!("Failed to serialize transaction: {}", err))
+    })?;
+    Ok(base64::engine::general_purpose::STANDARD.encode(bytes))
+}
+
+/// Builds an SPL Memo instruction carrying the given text
+///
+/// The memo requires no signers and has no effect besides being recorded in the
+/// transaction, which makes it useful for tagging on-chain activity, e.g. with a bot
+/// identifier. Only the Memo program ID is needed, so no `spl-memo` dependency is pulled in.
+///
+/// # Arguments
+///
+/// * `text` - Memo text, must be valid UTF-8 (guaranteed by `&str`)
+///
+/// # Returns
+///
+/// Returns the memo instruction, to be added to a transaction's instructions
+///
+/// # Examples
+///
+/// ```
+/// use pumpfun::utils::transaction::memo;
+///
+/// let instruction = memo("my-bot:v1");
+/// assert_eq!(instruction.data, b"my-bot:v1");
+/// assert!(instruction.accounts.is_empty());
+/// ```
+pub fn memo(text: &str) -> Instruction {
+    Instruction {
+        program_id: constants::accounts::MEMO_PROGRAM,
+        accounts: Vec::new(),
+        data: text.as_bytes().to_vec(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_unsigned_transaction_base64() {
+        let payer = Pubkey::new_unique();
+        let blockhash = Hash::new_unique();
+        let instructions = [memo("hello")];
+
+        let encoded = get_unsigned_transaction_base64(&payer, &instructions, blockhash).unwrap();
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .unwrap();
+        let transaction: solana_sdk::transaction::VersionedTransaction =
+            bincode::deserialize(&bytes).unwrap();
+
+        assert_eq!(transaction.signatures, vec![Signature::default()]);
+        assert_eq!(transaction.message.static_account_keys()[0], payer);
+        assert_eq!(*transaction.message.recent_blockhash(), blockhash);
+        assert_eq!(transaction.message.instructions().len(), 1);
+        assert!(matches!(
+            transaction.message,
+            solana_sdk::message::VersionedMessage::V0(_)
+        ));
+    }
+}
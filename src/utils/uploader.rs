@@ -0,0 +1,113 @@
+//! Pluggable metadata uploads for token creation
+//!
+//! Creating a token only needs the URI of its metadata. A [`MetadataUploader`] produces that
+//! URI from the [`CreateTokenMetadata`] passed to the create methods of
+//! [`PumpFun`](crate::PumpFun). [`PumpFunUploader`] is the default and uploads to the
+//! Pump.fun IPFS API; implement the trait to use another pinning service, to reuse metadata
+//! that is already hosted, or to mock uploads in tests.
+
+use super::{create_token_metadata_with_options, CreateTokenMetadata, UploadOptions};
+use crate::error;
+use async_trait::async_trait;
+
+/// Service turning token metadata into a metadata URI
+///
+/// # Examples
+///
+/// ```no_run
+/// use async_trait::async_trait;
+/// use pumpfun::{
+///     common::types::{Cluster, PriorityFee},
+///     error::ClientError,
+///     utils::{uploader::MetadataUploader, CreateTokenMetadata},
+///     PumpFun,
+/// };
+/// use solana_sdk::{commitment_config::CommitmentConfig, signature::Keypair};
+/// use std::sync::Arc;
+///
+/// /// Points every token at metadata already hosted under a base URL
+/// struct HostedMetadata(String);
+///
+/// #[async_trait]
+/// impl MetadataUploader for HostedMetadata {
+///     async fn upload(&self, metadata: &CreateTokenMetadata) -> Result<String, ClientError> {
+///         Ok(format!("{}/{}.json", self.0, metadata.symbol))
+///     }
+/// }
+///
+/// let cluster = Cluster::mainnet(CommitmentConfig::confirmed(), PriorityFee::default());
+/// let client = PumpFun::new(Arc::new(Keypair::new()), cluster).with_metadata_uploader(
+///     Arc::new(HostedMetadata("https://example.com/tokens".to_string())),
+/// );
+/// ```
+#[async_trait]
+pub trait MetadataUploader: Send + Sync {
+    /// Uploads the metadata of a new token
+    ///
+    /// # Arguments
+    ///
+    /// * `metadata` - Token metadata and image file information
+    ///
+    /// # Returns
+    ///
+    /// Returns the URI of the metadata JSON, stored on-chain by the create instruction, or
+    /// a ClientError if the upload fails
+    async fn upload(&self, metadata: &CreateTokenMetadata) -> Result<String, error::ClientError>;
+}
+
+/// Uploader sending metadata to the Pump.fun IPFS API
+///
+/// Used by [`PumpFun`](crate::PumpFun) unless another uploader is configured. Uploads go
+/// through [`create_token_metadata_with_options`], so the same HTTP client, pinning and
+/// circuit breaker options are available.
+///
+/// # Fields
+///
+/// * `options` - Options applied to every upload
+#[derive(Clone, Default)]
+pub struct PumpFunUploader {
+    pub options: UploadOptions,
+}
+
+impl PumpFunUploader {
+    /// Creates an uploader applying the given options to every upload
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - HTTP client, pinning and circuit breaker configuration
+    pub fn new(options: UploadOptions) -> Self {
+        Self { options }
+    }
+}
+
+#[async_trait]
+impl MetadataUploader for PumpFunUploader {
+    async fn upload(&self, metadata: &CreateTokenMetadata) -> Result<String, error::ClientError> {
+        create_token_metadata_with_options(metadata.clone(), &self.options)
+            .await
+            .map(|upload| upload.response.metadata_uri)
+            .map_err(error::ClientError::UploadMetadataError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_pumpfun_uploader_missing_image() {
+        let metadata = CreateTokenMetadata {
+            name: "My Token".to_string(),
+            symbol: "MYTKN".to_string(),
+            file: "/nonexistent/image.png".to_string(),
+            ..Default::default()
+        };
+
+        // The image is read before any request is sent
+        let err = PumpFunUploader::default()
+            .upload(&metadata)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, error::ClientError::UploadMetadataError(_)));
+    }
+}
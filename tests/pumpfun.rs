@@ -50,6 +50,8 @@ async fn test_02_create_token() {
             twitter: None,
             telegram: None,
             website: Some("https://example.com".to_string()),
+            created_on: None,
+            extra: Default::default(),
         };
 
         let signature = ctx
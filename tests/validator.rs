@@ -0,0 +1,342 @@
+//! End-to-end tests against a local `solana-test-validator`
+//!
+//! Only built with the `validator-tests` feature. The harness starts its own validator with
+//! the Pump.fun program and its dependencies loaded from local binaries, then runs a full
+//! create/buy/sell cycle and checks the resulting account states, which catches account
+//! ordering regressions that unit tests cannot see.
+//!
+//! The binaries and accounts are the ones downloaded by `scripts/pumpfun-test-validator.sh`:
+//!
+//! ```sh
+//! cd scripts && ./pumpfun-test-validator.sh   # downloads, then Ctrl+C once it is running
+//! cargo test --features validator-tests --test validator -- --test-threads 1
+//! ```
+//!
+//! `PUMPFUN_PROGRAMS_DIR` and `PUMPFUN_ACCOUNTS_DIR` override the default `scripts/programs`
+//! and `scripts/accounts` directories. Every `<address>.json` file in the accounts directory
+//! is loaded at its address. The tests are skipped when the Pump.fun binary or
+//! `solana-test-validator` is missing.
+
+use pumpfun::{
+    common::types::{Cluster, PriorityFee, TokenVariant, TokenVerification},
+    constants, utils, PumpFun,
+};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::{
+    commitment_config::CommitmentConfig, native_token::sol_str_to_lamports, signature::Keypair,
+    signer::Signer, transaction::Transaction,
+};
+use std::{
+    path::{Path, PathBuf},
+    process::{Child, Command, Stdio},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tempfile::TempDir;
+
+const RPC_PORT: u16 = 18899;
+const FAUCET_PORT: u16 = 19900;
+
+/// Programs loaded into the validator, as `(program id, file name)`
+const PROGRAMS: [(&str, &str); 3] = [
+    ("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P", "pumpfun.so"),
+    (
+        "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s",
+        "mpl-token-metadata.so",
+    ),
+    (
+        "pfeeUxB6jkeY1Hxd7CsFCAjcbHA9rWtchMGdZ6VojVZ",
+        "pumpfun_fee_config.so",
+    ),
+];
+
+fn scripts_dir(env: &str, default: &str) -> PathBuf {
+    std::env::var(env).map(PathBuf::from).unwrap_or_else(|_| {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("scripts")
+            .join(default)
+    })
+}
+
+/// A `solana-test-validator` process, killed when dropped
+struct TestValidator {
+    process: Child,
+    _ledger: TempDir,
+}
+
+impl TestValidator {
+    /// Starts a validator with the Pump.fun programs, or returns `None` if they are missing
+    async fn start() -> Option<Self> {
+        let programs_dir = scripts_dir("PUMPFUN_PROGRAMS_DIR", "programs");
+        let accounts_dir = scripts_dir("PUMPFUN_ACCOUNTS_DIR", "accounts");
+
+        if !programs_dir.join("pumpfun.so").exists() {
+            eprintln!(
+                "Skipping: no Pump.fun program binary in {}, run scripts/pumpfun-test-validator.sh",
+                programs_dir.display()
+            );
+            return None;
+        }
+
+        let ledger = TempDir::new().expect("Failed to create ledger directory");
+        let mut command = Command::new("solana-test-validator");
+        command
+            .arg("--reset")
+            .arg("--quiet")
+            .arg("--ledger")
+            .arg(ledger.path())
+            .args(["--rpc-port", &RPC_PORT.to_string()])
+            .args(["--faucet-port", &FAUCET_PORT.to_string()]);
+
+        for (program_id, file) in PROGRAMS {
+            let path = programs_dir.join(file);
+            if path.exists() {
+                command.arg("--bpf-program").arg(program_id).arg(path);
+            }
+        }
+
+        for entry in std::fs::read_dir(&accounts_dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            let path = entry.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                let address = path.file_stem().unwrap().to_string_lossy().into_owned();
+                command.arg("--account").arg(address).arg(path);
+            }
+        }
+
+        let process = match command.stdout(Stdio::null()).stderr(Stdio::null()).spawn() {
+            Ok(process) => process,
+            Err(err) => {
+                eprintln!("Skipping: failed to start solana-test-validator: {}", err);
+                return None;
+            }
+        };
+        let validator = Self {
+            process,
+            _ledger: ledger,
+        };
+
+        // Wait until the RPC endpoint is up
+        let rpc = RpcClient::new(validator.rpc_url());
+        let deadline = Instant::now() + Duration::from_secs(60);
+        while rpc.get_health().await.is_err() {
+            assert!(Instant::now() < deadline, "Validator did not start in time");
+            tokio::time::sleep(Duration::from_millis(500)).await;
+        }
+
+        Some(validator)
+    }
+
+    fn rpc_url(&self) -> String {
+        format!("http://127.0.0.1:{}", RPC_PORT)
+    }
+
+    fn ws_url(&self) -> String {
+        format!("ws://127.0.0.1:{}", RPC_PORT + 1)
+    }
+
+    /// Creates a client with a freshly funded payer
+    async fn client(&self) -> PumpFun {
+        let payer = Arc::new(Keypair::new());
+        let client = PumpFun::new(
+            payer.clone(),
+            Cluster::new(
+                self.rpc_url(),
+                self.ws_url(),
+                CommitmentConfig::confirmed(),
+                PriorityFee::default(),
+            ),
+        );
+
+        let signature = client
+            .rpc
+            .request_airdrop(&payer.pubkey(), sol_str_to_lamports("10").unwrap())
+            .await
+            .expect("Failed to request airdrop");
+        while !client
+            .rpc
+            .confirm_transaction(&signature)
+            .await
+            .expect("Failed to confirm airdrop")
+        {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+        }
+
+        client
+    }
+}
+
+impl Drop for TestValidator {
+    fn drop(&mut self) {
+        let _ = self.process.kill();
+        let _ = self.process.wait();
+    }
+}
+
+#[tokio::test]
+async fn test_create_buy_sell() {
+    let Some(validator) = TestValidator::start().await else {
+        return;
+    };
+    let client = validator.client().await;
+    let payer = client.payer.clone();
+    let global = client
+        .get_global_account()
+        .await
+        .expect("Failed to get global account");
+
+    // Rent priced from the rent sysvar matches the per-account minimums of the RPC
+    let mut expected_rent = 0;
+    for size in [
+        constants::sizes::MINT_SIZE,
+        constants::sizes::BONDING_CURVE_SIZE,
+        constants::sizes::TOKEN_ACCOUNT_SIZE,
+        constants::sizes::METADATA_SIZE,
+        constants::sizes::TOKEN_ACCOUNT_SIZE,
+    ] {
+        expected_rent += client
+            .rpc
+            .get_minimum_balance_for_rent_exemption(size)
+            .await
+            .unwrap();
+    }
+    assert_eq!(client.get_create_rent().await.unwrap(), expected_rent);
+
+    // Create, without uploading metadata
+    let mint = Keypair::new();
+    let ipfs = utils::TokenMetadataResponse {
+        metadata: utils::TokenMetadata {
+            name: "Validator Token".to_string(),
+            symbol: "VAL".to_string(),
+            description: "Created by the validator tests".to_string(),
+            image: "https://ipfs.io/ipfs/QmImage".to_string(),
+            show_name: true,
+            created_on: utils::DEFAULT_CREATED_ON.to_string(),
+            twitter: None,
+            telegram: None,
+            website: None,
+        },
+        metadata_uri: "https://ipfs.io/ipfs/QmMetadata".to_string(),
+        metadata_cid: Some("QmMetadata".to_string()),
+        image_cid: Some("QmImage".to_string()),
+    };
+    let create_ix = client.get_create_instruction(&mint, ipfs);
+    let blockhash = client.rpc.get_latest_blockhash().await.unwrap();
+    let transaction = Transaction::new_signed_with_payer(
+        &[create_ix],
+        Some(&payer.pubkey()),
+        &[&*payer, &mint],
+        blockhash,
+    );
+    client
+        .rpc
+        .send_and_confirm_transaction(&transaction)
+        .await
+        .expect("Failed to create token");
+
+    let curve = client
+        .get_bonding_curve_account(&mint.pubkey())
+        .await
+        .expect("Failed to get bonding curve");
+    assert_eq!(
+        curve.virtual_token_reserves,
+        global.initial_virtual_token_reserves
+    );
+    assert_eq!(
+        curve.virtual_sol_reserves,
+        global.initial_virtual_sol_reserves
+    );
+    assert_eq!(
+        curve.real_token_reserves,
+        global.initial_real_token_reserves
+    );
+    assert_eq!(curve.real_sol_reserves, 0);
+    assert_eq!(curve.creator, payer.pubkey());
+    assert!(!curve.complete);
+    assert_eq!(
+        client.get_mint_decimals(&mint.pubkey()).await.unwrap(),
+        constants::decimals::TOKEN_DECIMALS
+    );
+    assert_eq!(
+        client.detect_token_variant(&mint.pubkey()).await.unwrap(),
+        TokenVariant::Classic
+    );
+    assert_eq!(
+        client.verify_pumpfun_token(&mint.pubkey()).await.unwrap(),
+        TokenVerification::Verified
+    );
+    assert_eq!(
+        client.verify_pumpfun_token(&payer.pubkey()).await.unwrap(),
+        TokenVerification::NotAMint
+    );
+
+    // Buy
+    client
+        .buy(
+            mint.pubkey(),
+            sol_str_to_lamports("0.01").unwrap(),
+            None,
+            None,
+            None,
+        )
+        .await
+        .expect("Failed to buy tokens");
+
+    let ata =
+        spl_associated_token_account::get_associated_token_address(&payer.pubkey(), &mint.pubkey());
+    let balance: u64 = client
+        .rpc
+        .get_token_account_balance(&ata)
+        .await
+        .expect("Failed to get token balance")
+        .amount
+        .parse()
+        .unwrap();
+    let curve = client
+        .get_bonding_curve_account(&mint.pubkey())
+        .await
+        .unwrap();
+    assert!(balance > 0);
+    assert_eq!(
+        curve.real_token_reserves,
+        global.initial_real_token_reserves - balance
+    );
+    assert!(curve.real_sol_reserves > 0);
+
+    let positions = client.get_positions(&payer.pubkey()).await.unwrap();
+    assert_eq!(positions.len(), 1);
+    assert_eq!(positions[0].mint, mint.pubkey());
+    assert_eq!(positions[0].token_account, ata);
+    assert_eq!(positions[0].amount, balance);
+    assert!(positions[0].sell_value.is_some_and(|value| value > 0));
+    assert_eq!(
+        client
+            .quote_full_sell(&mint.pubkey(), &payer.pubkey())
+            .await
+            .unwrap(),
+        positions[0].sell_value.unwrap()
+    );
+
+    // Sell everything
+    client
+        .sell(mint.pubkey(), None, None, None)
+        .await
+        .expect("Failed to sell tokens");
+
+    let curve = client
+        .get_bonding_curve_account(&mint.pubkey())
+        .await
+        .unwrap();
+    assert_eq!(
+        curve.real_token_reserves,
+        global.initial_real_token_reserves
+    );
+    #[cfg(feature = "close-ata")]
+    assert!(client.rpc.get_account(&ata).await.is_err());
+}